@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 
 use egui_probe::{angle, Probe};
 use egui_probe_proc::EguiProbe;
@@ -19,6 +19,14 @@ fn custom_probe(_: &mut Foo, ui: &mut egui::Ui, _: &egui_probe::Style) -> egui::
     ui.label("This is custom probe")
 }
 
+fn not_empty(value: &str) -> Result<(), &'static str> {
+    if value.is_empty() {
+        Err("must not be empty")
+    } else {
+        Ok(())
+    }
+}
+
 #[derive(EguiProbe)]
 #[egui_probe(transparent)]
 struct UpTo7(#[egui_probe(range = ..=7)] u32);
@@ -51,15 +59,29 @@ impl Default for ComboBoxTags {
     }
 }
 
+#[derive(Default, EguiProbe)]
+struct Particle {
+    name: String,
+    mass: f32,
+    visible: bool,
+}
+
 #[derive(EguiProbe)]
 struct DemoValue {
+    /// Doc comments are shown as tooltips.
     boolean: bool,
 
-    #[egui_probe(toggle_switch)]
+    /// Tooltip may be overridden with `tooltip` attribute.
+    #[egui_probe(toggle_switch, tooltip = "Boolean shown as toggle switch")]
     boolean_toggle: bool,
 
+    /// Marked when modified from default, with button to reset it.
+    #[egui_probe(default = 0.5)]
     float: f32,
 
+    #[egui_probe(validate = not_empty)]
+    name: String,
+
     #[egui_probe(range = 22..=55)]
     range: usize,
 
@@ -77,6 +99,9 @@ struct DemoValue {
     #[egui_probe(name = "renamed ^_^")]
     renamed: u8,
 
+    #[egui_probe(readonly)]
+    frame_count: u64,
+
     maybe_boolean: Option<bool>,
 
     inner: InnerValue,
@@ -87,19 +112,35 @@ struct DemoValue {
 
     array: [u8; 3],
 
+    /// Right-click the label to copy, paste or reset the value.
+    #[egui_probe(clipboard)]
     vector: Vec<bool>,
 
     #[egui_probe(frozen)]
     frozen_vector: Vec<bool>,
 
+    /// Click a byte and type or paste hex digits to overwrite it.
+    #[egui_probe(hex)]
+    packet: Vec<u8>,
+
+    /// Shown as a grid, click column headers to sort rows.
+    #[egui_probe(table)]
+    particles: Vec<Particle>,
+
     map: HashMap<String, u32>,
 
     #[egui_probe(frozen)]
     frozen_map: HashMap<String, u32>,
+
+    deque: VecDeque<u8>,
+
+    btree_map: BTreeMap<u32, String>,
+
+    set: BTreeSet<String>,
 }
 
 #[derive(Default, EguiProbe)]
-#[egui_probe(rename_all = Train-Case)]
+#[egui_probe(rename_all = Train-Case, defaults)]
 struct InnerValue {
     line: String,
 
@@ -127,12 +168,14 @@ impl EguiProbeDemoApp {
                 boolean: false,
                 boolean_toggle: false,
                 float: 0.0,
+                name: "Probe".to_owned(),
                 range: 22,
                 range_to: UpTo7(0),
                 range_from: 100,
                 angle: 0.0,
                 custom: Foo,
                 renamed: 0,
+                frame_count: 0,
                 maybe_boolean: None,
                 inner: InnerValue {
                     line: "Hello, world!".to_owned(),
@@ -149,6 +192,14 @@ impl EguiProbeDemoApp {
                 array: [0, 1, 2],
                 vector: vec![false, true, false],
                 frozen_vector: vec![false, true, false],
+                packet: b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n".to_vec(),
+                particles: (0..1000)
+                    .map(|idx| Particle {
+                        name: format!("particle {idx}"),
+                        mass: (idx % 7) as f32,
+                        visible: idx % 2 == 0,
+                    })
+                    .collect(),
 
                 map: {
                     let mut map = HashMap::new();
@@ -163,6 +214,10 @@ impl EguiProbeDemoApp {
                     map.insert("bar".to_owned(), 2);
                     map
                 },
+
+                deque: VecDeque::from([1, 2, 3]),
+                btree_map: BTreeMap::from([(1, "one".to_owned()), (2, "two".to_owned())]),
+                set: BTreeSet::from(["foo".to_owned(), "bar".to_owned()]),
             },
         }
     }
@@ -170,13 +225,15 @@ impl EguiProbeDemoApp {
 
 impl eframe::App for EguiProbeDemoApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.value.frame_count += 1;
+
         egui::TopBottomPanel::top("header").show(ctx, |ui| {
             egui::widgets::global_dark_light_mode_switch(ui);
         });
 
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::ScrollArea::vertical().show(ui, |ui|{
-                Probe::new("Value", &mut self.value).show(ui);
+                Probe::new("Value", &mut self.value).filter(true).show(ui);
             });
         });
     }
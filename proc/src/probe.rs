@@ -22,10 +22,23 @@ proc_easy::easy_token!(tags);
 proc_easy::easy_token!(inlined);
 proc_easy::easy_token!(combobox);
 proc_easy::easy_token!(frozen);
+proc_easy::easy_token!(table);
+proc_easy::easy_token!(hex);
+proc_easy::easy_token!(normalized);
+proc_easy::easy_token!(path);
 proc_easy::easy_token!(rgb);
 proc_easy::easy_token!(rgba);
 proc_easy::easy_token!(rgba_premultiplied);
 proc_easy::easy_token!(rgba_unmultiplied);
+proc_easy::easy_token!(readonly);
+proc_easy::easy_token!(tooltip);
+proc_easy::easy_token!(no_tooltip);
+proc_easy::easy_token!(validate);
+proc_easy::easy_token!(serde);
+proc_easy::easy_token!(clipboard);
+proc_easy::easy_token!(multi);
+proc_easy::easy_token!(default);
+proc_easy::easy_token!(defaults);
 
 proc_easy::easy_parse! {
     #[derive(Clone, Copy)]
@@ -111,6 +124,61 @@ proc_easy::easy_argument_value! {
     }
 }
 
+/// Restrictions of the file browser in `path(extensions = ["png", "jpg"], dir_only)`.
+/// Parentheses may be omitted if there are no restrictions.
+struct PathFilter {
+    extensions: Vec<LitStr>,
+    dir_only: bool,
+}
+
+impl syn::parse::Parse for PathFilter {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut filter = PathFilter {
+            extensions: Vec::new(),
+            dir_only: false,
+        };
+
+        if !input.peek(syn::token::Paren) {
+            return Ok(filter);
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        while !content.is_empty() {
+            let ident: syn::Ident = content.parse()?;
+            if ident == "extensions" {
+                content.parse::<syn::Token![=]>()?;
+                let list;
+                syn::bracketed!(list in content);
+                let extensions =
+                    syn::punctuated::Punctuated::<LitStr, syn::Token![,]>::parse_terminated(&list)?;
+                filter.extensions.extend(extensions);
+            } else if ident == "dir_only" {
+                filter.dir_only = true;
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "Expected `extensions = [...]` or `dir_only`",
+                ));
+            }
+
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+proc_easy::easy_argument! {
+    struct FilePath {
+        path: path,
+        filter: PathFilter,
+    }
+}
+
 proc_easy::easy_argument_value! {
     struct Name {
         name: name,
@@ -118,6 +186,32 @@ proc_easy::easy_argument_value! {
     }
 }
 
+proc_easy::easy_argument_value! {
+    struct Tooltip {
+        tooltip: tooltip,
+        literal: syn::LitStr,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct Validate {
+        validate: validate,
+
+        /// Function must implement `Fn(&T) -> Result<(), E>`
+        /// where `T` is field or container type and `E: Into<::egui_probe::Validation>`.
+        path: syn::Path,
+    }
+}
+
+proc_easy::easy_argument_value! {
+    struct DefaultExpr {
+        default: default,
+
+        /// Expression of the field type.
+        expr: syn::Expr,
+    }
+}
+
 proc_easy::easy_argument_group! {
     enum FieldProbeKind {
         Range(Range),
@@ -126,6 +220,10 @@ proc_easy::easy_argument_group! {
         Multiline(multiline),
         ToggleSwitch(toggle_switch),
         Frozen(frozen),
+        Table(table),
+        Hex(hex),
+        Normalized(normalized),
+        FilePath(FilePath),
         Rgb(rgb),
         Rgba(rgba),
         RgbaPremultiplied(rgba_premultiplied),
@@ -133,6 +231,13 @@ proc_easy::easy_argument_group! {
     }
 }
 
+/// Error for an attribute used together with `skip`.
+macro_rules! skipped_error {
+    ($name:literal) => {
+        concat!("Cannot use `", $name, "` attribute for skipped field")
+    };
+}
+
 impl FieldProbeKind {
     fn span(&self) -> proc_macro2::Span {
         match self {
@@ -142,6 +247,10 @@ impl FieldProbeKind {
             FieldProbeKind::Multiline(multiline) => multiline.span(),
             FieldProbeKind::ToggleSwitch(toggle_switch) => toggle_switch.span(),
             FieldProbeKind::Frozen(frozen) => frozen.span(),
+            FieldProbeKind::Table(table) => table.span(),
+            FieldProbeKind::Hex(hex) => hex.span(),
+            FieldProbeKind::Normalized(normalized) => normalized.span(),
+            FieldProbeKind::FilePath(file_path) => file_path.path.span(),
             FieldProbeKind::Rgb(rgb) => rgb.span(),
             FieldProbeKind::Rgba(rgba) => rgba.span(),
             FieldProbeKind::RgbaPremultiplied(rgba_premultiplied) => rgba_premultiplied.span(),
@@ -150,23 +259,21 @@ impl FieldProbeKind {
     }
 
     fn error_when_skipped(&self) -> &'static str {
-        macro_rules! format_error {
-            ($name:literal) => {
-                concat!("Cannot use `", $name, "` attribute for skipped field")
-            };
-        }
-
         match self {
-            FieldProbeKind::With(_) => format_error!("with"),
-            FieldProbeKind::ProbeAs(_) => format_error!("as"),
-            FieldProbeKind::Range(_) => format_error!("range"),
-            FieldProbeKind::Multiline(_) => format_error!("multiline"),
-            FieldProbeKind::ToggleSwitch(_) => format_error!("toggle_switch"),
-            FieldProbeKind::Frozen(_) => format_error!("frozen"),
-            FieldProbeKind::Rgb(_) => format_error!("rgb"),
-            FieldProbeKind::Rgba(_) => format_error!("rgba"),
-            FieldProbeKind::RgbaPremultiplied(_) => format_error!("rgba_premultiplied"),
-            FieldProbeKind::RgbaUnmultiplied(_) => format_error!("rgba_unmultiplied"),
+            FieldProbeKind::With(_) => skipped_error!("with"),
+            FieldProbeKind::ProbeAs(_) => skipped_error!("as"),
+            FieldProbeKind::Range(_) => skipped_error!("range"),
+            FieldProbeKind::Multiline(_) => skipped_error!("multiline"),
+            FieldProbeKind::ToggleSwitch(_) => skipped_error!("toggle_switch"),
+            FieldProbeKind::Frozen(_) => skipped_error!("frozen"),
+            FieldProbeKind::Table(_) => skipped_error!("table"),
+            FieldProbeKind::Hex(_) => skipped_error!("hex"),
+            FieldProbeKind::Normalized(_) => skipped_error!("normalized"),
+            FieldProbeKind::FilePath(_) => skipped_error!("path"),
+            FieldProbeKind::Rgb(_) => skipped_error!("rgb"),
+            FieldProbeKind::Rgba(_) => skipped_error!("rgba"),
+            FieldProbeKind::RgbaPremultiplied(_) => skipped_error!("rgba_premultiplied"),
+            FieldProbeKind::RgbaUnmultiplied(_) => skipped_error!("rgba_unmultiplied"),
         }
     }
 }
@@ -179,6 +286,64 @@ proc_easy::easy_attributes! {
         skip: Option<skip>,
         name: Option<Name>,
         kind : Option<FieldProbeKind>,
+        // If `readonly` is present, the field is shown without allowing to edit it.
+        // Can be combined with any kind.
+        readonly: Option<readonly>,
+        // Tooltip for the field label. Doc comments are used by default.
+        tooltip: Option<Tooltip>,
+        no_tooltip: Option<no_tooltip>,
+        // Validator for the field value.
+        validate: Option<Validate>,
+        // If `serde` is present, the field can be edited as text from the label context menu.
+        serde: Option<serde>,
+        // If `clipboard` is present, the field can be copied, pasted and reset from the label context menu.
+        clipboard: Option<clipboard>,
+        // If `multi` is present, the field is compared and assigned as a whole when edited with `ProbeMulti`.
+        multi: Option<multi>,
+        // Default value of the field. Field is marked when modified and can be reset.
+        // Overrides default taken from the container with `defaults` attribute.
+        default: Option<DefaultExpr>,
+    }
+}
+
+impl FieldAttributes {
+    /// Returns `true` if the field is skipped.
+    /// Fails if other attributes are present together with `skip`.
+    fn is_skipped(&self) -> syn::Result<bool> {
+        if self.skip.is_none() {
+            return Ok(false);
+        }
+
+        if let Some(name) = &self.name {
+            return Err(syn::Error::new_spanned(
+                name.name,
+                "Cannot name skipped field",
+            ));
+        }
+
+        if let Some(kind) = &self.kind {
+            return Err(syn::Error::new(kind.span(), kind.error_when_skipped()));
+        }
+
+        let error = if let Some(readonly) = &self.readonly {
+            syn::Error::new(readonly.span(), skipped_error!("readonly"))
+        } else if let Some(tooltip) = &self.tooltip {
+            syn::Error::new_spanned(tooltip.tooltip, skipped_error!("tooltip"))
+        } else if let Some(validate) = &self.validate {
+            syn::Error::new_spanned(validate.validate, skipped_error!("validate"))
+        } else if let Some(serde) = &self.serde {
+            syn::Error::new(serde.span(), skipped_error!("serde"))
+        } else if let Some(clipboard) = &self.clipboard {
+            syn::Error::new(clipboard.span(), skipped_error!("clipboard"))
+        } else if let Some(multi) = &self.multi {
+            syn::Error::new(multi.span(), skipped_error!("multi"))
+        } else if let Some(default) = &self.default {
+            syn::Error::new(default.default.span(), skipped_error!("default"))
+        } else {
+            return Ok(true);
+        };
+
+        Err(error)
     }
 }
 
@@ -210,6 +375,15 @@ proc_easy::easy_attributes! {
         where_clause: Option<WhereClause>,
         transparent: Option<transparent>,
         tags: Option<EnumTags>,
+        readonly: Option<readonly>,
+        validate: Option<Validate>,
+        serde: Option<serde>,
+        clipboard: Option<clipboard>,
+        multi: Option<multi>,
+        // If `defaults` is present, fields are marked when modified and can be reset.
+        // Struct defaults are taken from its `Default` implementation,
+        // enum variant fields use `Default` implementation of their types.
+        defaults: Option<defaults>,
     }
 }
 
@@ -218,6 +392,9 @@ proc_easy::easy_attributes! {
     struct VariantAttributes {
         name: Option<Name>,
         transparent: Option<transparent>,
+        // Tooltip for the variant selector. Doc comments are used by default.
+        tooltip: Option<Tooltip>,
+        no_tooltip: Option<no_tooltip>,
     }
 }
 
@@ -236,21 +413,63 @@ fn make_name(
     }
 }
 
-fn field_name(field: &syn::Field, rename_case: Option<RenameCase>) -> syn::Result<Option<LitStr>> {
-    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+/// Collects doc comments into tooltip text.
+fn doc_tooltip(attrs: &[syn::Attribute]) -> Option<LitStr> {
+    let mut span = None;
+    let mut lines = Vec::new();
 
-    if attributes.skip.is_some() {
-        if let Some(name) = attributes.name {
-            return Err(syn::Error::new_spanned(
-                name.name,
-                "Cannot name skipped field",
-            ));
+    for attr in attrs {
+        if !attr.path().is_ident("doc") {
+            continue;
         }
 
-        if let Some(kind) = attributes.kind {
-            return Err(syn::Error::new(kind.span(), kind.error_when_skipped()));
+        if let syn::Meta::NameValue(syn::MetaNameValue {
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }),
+            ..
+        }) = &attr.meta
+        {
+            span.get_or_insert(lit.span());
+            let line = lit.value();
+            match line.strip_prefix(' ') {
+                Some(line) => lines.push(line.to_owned()),
+                None => lines.push(line),
+            }
         }
+    }
 
+    let text = lines.join("\n");
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    Some(LitStr::new(text, span.unwrap()))
+}
+
+fn make_tooltip(
+    tooltip: Option<Tooltip>,
+    no_tooltip: Option<no_tooltip>,
+    attrs: &[syn::Attribute],
+) -> syn::Result<Option<LitStr>> {
+    match (tooltip, no_tooltip) {
+        (Some(_), Some(no_tooltip)) => Err(syn::Error::new(
+            no_tooltip.span(),
+            "Cannot use `tooltip` and `no_tooltip` together",
+        )),
+        (Some(tooltip), None) => Ok(Some(tooltip.literal)),
+        (None, Some(_)) => Ok(None),
+        (None, None) => Ok(doc_tooltip(attrs)),
+    }
+}
+
+fn field_name(field: &syn::Field, rename_case: Option<RenameCase>) -> syn::Result<Option<LitStr>> {
+    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+    if attributes.is_skipped()? {
         return Ok(None);
     }
 
@@ -260,25 +479,64 @@ fn field_name(field: &syn::Field, rename_case: Option<RenameCase>) -> syn::Resul
 }
 
 fn field_probe(idx: usize, field: &syn::Field) -> syn::Result<Option<proc_macro2::TokenStream>> {
-    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
-
-    if attributes.skip.is_some() {
-        if let Some(name) = attributes.name {
-            return Err(syn::Error::new_spanned(
-                name.name,
-                "Cannot name skipped field",
-            ));
-        }
+    Ok(field_probe_access(idx, field)?.map(|(probe, _)| probe))
+}
 
-        if let Some(kind) = attributes.kind {
-            return Err(syn::Error::new(kind.span(), kind.error_when_skipped()));
-        }
+/// Returns probe expression of the field together with field accesses
+/// that reach the field from the probe, as in `probe #access`.
+///
+/// Field can't be reached within the probe built by `as` attribute.
+fn field_probe_access(
+    idx: usize,
+    field: &syn::Field,
+) -> syn::Result<Option<(proc_macro2::TokenStream, Option<proc_macro2::TokenStream>)>> {
+    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
 
+    if attributes.is_skipped()? {
         return Ok(None);
     }
 
     let binding = quote::format_ident!("___{}", idx);
 
+    if let (Some(serde), Some(_)) = (&attributes.serde, &attributes.kind) {
+        return Err(syn::Error::new(
+            serde.span(),
+            "Cannot combine `serde` attribute with `with`, `as`, `range` and similar attributes",
+        ));
+    }
+
+    if let (Some(clipboard), Some(_)) = (&attributes.clipboard, &attributes.kind) {
+        return Err(syn::Error::new(
+            clipboard.span(),
+            "Cannot combine `clipboard` attribute with `with`, `as`, `range` and similar attributes",
+        ));
+    }
+
+    if let (Some(multi), Some(_)) = (&attributes.multi, &attributes.kind) {
+        return Err(syn::Error::new(
+            multi.span(),
+            "Cannot combine `multi` attribute with `with`, `as`, `range` and similar attributes",
+        ));
+    }
+
+    if let (Some(validate), Some(FieldProbeKind::ProbeAs(_))) =
+        (&attributes.validate, &attributes.kind)
+    {
+        return Err(syn::Error::new_spanned(
+            validate.validate,
+            "Cannot combine `validate` attribute with `as` attribute",
+        ));
+    }
+
+    let probe_as = matches!(attributes.kind, Some(FieldProbeKind::ProbeAs(_)));
+
+    // Field accesses leading to the field from the probe.
+    let access = match &attributes.kind {
+        None => proc_macro2::TokenStream::new(),
+        Some(FieldProbeKind::Multiline(_)) => quote::quote!(.string),
+        Some(_) => quote::quote!(.value),
+    };
+
     let tokens = match attributes.kind {
         None => {
             quote::quote_spanned! {field.span() =>
@@ -318,6 +576,34 @@ fn field_probe(idx: usize, field: &syn::Field) -> syn::Result<Option<proc_macro2
                 &mut probe_frozen(#binding)
             }
         }
+        Some(FieldProbeKind::Table(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut probe_table(#binding)
+            }
+        }
+        Some(FieldProbeKind::Hex(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut probe_hex(#binding)
+            }
+        }
+        Some(FieldProbeKind::Normalized(_)) => {
+            quote::quote_spanned! {field.span() =>
+                &mut probe_normalized(#binding)
+            }
+        }
+        Some(FieldProbeKind::FilePath(file_path)) => {
+            let extensions = file_path.filter.extensions;
+            let dir_only = file_path.filter.dir_only;
+            quote::quote_spanned! {field.span() =>
+                &mut probe_file_path(
+                    ::egui_probe::PathFilter {
+                        extensions: &[#(#extensions),*],
+                        dir_only: #dir_only,
+                    },
+                    #binding,
+                )
+            }
+        }
         Some(FieldProbeKind::Rgb(_)) => {
             quote::quote_spanned! {field.span() =>
                 &mut probe_rgb(#binding)
@@ -340,7 +626,197 @@ fn field_probe(idx: usize, field: &syn::Field) -> syn::Result<Option<proc_macro2
         }
     };
 
-    Ok(Some(tokens))
+    let access = match attributes.serde {
+        None => access,
+        Some(_) => quote::quote!(.value #access),
+    };
+
+    let tokens = match attributes.serde {
+        None => tokens,
+        Some(_) => quote::quote_spanned! {field.span() =>
+            &mut probe_text_value(#tokens)
+        },
+    };
+
+    let access = match attributes.clipboard {
+        None => access,
+        Some(_) => quote::quote!(.value #access),
+    };
+
+    let tokens = match attributes.clipboard {
+        None => tokens,
+        Some(_) => quote::quote_spanned! {field.span() =>
+            &mut probe_clipboard(#tokens)
+        },
+    };
+
+    let access = match attributes.multi {
+        None => access,
+        Some(_) => quote::quote!(.value #access),
+    };
+
+    let tokens = match attributes.multi {
+        None => tokens,
+        Some(_) => quote::quote_spanned! {field.span() =>
+            &mut probe_multi(#tokens)
+        },
+    };
+
+    let access = match attributes.readonly {
+        None => access,
+        Some(_) => quote::quote!(.value #access),
+    };
+
+    let tokens = match attributes.readonly {
+        None => tokens,
+        Some(_) => quote::quote_spanned! {field.span() =>
+            &mut probe_read_only(#tokens)
+        },
+    };
+
+    let (tokens, access) = match attributes.validate {
+        None => (tokens, access),
+        Some(validate) => {
+            let path = validate.path;
+            let tokens = quote::quote_spanned! {field.span() =>
+                &mut probe_validated(|probe| #path(&*probe #access), #tokens)
+            };
+            (tokens, quote::quote!(.value #access))
+        }
+    };
+
+    let tooltip = make_tooltip(attributes.tooltip, attributes.no_tooltip, &field.attrs)?;
+
+    let (tokens, access) = match tooltip {
+        None => (tokens, access),
+        Some(tooltip) => {
+            let tokens = quote::quote_spanned! {field.span() =>
+                &mut probe_tooltip(#tooltip, #tokens)
+            };
+            (tokens, quote::quote!(.value #access))
+        }
+    };
+
+    let access = if probe_as { None } else { Some(access) };
+
+    Ok(Some((tokens, access)))
+}
+
+/// Expressions of the field default.
+struct FieldDefault {
+    /// Default the field is compared with, may be a place.
+    compare: proc_macro2::TokenStream,
+
+    /// Default the field is reset to.
+    reset: proc_macro2::TokenStream,
+}
+
+/// Returns the field default if the field is marked when modified.
+///
+/// `container_default` is used for fields without `default` attribute
+/// if container has `defaults` attribute.
+fn field_default(
+    field: &syn::Field,
+    container_default: Option<FieldDefault>,
+) -> syn::Result<Option<FieldDefault>> {
+    let attributes: FieldAttributes = proc_easy::EasyAttributes::parse(&field.attrs, field.span())?;
+
+    if attributes.skip.is_some() {
+        return Ok(None);
+    }
+
+    if let Some(readonly) = attributes.readonly {
+        if attributes.default.is_some() {
+            return Err(syn::Error::new(
+                readonly.span(),
+                "Cannot combine `readonly` attribute with `default` attribute",
+            ));
+        }
+        return Ok(None);
+    }
+
+    match attributes.default {
+        Some(default) => {
+            let expr = default.expr;
+            Ok(Some(FieldDefault {
+                compare: quote::quote!((#expr)),
+                reset: quote::quote!(#expr),
+            }))
+        }
+        None => Ok(container_default),
+    }
+}
+
+/// Calls `iterate_inner` callback for the field.
+///
+/// Fields with default are compared with it only when asked,
+/// through `access` to the field within the probe.
+/// Reset requested during the call is applied after it.
+fn field_iterate_inner(
+    idx: usize,
+    name: &LitStr,
+    probe: &proc_macro2::TokenStream,
+    access: Option<&proc_macro2::TokenStream>,
+    default: Option<&FieldDefault>,
+) -> proc_macro2::TokenStream {
+    let binding = quote::format_ident!("___{}", idx);
+
+    let Some(FieldDefault { compare, reset }) = default else {
+        return quote::quote! {
+            _f(#name, #probe);
+        };
+    };
+
+    let is_default = match access {
+        Some(access) => quote::quote!(|probe| *probe #access == #compare),
+
+        // Field can't be reached within the probe, compare it beforehand.
+        None => quote::quote! {{
+            let __is_default = *#binding == #compare;
+            move |_| __is_default
+        }},
+    };
+
+    quote::quote! {
+        {
+            let mut __reset = false;
+            _f(#name, &mut probe_reset(#is_default, &mut __reset, #probe));
+            if __reset {
+                *#binding = #reset;
+            }
+        }
+    }
+}
+
+/// Calls `iterate_inner` callback for each non-skipped field.
+fn fields_iterate_inner(
+    fields: &syn::Fields,
+    rename_case: Option<RenameCase>,
+    container_default: &dyn Fn(usize, &syn::Field) -> Option<FieldDefault>,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let mut tokens = Vec::new();
+
+    for (idx, field) in fields.iter().enumerate() {
+        let name = match field_name(field, rename_case)? {
+            None => continue,
+            Some(name) => name,
+        };
+        let (probe, access) = match field_probe_access(idx, field)? {
+            None => continue,
+            Some(probe) => probe,
+        };
+        let default = field_default(field, container_default(idx, field))?;
+
+        tokens.push(field_iterate_inner(
+            idx,
+            &name,
+            &probe,
+            access.as_ref(),
+            default.as_ref(),
+        ));
+    }
+
+    Ok(tokens)
 }
 
 fn variant_selected(
@@ -403,10 +879,17 @@ fn variant_probe(
         syn::Fields::Named(_) => quote::quote! {Self::#ident {..}},
     };
 
+    let tooltip = make_tooltip(attributes.tooltip, attributes.no_tooltip, &variant.attrs)?;
+    let on_hover_text = match tooltip {
+        None => quote::quote!(),
+        Some(tooltip) => quote::quote!(.on_hover_text(#tooltip)),
+    };
+
     let tokens = quote::quote_spanned! {variant.ident.span() =>
         let checked = match self { #pattern => true, _ => false };
-        if _ui.selectable_label(checked, #name).clicked() && !checked {
+        if _ui.selectable_label(checked, #name)#on_hover_text.clicked() && !checked {
             *self = #construct;
+            _changed = true;
         }
         // if _ui.selectable_label(checked, #name).clicked() {
         //     if !checked {
@@ -466,7 +949,7 @@ fn variant_inline_probe(variant: &syn::Variant) -> syn::Result<proc_macro2::Toke
 
         let tokens = quote::quote_spanned! {variant.ident.span() =>
             #pattern => {
-                ::egui_probe::EguiProbe::probe(#field_probe, _ui, _style);
+                _changed |= ::egui_probe::EguiProbe::probe(#field_probe, _ui, _style).changed();
             }
         };
 
@@ -554,6 +1037,7 @@ fn variant_has_inner(variant: &syn::Variant) -> syn::Result<proc_macro2::TokenSt
 fn variant_iterate_inner(
     variant: &syn::Variant,
     rename_case: Option<RenameCase>,
+    defaults: bool,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let attributes: VariantAttributes =
         proc_easy::EasyAttributes::parse(&variant.attrs, variant.span())?;
@@ -603,24 +1087,22 @@ fn variant_iterate_inner(
 
         Ok(tokens)
     } else {
-        let fields_name: Vec<_> = variant
-            .fields
-            .iter()
-            .filter_map(|field| field_name(field, rename_case).transpose())
-            .collect::<syn::Result<_>>()?;
-
-        let fields_probe: Vec<_> = variant
-            .fields
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, field)| field_probe(idx, field).transpose())
-            .collect::<syn::Result<_>>()?;
-
-        assert_eq!(fields_name.len(), fields_probe.len());
+        let fields_iterate_inner =
+            fields_iterate_inner(&variant.fields, rename_case, &|_, field| {
+                if !defaults {
+                    return None;
+                }
+                let ty = &field.ty;
+                let default = quote::quote!(<#ty as ::core::default::Default>::default());
+                Some(FieldDefault {
+                    compare: default.clone(),
+                    reset: default,
+                })
+            })?;
 
         let tokens = quote::quote_spanned! {variant.ident.span() =>
             #pattern => {
-                #(_f(#fields_name, #fields_probe))*
+                #(#fields_iterate_inner)*
             },
         };
 
@@ -628,12 +1110,111 @@ fn variant_iterate_inner(
     }
 }
 
+/// Shadows style with read-only one if whole value is read-only.
+fn read_only_style(read_only: bool, style: &syn::Ident) -> proc_macro2::TokenStream {
+    if !read_only {
+        return proc_macro2::TokenStream::new();
+    }
+
+    quote::quote! {
+        let #style = &read_only_style(#style);
+    }
+}
+
+/// Shadows callback of `iterate_inner` to make all inner values read-only
+/// if whole value is read-only.
+fn read_only_inner(read_only: bool, f: &syn::Ident) -> proc_macro2::TokenStream {
+    if !read_only {
+        return proc_macro2::TokenStream::new();
+    }
+
+    quote::quote! {
+        let #f: &mut dyn FnMut(&str, &mut dyn ::egui_probe::EguiProbe) =
+            &mut |label: &str, value: &mut dyn ::egui_probe::EguiProbe| {
+                #f(label, &mut probe_read_only(value))
+            };
+    }
+}
+
+/// Calls container validator and returns early if it fails.
+fn container_validate(validate: Option<&Validate>) -> proc_macro2::TokenStream {
+    match validate {
+        None => proc_macro2::TokenStream::new(),
+        Some(validate) => {
+            let path = &validate.path;
+            quote::quote! {
+                #path(&*self).map_err(::core::convert::Into::<::egui_probe::Validation>::into)?;
+            }
+        }
+    }
+}
+
+/// Implements `EguiProbe::as_text_value` if the type can be edited as text.
+fn text_value_method(serde: bool) -> proc_macro2::TokenStream {
+    if !serde {
+        return proc_macro2::TokenStream::new();
+    }
+
+    quote::quote! {
+        fn as_text_value(&mut self) -> ::core::option::Option<&mut dyn ::egui_probe::TextValue> {
+            ::core::option::Option::Some(self)
+        }
+    }
+}
+
+/// Implements `EguiProbe::as_clipboard_value` if the type can be copied, pasted and reset.
+fn clipboard_value_method(clipboard: bool) -> proc_macro2::TokenStream {
+    if !clipboard {
+        return proc_macro2::TokenStream::new();
+    }
+
+    quote::quote! {
+        fn as_clipboard_value(&mut self) -> ::core::option::Option<&mut dyn ::egui_probe::ClipboardValue> {
+            ::core::option::Option::Some(self)
+        }
+    }
+}
+
+/// Implements `EguiProbe::as_multi_value` if the type can be edited with `ProbeMulti` as a whole.
+fn multi_value_method(multi: bool) -> proc_macro2::TokenStream {
+    if !multi {
+        return proc_macro2::TokenStream::new();
+    }
+
+    quote::quote! {
+        fn as_multi_value(&mut self) -> ::core::option::Option<&mut dyn ::egui_probe::MultiValue> {
+            ::core::option::Option::Some(self)
+        }
+    }
+}
+
+/// Implements `EguiProbe::validate` using container validator.
+fn validate_method(validate: Option<&Validate>) -> proc_macro2::TokenStream {
+    if validate.is_none() {
+        return proc_macro2::TokenStream::new();
+    }
+
+    let container_validate = container_validate(validate);
+
+    quote::quote! {
+        fn validate(&mut self) -> ::core::result::Result<(), ::egui_probe::Validation> {
+            #container_validate
+            ::core::result::Result::Ok(())
+        }
+    }
+}
+
 pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let ident = &input.ident;
     let generics = &input.generics;
 
     let attributes: TypeAttributes = proc_easy::EasyAttributes::parse(&input.attrs, ident.span())?;
     let rename_case = attributes.rename_all.map(|rename_all| rename_all.case);
+    let read_only = attributes.readonly.is_some();
+    let text_value_method = text_value_method(attributes.serde.is_some());
+    let clipboard_value_method = clipboard_value_method(attributes.clipboard.is_some());
+    let multi_value_method = multi_value_method(attributes.multi.is_some());
+    let defaults = attributes.defaults.is_some();
 
     let (impl_generics, ty_generics, mut where_clause) = generics.split_for_impl();
 
@@ -695,6 +1276,10 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
 
                 let field_probe = &fields_probe[0];
 
+                let read_only_style = read_only_style(read_only, &quote::format_ident!("style"));
+                let read_only_inner = read_only_inner(read_only, &quote::format_ident!("f"));
+                let container_validate = container_validate(attributes.validate.as_ref());
+
                 let tokens = quote::quote! {
                     impl #impl_generics ::egui_probe::EguiProbe for #ident #ty_generics
                     #where_clause
@@ -702,6 +1287,7 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                         fn probe(&mut self, ui: &mut ::egui_probe::egui::Ui, style: &::egui_probe::Style) -> ::egui_probe::egui::Response {
                             use ::egui_probe::private::*;
 
+                            #read_only_style
                             let #pattern = self;
 
                             ::egui_probe::EguiProbe::probe(#field_probe, ui, style)
@@ -718,19 +1304,58 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                         fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn ::egui_probe::EguiProbe)) {
                             use ::egui_probe::private::*;
 
+                            #read_only_inner
                             let #pattern = self;
 
                             ::egui_probe::EguiProbe::iterate_inner(#field_probe, f)
                         }
+
+                        fn validate(&mut self) -> ::core::result::Result<(), ::egui_probe::Validation> {
+                            use ::egui_probe::private::*;
+
+                            #container_validate
+                            let #pattern = self;
+
+                            ::egui_probe::EguiProbe::validate(#field_probe)
+                        }
+
+                        #text_value_method
+
+                        #clipboard_value_method
+
+                        #multi_value_method
                     }
                 };
                 Ok(tokens)
             } else {
-                let fields_name: Vec<_> = data
-                    .fields
-                    .iter()
-                    .filter_map(|field| field_name(field, rename_case).transpose())
-                    .collect::<syn::Result<_>>()?;
+                // Field defaults are taken from the default of the struct,
+                // built once when first compared with.
+                let fields_iterate_inner =
+                    fields_iterate_inner(&data.fields, rename_case, &|idx, field| {
+                        if !defaults {
+                            return None;
+                        }
+                        let member = match &field.ident {
+                            Some(ident) => syn::Member::Named(ident.clone()),
+                            None => syn::Member::Unnamed(syn::Index::from(idx)),
+                        };
+                        let default = quote::quote!(<Self as ::core::default::Default>::default);
+                        Some(FieldDefault {
+                            compare: quote::quote!(__defaults.get_or_init(#default).#member),
+                            reset: quote::quote!(__defaults.take().unwrap_or_default().#member),
+                        })
+                    })?;
+
+                let container_defaults = match data.fields {
+                    syn::Fields::Named(_) | syn::Fields::Unnamed(_) if defaults => quote::quote! {
+                        #[allow(unused_mut, unused_variables)]
+                        let mut __defaults = ::core::cell::OnceCell::<Self>::new();
+                    },
+                    _ => proc_macro2::TokenStream::new(),
+                };
+
+                let read_only_inner = read_only_inner(read_only, &quote::format_ident!("_f"));
+                let validate_method = validate_method(attributes.validate.as_ref());
 
                 let tokens = quote::quote! {
                     impl #impl_generics ::egui_probe::EguiProbe for #ident #ty_generics
@@ -747,12 +1372,20 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                         fn iterate_inner(&mut self, _f: &mut dyn FnMut(&str, &mut dyn ::egui_probe::EguiProbe)) {
                             use ::egui_probe::private::*;
 
+                            #read_only_inner
+                            #container_defaults
                             let #pattern = self;
 
-                            #(
-                                _f(#fields_name, #fields_probe);
-                            )*
+                            #(#fields_iterate_inner)*
                         }
+
+                        #validate_method
+
+                        #text_value_method
+
+                        #clipboard_value_method
+
+                        #multi_value_method
                     }
                 };
                 Ok(tokens)
@@ -787,7 +1420,7 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
             let variants_iterate_inner = data
                 .variants
                 .iter()
-                .map(|variant| variant_iterate_inner(variant, rename_case))
+                .map(|variant| variant_iterate_inner(variant, rename_case, defaults))
                 .collect::<syn::Result<Vec<_>>>()?;
 
             let variants_style = match attributes.tags {
@@ -802,6 +1435,10 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                 }) => quote::quote!(::egui_probe::VariantsStyle::ComboBox),
             };
 
+            let read_only_style = read_only_style(read_only, &quote::format_ident!("_style"));
+            let read_only_inner = read_only_inner(read_only, &quote::format_ident!("_f"));
+            let validate_method = validate_method(attributes.validate.as_ref());
+
             let tokens = quote::quote! {
                 impl #impl_generics ::egui_probe::EguiProbe for #ident #ty_generics
                     #where_clause
@@ -809,30 +1446,46 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                         fn probe(&mut self, ui: &mut ::egui_probe::egui::Ui, _style: &::egui_probe::Style) -> ::egui_probe::egui::Response {
                             use ::egui_probe::private::*;
 
-                            ui.horizontal(|_ui| {
-                                match #variants_style {
-                                    ::egui_probe::VariantsStyle::Inlined => {
-                                        let _in_cbox = false;
-                                        #(
-                                            #variants_probe
-                                        )*
-                                    }
-                                    ::egui_probe::VariantsStyle::ComboBox => {
-                                        let selected_variant = match self { #(#variants_selected,)* };
-                                        let cbox = ::egui_probe::egui::ComboBox::from_id_source(_ui.make_persistent_id("cbox")).selected_text(selected_variant);
-                                        let _in_cbox = true;
-                                        cbox.show_ui(_ui, |_ui| {
+                            #read_only_style
+                            let r = ui.horizontal(|_ui| {
+                                let mut _changed = false;
+
+                                if _style.read_only {
+                                    let selected_variant = match self { #(#variants_selected,)* };
+                                    _ui.label(selected_variant);
+                                } else {
+                                    match #variants_style {
+                                        ::egui_probe::VariantsStyle::Inlined => {
+                                            let _in_cbox = false;
                                             #(
-                                                #variants_probe;
+                                                #variants_probe
                                             )*
-                                        });
+                                        }
+                                        ::egui_probe::VariantsStyle::ComboBox => {
+                                            let selected_variant = match self { #(#variants_selected,)* };
+                                            let cbox = ::egui_probe::egui::ComboBox::from_id_source(_ui.make_persistent_id("cbox")).selected_text(selected_variant);
+                                            let _in_cbox = true;
+                                            cbox.show_ui(_ui, |_ui| {
+                                                #(
+                                                    #variants_probe;
+                                                )*
+                                            });
+                                        }
                                     }
                                 }
 
                                 match self {#(
                                     #variants_inline_probe
                                 )*}
-                            }).response
+
+                                _changed
+                            });
+
+                            let mut response = r.response;
+                            if r.inner {
+                                response.mark_changed();
+                            }
+                            response
                         }
 
                         fn has_inner(&mut self) -> bool {
@@ -846,10 +1499,19 @@ pub fn derive(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream>
                         fn iterate_inner(&mut self, _f: &mut dyn FnMut(&str, &mut dyn ::egui_probe::EguiProbe)) {
                             use ::egui_probe::private::*;
 
+                            #read_only_inner
                             match self {#(
                                 #variants_iterate_inner
                             )*}
                         }
+
+                        #validate_method
+
+                        #text_value_method
+
+                        #clipboard_value_method
+
+                        #multi_value_method
                     }
             };
 
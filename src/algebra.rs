@@ -1,24 +1,63 @@
-use egui::{Pos2, Rect, Vec2};
+use core::{fmt::Display, ops::BitOr};
 
-use crate::EguiProbe;
+use egui::{emath::Numeric, Pos2, Rect, Vec2};
+
+use crate::{num::drag_value, ClipboardValue, EguiProbe, MultiValue, Style};
+
+// Only editors shared by math library types need these.
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+use {crate::angle, core::ops::RangeInclusive};
+
+/// Modifier to keep the vector normalized.
+///
+/// Edited value is normalized once editing of its components is finished,
+/// changes that make it zero length are discarded.
+pub struct EguiProbeNormalized<'a, T> {
+    pub value: &'a mut T,
+}
+
+/// Shows the vector and keeps it normalized.
+///
+/// Add `#[egui_probe(normalized)]` to the field to do the same with derive.
+#[inline(always)]
+pub fn normalized<T>(value: &mut T) -> EguiProbeNormalized<'_, T>
+where
+    for<'a> EguiProbeNormalized<'a, T>: EguiProbe,
+{
+    EguiProbeNormalized { value }
+}
 
 impl EguiProbe for Vec2 {
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
-        ui.horizontal(|ui| {
-            ui.add(egui::DragValue::new(&mut self.x));
-            ui.add(egui::DragValue::new(&mut self.y));
-        })
-        .response
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        ui.horizontal(|ui| self.x.probe(ui, style) | self.y.probe(ui, style))
+            .inner
+    }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
     }
 }
 
 impl EguiProbe for Pos2 {
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
-        ui.horizontal(|ui| {
-            ui.add(egui::DragValue::new(&mut self.x));
-            ui.add(egui::DragValue::new(&mut self.y));
-        })
-        .response
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        ui.horizontal(|ui| self.x.probe(ui, style) | self.y.probe(ui, style))
+            .inner
+    }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
     }
 }
 
@@ -26,14 +65,251 @@ impl EguiProbe for Rect {
     fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
         ui.horizontal(|ui| {
             let top = ui.label("top");
-            self.min.y.probe(ui, style).labelled_by(top.id);
+            let r = self.min.y.probe(ui, style).labelled_by(top.id);
             let left = ui.label("left");
-            self.min.x.probe(ui, style).labelled_by(left.id);
+            let r = r | self.min.x.probe(ui, style).labelled_by(left.id);
             let bottom = ui.label("bottom");
-            self.max.y.probe(ui, style).labelled_by(bottom.id);
+            let r = r | self.max.y.probe(ui, style).labelled_by(bottom.id);
             let right = ui.label("right");
-            self.max.x.probe(ui, style).labelled_by(right.id);
+            r | self.max.x.probe(ui, style).labelled_by(right.id)
+        })
+        .inner
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
+
+/// Shows components of a vector in a row.
+///
+/// This is the row shared by vectors of all supported math libraries,
+/// so that they look the same.
+/// Components are clamped to the range if one is given.
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+pub(crate) fn components_probe<T>(
+    components: &mut [T],
+    range: Option<RangeInclusive<T>>,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response
+where
+    T: Numeric + Display,
+{
+    components
+        .iter_mut()
+        .map(|component| drag_value(component, range.clone(), ui, style))
+        .reduce(BitOr::bitor)
+        .unwrap_or_else(|| ui.weak("empty"))
+}
+
+/// Returns normalized components, or `None` for zero length vector.
+fn normalize<T, const N: usize>(components: [T; N]) -> Option<[T; N]>
+where
+    T: Numeric,
+{
+    let length = components
+        .iter()
+        .map(|component| component.to_f64().powi(2))
+        .sum::<f64>()
+        .sqrt();
+
+    (length > 0.0).then(|| components.map(|component| T::from_f64(component.to_f64() / length)))
+}
+
+/// Shows components of the normalized vector in a row.
+///
+/// Changes are applied as they are made,
+/// and components are normalized once editing is finished,
+/// so that typed values are not replaced while typing.
+/// Changes that make the vector zero length are discarded.
+pub(crate) fn normalized_components<T, const N: usize>(
+    components: &mut [T; N],
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response
+where
+    T: Numeric + Display,
+{
+    let id = ui.make_persistent_id("probe_normalized");
+    let was_editing = ui.ctx().data_mut(|d| d.get_temp(id)).unwrap_or(false);
+
+    let mut edited = *components;
+    let mut editing = false;
+    let mut r = edited
+        .iter_mut()
+        .map(|component| {
+            let r = drag_value(component, None, ui, style);
+            editing |= r.has_focus() || r.dragged();
+            r
         })
-        .response
+        .reduce(BitOr::bitor)
+        .expect("vectors have components");
+
+    if r.changed() && normalize(edited).is_some() {
+        *components = edited;
+    }
+
+    if !editing && (was_editing || r.changed()) {
+        if let Some(normalized) = normalize(*components) {
+            if normalized != *components {
+                *components = normalized;
+                r.mark_changed();
+            }
+        }
+    }
+
+    ui.ctx().data_mut(|d| match editing {
+        false => d.remove::<bool>(id),
+        true => d.insert_temp(id, true),
+    });
+    r
+}
+
+/// Shows elements of a matrix as a grid.
+///
+/// Elements are stored column by column, or row by row if `row_major` is `true`.
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+pub(crate) fn matrix_probe<T>(
+    elements: &mut [T],
+    rows: usize,
+    row_major: bool,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response
+where
+    T: Numeric + Display,
+{
+    let columns = elements.len() / rows.max(1);
+    let mut changed = false;
+
+    let mut r = ui
+        .vertical(|ui| {
+            egui::Grid::new("probe_matrix").show(ui, |ui| {
+                for row in 0..rows {
+                    for column in 0..columns {
+                        let idx = match row_major {
+                            false => column * rows + row,
+                            true => row * columns + column,
+                        };
+                        changed |= drag_value(&mut elements[idx], None, ui, style).changed();
+                    }
+                    ui.end_row();
+                }
+            });
+        })
+        .response;
+
+    if changed {
+        r.mark_changed();
+    }
+    r
+}
+
+/// Converts unit quaternion to Euler angles.
+///
+/// Angles are rotations around X, Y and Z axes, applied in that order.
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+fn quaternion_to_euler([x, y, z, w]: [f64; 4]) -> [f64; 3] {
+    let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+    let pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0).asin();
+    let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+    [roll, pitch, yaw]
+}
+
+/// Converts Euler angles to unit quaternion.
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+fn euler_to_quaternion([roll, pitch, yaw]: [f64; 3]) -> [f64; 4] {
+    let (sr, cr) = (roll / 2.0).sin_cos();
+    let (sp, cp) = (pitch / 2.0).sin_cos();
+    let (sy, cy) = (yaw / 2.0).sin_cos();
+    [
+        sr * cp * cy - cr * sp * sy,
+        cr * sp * cy + sr * cp * sy,
+        cr * cp * sy - sr * sp * cy,
+        cr * cp * cy + sr * sp * sy,
+    ]
+}
+
+/// Shows quaternion as Euler angles, or as raw components if toggled.
+///
+/// Components are in `x, y, z, w` order.
+/// Raw components are normalized once edited if `normalized` is `true`,
+/// quaternion edited with Euler angles is always normalized.
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+pub(crate) fn quaternion_probe<T>(
+    components: &mut [T; 4],
+    normalized: bool,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response
+where
+    T: Numeric + Display,
+{
+    let id = ui.make_persistent_id("probe_quaternion_raw");
+    let mut raw = ui.ctx().data_mut(|d| d.get_temp(id)).unwrap_or(false);
+
+    let mut changed = false;
+
+    // Response covers the toggle, so that it is part of the row.
+    let mut response = ui
+        .horizontal(|ui| {
+            changed = if raw && normalized {
+                normalized_components(components, ui, style).changed()
+            } else if raw {
+                components_probe(components, None, ui, style).changed()
+            } else {
+                let unit = normalize(components.map(T::to_f64)).unwrap_or([0.0, 0.0, 0.0, 1.0]);
+                let mut angles = quaternion_to_euler(unit).map(|angle| angle as f32);
+
+                let [x, y, z] = &mut angles;
+                let r = angle(x).probe(ui, style)
+                    | angle(y).probe(ui, style)
+                    | angle(z).probe(ui, style);
+
+                if r.changed() {
+                    *components = euler_to_quaternion(angles.map(f64::from)).map(T::from_f64);
+                }
+                r.changed()
+            };
+
+            if ui
+                .selectable_label(raw, "xyzw")
+                .on_hover_text("Edit raw components instead of Euler angles")
+                .clicked()
+            {
+                raw = !raw;
+            }
+        })
+        .response;
+
+    if changed {
+        response.mark_changed();
+    }
+
+    ui.ctx().data_mut(|d| match raw {
+        false => d.remove::<bool>(id),
+        true => d.insert_temp(id, true),
+    });
+
+    response
+}
+
+impl EguiProbe for EguiProbeNormalized<'_, Vec2> {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        let mut components = (*self.value).into();
+        let r = ui
+            .horizontal(|ui| normalized_components(&mut components, ui, style))
+            .inner;
+        if r.changed() {
+            *self.value = components.into();
+        }
+        r
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
     }
 }
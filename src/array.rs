@@ -1,4 +1,10 @@
-use crate::EguiProbe;
+use std::ops::Range;
+
+use crate::{
+    collections::iterate_slice_range,
+    hex::{hex_probe, EguiProbeHex, HexBytes},
+    EguiProbe, MultiValue,
+};
 
 impl<T, const N: usize> EguiProbe for [T; N]
 where
@@ -17,6 +23,14 @@ where
             f(&format!("[{i}]"), value);
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_slice_range(&mut self[..], range, f)
+    }
 }
 
 impl<T, const N: usize> EguiProbe for &mut [T; N]
@@ -36,6 +50,14 @@ where
             f(&format!("[{i}]"), value);
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_slice_range(&mut self[..], range, f)
+    }
 }
 
 impl<T> EguiProbe for &mut [T]
@@ -55,4 +77,29 @@ where
             f(&format!("[{i}]"), value);
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_slice_range(&mut self[..], range, f)
+    }
+}
+
+impl<const N: usize> HexBytes for [u8; N] {
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl<const N: usize> EguiProbe for EguiProbeHex<'_, [u8; N]> {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        hex_probe(self.value, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
@@ -1,13 +1,46 @@
-use crate::{BooleanStyle, EguiProbe, Style};
+use crate::{BooleanStyle, ClipboardValue, EguiProbe, MultiValue, Style};
 
 impl EguiProbe for bool {
     #[inline(always)]
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
         match style.boolean {
-            BooleanStyle::Checkbox => ui.add(egui::Checkbox::without_text(self)),
-            BooleanStyle::ToggleSwitch => toggle_switch(self, ui),
+            BooleanStyle::Checkbox => {
+                ui.add_enabled(!style.read_only, egui::Checkbox::without_text(self))
+            }
+            BooleanStyle::ToggleSwitch => {
+                ui.add_enabled_ui(!style.read_only, |ui| toggle_switch(self, ui))
+                    .inner
+            }
         }
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
+
+/// Modifier to show boolean as a toggle switch.
+pub struct EguiProbeToggleSwitch<'a> {
+    pub value: &'a mut bool,
+}
+
+impl EguiProbe for EguiProbeToggleSwitch<'_> {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        ui.add_enabled_ui(!style.read_only, |ui| toggle_switch(self.value, ui))
+            .inner
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
 
 /// Shows a toggle switch.
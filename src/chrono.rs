@@ -0,0 +1,179 @@
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Timelike,
+};
+
+use crate::{
+    datetime::{
+        date_time_probe, format_offset, parse_date, parse_date_time, parse_time, CivilDate,
+        CivilTime, DateTimeValue, ParsedDateTime,
+    },
+    ClipboardValue, EguiProbe, MultiValue, Style,
+};
+
+fn civil_date(date: NaiveDate) -> CivilDate {
+    CivilDate {
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+    }
+}
+
+fn naive_date(date: CivilDate) -> Result<NaiveDate, String> {
+    NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+        .ok_or_else(|| format!("date {date} is out of range"))
+}
+
+/// Leap second is shown as the second before it.
+fn civil_time(time: NaiveTime) -> CivilTime {
+    CivilTime {
+        hour: time.hour(),
+        minute: time.minute(),
+        second: time.second(),
+        nanosecond: time.nanosecond() % 1_000_000_000,
+    }
+}
+
+fn naive_time(time: CivilTime) -> Result<NaiveTime, String> {
+    NaiveTime::from_hms_nano_opt(time.hour, time.minute, time.second, time.nanosecond)
+        .ok_or_else(|| format!("time {time} is out of range"))
+}
+
+fn naive_date_time(parsed: &ParsedDateTime) -> Result<NaiveDateTime, String> {
+    Ok(NaiveDateTime::new(
+        naive_date(parsed.date)?,
+        naive_time(parsed.time)?,
+    ))
+}
+
+impl DateTimeValue for NaiveDate {
+    fn format(&self) -> String {
+        civil_date(*self).to_string()
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        naive_date(parse_date(text)?)
+    }
+
+    fn calendar_date(&self) -> Option<CivilDate> {
+        Some(civil_date(*self))
+    }
+
+    fn with_calendar_date(&self, date: CivilDate) -> Option<Self> {
+        naive_date(date).ok()
+    }
+}
+
+impl DateTimeValue for NaiveTime {
+    fn format(&self) -> String {
+        civil_time(*self).to_string()
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        naive_time(parse_time(text)?)
+    }
+}
+
+impl DateTimeValue for NaiveDateTime {
+    fn format(&self) -> String {
+        format!("{} {}", civil_date(self.date()), civil_time(self.time()))
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        let parsed = parse_date_time(text)?;
+        if parsed.offset.is_some() {
+            return Err("naive date and time has no offset".to_owned());
+        }
+        naive_date_time(&parsed)
+    }
+
+    fn calendar_date(&self) -> Option<CivilDate> {
+        Some(civil_date(self.date()))
+    }
+
+    fn with_calendar_date(&self, date: CivilDate) -> Option<Self> {
+        Some(NaiveDateTime::new(naive_date(date).ok()?, self.time()))
+    }
+}
+
+/// Date and time are shown in the time zone of the value, followed by its offset.
+///
+/// Text without offset is taken as local time of the time zone,
+/// text with offset is converted to the time zone.
+impl<Tz> DateTimeValue for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn format(&self) -> String {
+        let local = self.naive_local();
+        format!(
+            "{} {} {}",
+            civil_date(local.date()),
+            civil_time(local.time()),
+            format_offset(self.offset().fix().local_minus_utc())
+        )
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        let parsed = parse_date_time(text)?;
+        let local = naive_date_time(&parsed)?;
+
+        let result = match parsed.offset {
+            None => self.timezone().from_local_datetime(&local).single(),
+            Some(offset) => FixedOffset::east_opt(offset)
+                .and_then(|offset| offset.from_local_datetime(&local).single())
+                .map(|date_time| date_time.with_timezone(&self.timezone())),
+        };
+
+        result.ok_or_else(|| "time does not exist in the time zone".to_owned())
+    }
+
+    fn calendar_date(&self) -> Option<CivilDate> {
+        Some(civil_date(self.naive_local().date()))
+    }
+
+    fn with_calendar_date(&self, date: CivilDate) -> Option<Self> {
+        let local = NaiveDateTime::new(naive_date(date).ok()?, self.naive_local().time());
+        self.timezone().from_local_datetime(&local).single()
+    }
+}
+
+macro_rules! impl_for_naive {
+    ($($naive:ident),*) => {
+        $(
+            impl EguiProbe for $naive {
+                #[inline(always)]
+                fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                    date_time_probe(self, ui, style)
+                }
+
+                #[inline(always)]
+                fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                    Some(self)
+                }
+
+                #[inline(always)]
+                fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_naive!(NaiveDate, NaiveTime, NaiveDateTime);
+
+impl<Tz> EguiProbe for DateTime<Tz>
+where
+    Tz: TimeZone + 'static,
+{
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        date_time_probe(self, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
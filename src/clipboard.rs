@@ -0,0 +1,155 @@
+use std::{any::Any, sync::Arc};
+
+use crate::{EguiProbe, Style};
+
+/// Value that can be copied, pasted and reset to default
+/// from the context menu of its label.
+///
+/// Implemented for all types that implement `Clone` and `Default`.
+pub trait ClipboardValue {
+    /// Returns copy of the value to put into the clipboard.
+    fn copy_value(&self) -> Arc<dyn Any + Send + Sync>;
+
+    /// Returns `true` if the copied value has the same type.
+    fn can_paste(&self, copied: &(dyn Any + Send + Sync)) -> bool;
+
+    /// Replaces the value with the copied one.
+    ///
+    /// Returns `false` if the copied value has different type.
+    fn paste_value(&mut self, copied: &(dyn Any + Send + Sync)) -> bool;
+
+    /// Replaces the value with the default one.
+    fn reset_value(&mut self);
+}
+
+impl<T> ClipboardValue for T
+where
+    T: Clone + Default + Send + Sync + 'static,
+{
+    fn copy_value(&self) -> Arc<dyn Any + Send + Sync> {
+        Arc::new(self.clone())
+    }
+
+    fn can_paste(&self, copied: &(dyn Any + Send + Sync)) -> bool {
+        copied.is::<T>()
+    }
+
+    fn paste_value(&mut self, copied: &(dyn Any + Send + Sync)) -> bool {
+        match copied.downcast_ref::<T>() {
+            Some(copied) => {
+                self.clone_from(copied);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn reset_value(&mut self) {
+        *self = T::default();
+    }
+}
+
+/// Value copied from a row, shared by all probes in the context.
+#[derive(Clone)]
+struct Clipboard {
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+fn clipboard_id() -> egui::Id {
+    egui::Id::new("egui_probe_clipboard")
+}
+
+/// Adds "Copy", "Paste" and "Reset to default" buttons to the context menu.
+///
+/// "Paste" is enabled only if copied value has the same type.
+/// "Reset to default" is added only if `reset` is `true`,
+/// values with known default have their own reset button instead.
+/// Returns `true` if the value was changed.
+pub(crate) fn clipboard_menu(
+    value: &mut dyn ClipboardValue,
+    ui: &mut egui::Ui,
+    style: &Style,
+    reset: bool,
+) -> bool {
+    let copied = ui
+        .ctx()
+        .data_mut(|d| d.get_temp::<Clipboard>(clipboard_id()));
+
+    if ui.button("Copy").clicked() {
+        let clipboard = Clipboard {
+            value: value.copy_value(),
+        };
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(clipboard_id(), clipboard));
+        ui.close_menu();
+    }
+
+    let mut changed = false;
+
+    let can_paste = !style.read_only
+        && copied
+            .as_ref()
+            .is_some_and(|copied| value.can_paste(&*copied.value));
+
+    if ui
+        .add_enabled(can_paste, egui::Button::new("Paste"))
+        .clicked()
+    {
+        if let Some(copied) = copied {
+            changed = value.paste_value(&*copied.value);
+        }
+        ui.close_menu();
+    }
+
+    if reset
+        && ui
+            .add_enabled(!style.read_only, egui::Button::new("Reset to default"))
+            .clicked()
+    {
+        value.reset_value();
+        changed = true;
+        ui.close_menu();
+    }
+
+    changed
+}
+
+/// Modifier to allow copying, pasting and resetting the value
+/// from the label context menu.
+pub struct EguiProbeClipboard<'a, T> {
+    pub value: &'a mut T,
+}
+
+impl<T> EguiProbe for EguiProbeClipboard<'_, T>
+where
+    T: EguiProbe + ClipboardValue,
+{
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(&mut *self.value)
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        probe,
+        has_inner,
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        validate,
+        as_text_value,
+        as_default_value,
+        as_multi_value,
+    );
+}
+
+/// Allows copying, pasting and resetting the value from the label context menu.
+///
+/// Value must implement `Clone` and `Default`.
+#[inline(always)]
+pub fn clipboard_value<T>(value: &mut T) -> EguiProbeClipboard<'_, T>
+where
+    T: EguiProbe + ClipboardValue,
+{
+    EguiProbeClipboard { value }
+}
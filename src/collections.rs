@@ -1,3 +1,5 @@
+use std::{any::Any, ops::Range, sync::Arc};
+
 use crate::{EguiProbe, Style};
 
 /// Modifier to add a delete button to an item probe UI.
@@ -12,25 +14,433 @@ where
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
         ui.horizontal(|ui| {
-            self.value.probe(ui, style);
+            let mut r = self.value.probe(ui, style);
+            if style.read_only {
+                return r;
+            }
             ui.add_space(ui.spacing().item_spacing.x);
             if ui.small_button(style.remove_button_text()).clicked() {
                 self.delete = true;
+                r.mark_changed();
             };
+            r
         })
-        .response
+        .inner
     }
 
     fn has_inner(&mut self) -> bool {
         self.value.has_inner() && !self.delete
     }
 
-    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
-        self.value.iterate_inner(f);
-    }
+    forward_probe_methods!(
+        |this| this.value =>
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        validate,
+        as_clipboard_value,
+        as_text_value,
+        as_default_value,
+        as_multi_value,
+    );
 }
 
 /// Modifier to disable adding/removing items from collections.
 pub struct EguiProbeFrozen<'a, T> {
     pub value: &'a mut T,
 }
+
+/// Action requested from the UI of a sequence item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceAction {
+    Remove,
+    MoveUp,
+    MoveDown,
+    Duplicate,
+    InsertBefore,
+}
+
+/// Drag-and-drop payload of a sequence item.
+struct SequenceDrag {
+    /// Id of the drag handle of the dragged item.
+    handle: egui::Id,
+}
+
+/// Sequence item a dragged item is hovered over or dropped onto.
+pub struct SequenceDrop {
+    /// Id of the drag handle of the dragged item.
+    handle: egui::Id,
+    released: bool,
+    rect: egui::Rect,
+    painter: egui::Painter,
+}
+
+/// Function cloning sequence items to duplicate them.
+pub type CloneFn<T> = fn(&T) -> T;
+
+/// Modifier to add reordering, duplicating, inserting and removing controls
+/// to an item probe UI.
+pub struct SequenceItem<'a, T> {
+    pub value: &'a mut T,
+    pub idx: usize,
+    pub len: usize,
+    pub action: Option<SequenceAction>,
+
+    /// Clones the value to duplicate it, if items are cloneable.
+    pub clone: Option<CloneFn<T>>,
+
+    /// Clone of the value to insert when duplicated.
+    pub cloned: Option<T>,
+
+    /// Copy of the value to insert when duplicated
+    /// if items can't be cloned but have a clipboard value.
+    pub copied: Option<Arc<dyn Any + Send + Sync>>,
+
+    /// Id of the drag handle, set when the item is shown.
+    ///
+    /// Ids of rows are unique in the probe tree,
+    /// so handles identify items dragged within the same sequence.
+    pub handle: Option<egui::Id>,
+    pub drop: Option<SequenceDrop>,
+}
+
+impl<T> SequenceItem<'_, T>
+where
+    T: EguiProbe,
+{
+    /// Shows buttons to move, duplicate and insert items.
+    fn buttons(&mut self, ui: &mut egui::Ui) {
+        let can_duplicate = self.clone.is_some() || self.value.as_clipboard_value().is_some();
+        let actions = [
+            ("⏶", "Move up", self.idx > 0, SequenceAction::MoveUp),
+            (
+                "⏷",
+                "Move down",
+                self.idx + 1 < self.len,
+                SequenceAction::MoveDown,
+            ),
+            ("🗐", "Duplicate", can_duplicate, SequenceAction::Duplicate),
+            ("➕", "Insert before", true, SequenceAction::InsertBefore),
+        ];
+
+        for (text, hover, enabled, action) in actions {
+            let button = ui
+                .add_enabled(enabled, egui::Button::new(text).small())
+                .on_hover_text(hover);
+            if button.clicked() {
+                if action == SequenceAction::Duplicate {
+                    match self.clone {
+                        Some(clone) => self.cloned = Some(clone(self.value)),
+                        None => {
+                            self.copied = self
+                                .value
+                                .as_clipboard_value()
+                                .map(|value| value.copy_value());
+                        }
+                    }
+                }
+                self.action = Some(action);
+            }
+        }
+    }
+}
+
+impl<T> EguiProbe for SequenceItem<'_, T>
+where
+    T: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        if style.read_only {
+            return self.value.probe(ui, style);
+        }
+
+        let egui::InnerResponse {
+            inner: mut r,
+            response: row,
+        } = ui.horizontal(|ui| {
+            let controls = sequence_controls(ui.ctx());
+            if controls {
+                let handle = ui.id().with("drag_handle");
+                self.handle = Some(handle);
+                ui.dnd_drag_source(handle, SequenceDrag { handle }, |ui| {
+                    ui.add(egui::Label::new("☰").selectable(false))
+                })
+                .response
+                .on_hover_text("Drag to reorder");
+            }
+
+            let mut r = self.value.probe(ui, style);
+
+            ui.add_space(ui.spacing().item_spacing.x);
+            if ui.small_button(style.remove_button_text()).clicked() {
+                self.action = Some(SequenceAction::Remove);
+            }
+
+            if controls {
+                self.buttons(ui);
+            }
+
+            if self.action.is_some() {
+                r.mark_changed();
+            }
+            r
+        });
+
+        // Dragged item is resolved by the sequence,
+        // as the item doesn't know handles of other items.
+        let hovered = row.dnd_hover_payload::<SequenceDrag>();
+        let released = row.dnd_release_payload::<SequenceDrag>();
+        if let Some(drag) = released.as_ref().or(hovered.as_ref()) {
+            if Some(drag.handle) != self.handle {
+                self.drop = Some(SequenceDrop {
+                    handle: drag.handle,
+                    released: released.is_some(),
+                    rect: row.rect,
+                    painter: ui.painter().clone(),
+                });
+                if released.is_some() {
+                    r.mark_changed();
+                }
+            }
+        }
+
+        r
+    }
+
+    fn has_inner(&mut self) -> bool {
+        self.value.has_inner() && self.action != Some(SequenceAction::Remove)
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        validate,
+        as_clipboard_value,
+        as_text_value,
+        as_default_value,
+        as_multi_value,
+    );
+}
+
+fn sequence_controls_id() -> egui::Id {
+    egui::Id::new("egui_probe_sequence_controls")
+}
+
+/// Returns `false` if drag handles and buttons to reorder, duplicate and insert
+/// sequence items are hidden.
+fn sequence_controls(cx: &egui::Context) -> bool {
+    cx.data(|d| d.get_temp::<bool>(sequence_controls_id()))
+        .unwrap_or(true)
+}
+
+/// Hides controls of sequence items shown by `f`.
+pub(crate) fn without_sequence_controls<R>(cx: &egui::Context, f: impl FnOnce() -> R) -> R {
+    let controls = sequence_controls(cx);
+    cx.data_mut(|d| d.insert_temp(sequence_controls_id(), false));
+    let r = f();
+    cx.data_mut(|d| d.insert_temp(sequence_controls_id(), controls));
+    r
+}
+
+/// Sequence collection which items can be reordered.
+///
+/// Public only to bound [`EguiProbeCloneItems`] implementation,
+/// it can't be named outside of the crate.
+pub trait Sequence {
+    type Item;
+
+    fn sequence_len(&self) -> usize;
+
+    /// Calls `f` for items with indices in the range, which is within the sequence.
+    fn for_each_item(&mut self, range: Range<usize>, f: &mut dyn FnMut(usize, &mut Self::Item));
+
+    fn insert_item(&mut self, idx: usize, item: Self::Item);
+
+    fn remove_item(&mut self, idx: usize) -> Self::Item;
+}
+
+/// Clamps the range of indices to the collection of the length.
+pub(crate) fn clamp_range(range: Range<usize>, len: usize) -> Range<usize> {
+    let end = range.end.min(len);
+    range.start.min(end)..end
+}
+
+/// Iterates over slice items with indices in the range.
+///
+/// Returns number of all items.
+pub(crate) fn iterate_slice_range<T>(
+    slice: &mut [T],
+    range: Range<usize>,
+    f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+) -> usize
+where
+    T: EguiProbe,
+{
+    let range = clamp_range(range, slice.len());
+    let start = range.start;
+    for (i, value) in slice[range].iter_mut().enumerate() {
+        f(&format!("[{}]", start + i), value);
+    }
+    slice.len()
+}
+
+/// Iterates over sequence items with indices in the range wrapped into [`SequenceItem`]
+/// and applies action requested from the UI.
+///
+/// Items are duplicated with `clone` if provided,
+/// otherwise through their clipboard value.
+///
+/// Returns number of items before the action is applied.
+pub(crate) fn iterate_sequence<S>(
+    sequence: &mut S,
+    range: Range<usize>,
+    clone: Option<CloneFn<S::Item>>,
+    f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+) -> usize
+where
+    S: Sequence,
+    S::Item: EguiProbe + Default,
+{
+    let len = sequence.sequence_len();
+
+    let mut action = None;
+    let mut cloned = None;
+    let mut copied = None;
+
+    // Handles of shown items and items dragged items are hovered over.
+    let mut handles = Vec::new();
+    let mut drops = Vec::new();
+
+    sequence.for_each_item(clamp_range(range, len), &mut |idx, value| {
+        let mut item = SequenceItem {
+            value,
+            idx,
+            len,
+            action: None,
+            clone,
+            cloned: None,
+            copied: None,
+            handle: None,
+            drop: None,
+        };
+        f(&format!("[{idx}]"), &mut item);
+
+        if let Some(handle) = item.handle {
+            handles.push((handle, idx));
+        }
+        if let Some(drop) = item.drop {
+            drops.push((drop, idx));
+        }
+        if let Some(item_action) = item.action {
+            cloned = item.cloned;
+            copied = item.copied;
+            action = Some((idx, item_action));
+        }
+    });
+
+    // Items dragged from other sequences are not among the handles.
+    let mut moved = None;
+    for (drop, idx) in drops {
+        let Some(&(_, from)) = handles.iter().find(|(handle, _)| *handle == drop.handle) else {
+            continue;
+        };
+
+        let y = if from > idx {
+            drop.rect.top()
+        } else {
+            drop.rect.bottom()
+        };
+        let stroke = drop.painter.ctx().style().visuals.selection.stroke;
+        drop.painter.hline(drop.rect.x_range(), y, stroke);
+
+        if drop.released {
+            moved = Some((from, idx));
+        }
+    }
+
+    if let Some((from, idx)) = moved {
+        let item = sequence.remove_item(from);
+        sequence.insert_item(idx, item);
+        return len;
+    }
+
+    let Some((idx, action)) = action else {
+        return len;
+    };
+
+    match action {
+        SequenceAction::Remove => {
+            sequence.remove_item(idx);
+        }
+        SequenceAction::MoveUp if idx > 0 => {
+            let item = sequence.remove_item(idx);
+            sequence.insert_item(idx - 1, item);
+        }
+        SequenceAction::MoveDown if idx + 1 < len => {
+            let item = sequence.remove_item(idx);
+            sequence.insert_item(idx + 1, item);
+        }
+        SequenceAction::Duplicate => {
+            if let Some(item) = cloned {
+                sequence.insert_item(idx + 1, item);
+            } else {
+                // Copy is pasted into a new item, so that items don't need to be `Clone`.
+                let mut item = S::Item::default();
+                let pasted = copied.is_some_and(|copied| {
+                    item.as_clipboard_value()
+                        .is_some_and(|value| value.paste_value(&*copied))
+                });
+                if pasted {
+                    sequence.insert_item(idx + 1, item);
+                }
+            }
+        }
+        SequenceAction::InsertBefore => {
+            sequence.insert_item(idx, S::Item::default());
+        }
+        _ => {}
+    }
+
+    len
+}
+
+/// Modifier to duplicate items of a sequence by cloning them.
+///
+/// Without it items are duplicated only if they have a clipboard value.
+pub struct EguiProbeCloneItems<'a, S> {
+    pub value: &'a mut S,
+}
+
+impl<S> EguiProbe for EguiProbeCloneItems<'_, S>
+where
+    S: EguiProbe + Sequence,
+    S::Item: EguiProbe + Default + Clone,
+{
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        iterate_sequence(self.value, 0..usize::MAX, Some(S::Item::clone), f);
+    }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_sequence(self.value, range, Some(S::Item::clone), f)
+    }
+
+    forward_probe_methods!(|this| this.value => probe, has_inner, tooltip, validate);
+}
+
+/// Allows duplicating items of `Vec`, `VecDeque` and `SmallVec` by cloning them.
+///
+/// Use with `#[egui_probe(as clone_items)]` on a field.
+#[inline(always)]
+pub fn clone_items<S>(value: &mut S) -> EguiProbeCloneItems<'_, S>
+where
+    S: EguiProbe + Sequence,
+    S::Item: EguiProbe + Default + Clone,
+{
+    EguiProbeCloneItems { value }
+}
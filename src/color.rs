@@ -1,6 +1,11 @@
 use egui::{epaint::Hsva, Color32, Rgba};
 
-use crate::EguiProbe;
+use crate::{ClipboardValue, EguiProbe, MultiValue};
+
+/// Shows color without allowing to edit it.
+fn show_color(ui: &mut egui::Ui, color: impl Into<Color32>) -> egui::Response {
+    egui::color_picker::show_color(ui, color, ui.spacing().interact_size)
+}
 
 /// Modifier to edit color as rgb.
 pub struct EguiProbeRgb<'a, T> {
@@ -24,84 +29,171 @@ pub struct EguiProbeRgbaUnmultiplied<'a, T> {
 
 impl EguiProbe for Color32 {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self);
+        }
         ui.color_edit_button_srgba(self)
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
 
 impl EguiProbe for EguiProbeRgb<'_, Color32> {
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self.value);
+        }
         egui::color_picker::color_edit_button_srgba(
             ui,
-            &mut self.value,
+            self.value,
             egui::color_picker::Alpha::Opaque,
         )
     }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
 
 impl EguiProbe for EguiProbeRgba<'_, Color32> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self.value);
+        }
         ui.color_edit_button_srgba(self.value)
     }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
 
 impl EguiProbe for EguiProbeRgbaPremultiplied<'_, Color32> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self.value);
+        }
         ui.color_edit_button_srgba(self.value)
     }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
 
 impl EguiProbe for Rgba {
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self);
+        }
         egui::color_picker::color_edit_button_rgba(
             ui,
             self,
             egui::color_picker::Alpha::BlendOrAdditive,
         )
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
 
 impl EguiProbe for EguiProbeRgb<'_, Rgba> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self.value);
+        }
         egui::color_picker::color_edit_button_rgba(
             ui,
             self.value,
             egui::color_picker::Alpha::Opaque,
         )
     }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
 
 impl EguiProbe for EguiProbeRgba<'_, Rgba> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self.value);
+        }
         egui::color_picker::color_edit_button_rgba(
             ui,
             self.value,
             egui::color_picker::Alpha::Opaque,
         )
     }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
 
 impl EguiProbe for EguiProbeRgbaUnmultiplied<'_, Rgba> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self.value);
+        }
         egui::color_picker::color_edit_button_rgba(
             ui,
             self.value,
             egui::color_picker::Alpha::Opaque,
         )
     }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
 
 impl EguiProbe for Hsva {
-    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        if style.read_only {
+            return show_color(ui, *self);
+        }
         egui::color_picker::color_edit_button_hsva(
             ui,
             self,
             egui::color_picker::Alpha::BlendOrAdditive,
         )
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
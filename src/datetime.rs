@@ -0,0 +1,593 @@
+//! Editing of dates and times.
+//!
+//! Values are edited as text, with calendar popup to pick dates.
+//! Text is formatted and parsed here, so that `SystemTime`,
+//! `chrono` and `time` types look and behave the same.
+
+use core::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{EguiProbe, MultiValue, Style};
+
+const SECS_PER_DAY: i64 = 86_400;
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Date in the proleptic Gregorian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct CivilDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CivilDate {
+    /// Returns the date that is given number of days after 1970-01-01.
+    fn from_days(days: i64) -> Self {
+        // Algorithm by Howard Hinnant, years are counted from March,
+        // so that leap day is the last day of the year.
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        CivilDate {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    /// Returns number of days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = i64::from((self.month + 9) % 12);
+        let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns day of the week, from 0 for Monday to 6 for Sunday.
+    fn weekday(self) -> u32 {
+        // 1970-01-01 is Thursday.
+        (self.to_days() + 3).rem_euclid(7) as u32
+    }
+}
+
+impl fmt::Display for CivilDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Time of day with nanosecond precision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct CivilTime {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub nanosecond: u32,
+}
+
+impl CivilTime {
+    fn seconds_from_midnight(self) -> i64 {
+        i64::from(self.hour * 3600 + self.minute * 60 + self.second)
+    }
+}
+
+/// Fraction of a second is shown only if it is not zero,
+/// with three, six or nine digits as needed.
+impl fmt::Display for CivilTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        match self.nanosecond {
+            0 => Ok(()),
+            nanos if nanos % 1_000_000 == 0 => write!(f, ".{:03}", nanos / 1_000_000),
+            nanos if nanos % 1_000 == 0 => write!(f, ".{:06}", nanos / 1_000),
+            nanos => write!(f, ".{nanos:09}"),
+        }
+    }
+}
+
+/// Formats offset from UTC in seconds as `+HH:MM`.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+    match seconds {
+        0 => format!("{sign}{hours:02}:{minutes:02}"),
+        _ => format!("{sign}{hours:02}:{minutes:02}:{seconds:02}"),
+    }
+}
+
+/// Splits timestamp in seconds since the Unix epoch into date and time of day.
+fn split_timestamp(secs: i64, nanosecond: u32) -> (CivilDate, CivilTime) {
+    let date = CivilDate::from_days(secs.div_euclid(SECS_PER_DAY));
+    let secs = secs.rem_euclid(SECS_PER_DAY) as u32;
+    let time = CivilTime {
+        hour: secs / 3600,
+        minute: secs / 60 % 60,
+        second: secs % 60,
+        nanosecond,
+    };
+    (date, time)
+}
+
+fn parse_number<T: core::str::FromStr>(text: &str, what: &str) -> Result<T, String> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("invalid {what} `{text}`"));
+    }
+    text.parse()
+        .map_err(|_| format!("{what} `{text}` is too large"))
+}
+
+/// Parses date in `YYYY-MM-DD` format.
+pub(crate) fn parse_date(text: &str) -> Result<CivilDate, String> {
+    let text = text.trim();
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+
+    let mut parts = unsigned.splitn(3, '-');
+    let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("expected date as `YYYY-MM-DD`".to_owned());
+    };
+
+    let year: i32 = parse_number(year, "year")?;
+    let month: u32 = parse_number(month, "month")?;
+    let day: u32 = parse_number(day, "day")?;
+    let year = if negative { -year } else { year };
+
+    if !(1..=12).contains(&month) {
+        return Err(format!("month {month} is not in 1..=12"));
+    }
+    let last_day = days_in_month(year, month);
+    if !(1..=last_day).contains(&day) {
+        return Err(format!(
+            "day {day} is not in 1..={last_day} for {}",
+            MONTH_NAMES[month as usize - 1]
+        ));
+    }
+
+    Ok(CivilDate { year, month, day })
+}
+
+/// Parses time of day in `HH:MM`, `HH:MM:SS` or `HH:MM:SS.fraction` format.
+pub(crate) fn parse_time(text: &str) -> Result<CivilTime, String> {
+    let text = text.trim();
+    let (text, fraction) = match text.split_once('.') {
+        Some((text, fraction)) => (text, Some(fraction)),
+        None => (text, None),
+    };
+
+    let mut parts = text.splitn(3, ':');
+    let (Some(hour), Some(minute)) = (parts.next(), parts.next()) else {
+        return Err("expected time as `HH:MM:SS`".to_owned());
+    };
+
+    let hour: u32 = parse_number(hour, "hour")?;
+    let minute: u32 = parse_number(minute, "minute")?;
+    let second: u32 = match parts.next() {
+        Some(second) => parse_number(second, "second")?,
+        None if fraction.is_some() => return Err("fraction without seconds".to_owned()),
+        None => 0,
+    };
+
+    let nanosecond = match fraction {
+        None => 0,
+        Some(fraction) if fraction.len() > 9 => {
+            return Err("fraction is more precise than nanoseconds".to_owned());
+        }
+        Some(fraction) => {
+            let digits: u32 = parse_number(fraction, "fraction")?;
+            digits * 10u32.pow(9 - fraction.len() as u32)
+        }
+    };
+
+    if hour > 23 {
+        return Err(format!("hour {hour} is not in 0..=23"));
+    }
+    if minute > 59 {
+        return Err(format!("minute {minute} is not in 0..=59"));
+    }
+    if second > 59 {
+        return Err(format!("second {second} is not in 0..=59"));
+    }
+
+    Ok(CivilTime {
+        hour,
+        minute,
+        second,
+        nanosecond,
+    })
+}
+
+/// Parses offset from UTC as `Z`, `UTC`, `+HH`, `+HH:MM` or `+HHMM`.
+/// Returns offset in seconds.
+fn parse_offset(text: &str) -> Result<i32, String> {
+    if text == "Z" || text.eq_ignore_ascii_case("UTC") {
+        return Ok(0);
+    }
+
+    let invalid = || format!("invalid offset `{text}`");
+    let (sign, rest) = match text.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, text.strip_prefix('+').ok_or_else(invalid)?),
+    };
+
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if rest.len() > 2 && rest.is_char_boundary(2) => rest.split_at(2),
+        None => (rest, "0"),
+    };
+
+    let hours: i32 = parse_number(hours, "offset").map_err(|_| invalid())?;
+    let minutes: i32 = parse_number(minutes, "offset").map_err(|_| invalid())?;
+    if hours > 23 || minutes > 59 {
+        return Err(invalid());
+    }
+
+    Ok(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Date and time parsed from text.
+pub(crate) struct ParsedDateTime {
+    pub date: CivilDate,
+    pub time: CivilTime,
+
+    /// Offset from UTC in seconds, if it was in the text.
+    pub offset: Option<i32>,
+}
+
+/// Parses date and time in `YYYY-MM-DD HH:MM:SS` format,
+/// optionally followed by offset from UTC.
+/// Date and time may be separated by `T` instead of space.
+pub(crate) fn parse_date_time(text: &str) -> Result<ParsedDateTime, String> {
+    let text = text.trim();
+
+    // Skip sign of the year, so that it is not taken for the separator.
+    let Some(split) = text
+        .char_indices()
+        .skip(1)
+        .find(|&(_, c)| c == ' ' || c == 'T')
+        .map(|(idx, _)| idx)
+    else {
+        return Err("expected date and time as `YYYY-MM-DD HH:MM:SS`".to_owned());
+    };
+
+    let date = parse_date(&text[..split])?;
+    let rest = text[split + 1..].trim_start();
+
+    let offset_start = rest
+        .find(|c: char| c.is_whitespace() || matches!(c, '+' | '-' | 'Z' | 'U' | 'u'))
+        .unwrap_or(rest.len());
+    let time = parse_time(&rest[..offset_start])?;
+
+    let offset = match rest[offset_start..].trim() {
+        "" => None,
+        offset => Some(parse_offset(offset)?),
+    };
+
+    Ok(ParsedDateTime { date, time, offset })
+}
+
+/// Value that is edited as text with date and time.
+pub(crate) trait DateTimeValue: Sized {
+    /// Formats the value into text that `parse` accepts.
+    fn format(&self) -> String;
+
+    /// Parses text into new value.
+    ///
+    /// Current value fills in what is missing in the text, like time zone.
+    fn parse(&self, text: &str) -> Result<Self, String>;
+
+    /// Returns date shown in the calendar.
+    /// Values without date are shown without calendar.
+    fn calendar_date(&self) -> Option<CivilDate> {
+        None
+    }
+
+    /// Returns the value with date replaced, keeping everything else.
+    fn with_calendar_date(&self, _date: CivilDate) -> Option<Self> {
+        None
+    }
+}
+
+#[derive(Clone)]
+struct TextEntryState {
+    text: String,
+    error: Option<String>,
+}
+
+/// Shows the value as editable text, followed by calendar button if the value has date.
+///
+/// Value is replaced as soon as edited text is parsed successfully,
+/// otherwise parse error is shown after the text.
+/// Text is reformatted once editing is finished, unless it has an error.
+pub(crate) fn date_time_probe<T>(value: &mut T, ui: &mut egui::Ui, style: &Style) -> egui::Response
+where
+    T: DateTimeValue,
+{
+    if style.read_only {
+        return ui.label(value.format());
+    }
+
+    let id = ui.make_persistent_id("probe_date_time");
+    let editing = ui.memory(|m| m.has_focus(id));
+    let mut state = match ui.ctx().data_mut(|d| d.get_temp::<TextEntryState>(id)) {
+        Some(state) if editing || state.error.is_some() => state,
+        _ => TextEntryState {
+            text: value.format(),
+            error: None,
+        },
+    };
+
+    let mut changed = false;
+
+    // Response covers the calendar button and the error, so that they are part of the row.
+    let mut response = ui
+        .horizontal(|ui| {
+            let mut text_edit = egui::TextEdit::singleline(&mut state.text).id(id);
+            if state.error.is_some() {
+                text_edit = text_edit.text_color(ui.visuals().error_fg_color);
+            }
+
+            if ui.add(text_edit).changed() {
+                match value.parse(&state.text) {
+                    Ok(parsed) => {
+                        *value = parsed;
+                        state.error = None;
+                        changed = true;
+                    }
+                    Err(err) => state.error = Some(err),
+                }
+            }
+
+            if let Some(mut date) = value.calendar_date() {
+                if date_picker(&mut date, ui).changed() {
+                    if let Some(picked) = value.with_calendar_date(date) {
+                        *value = picked;
+                        state.text = value.format();
+                        state.error = None;
+                        changed = true;
+                    }
+                }
+            }
+
+            if let Some(error) = &state.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        })
+        .response;
+
+    if changed {
+        response.mark_changed();
+    }
+
+    let keep = state.error.is_some() || ui.memory(|m| m.has_focus(id));
+    ui.ctx().data_mut(|d| match keep {
+        false => d.remove::<TextEntryState>(id),
+        true => d.insert_temp(id, state),
+    });
+
+    response
+}
+
+/// Shows popup below the button while it is open, until something is picked in it.
+///
+/// Unlike `egui::popup_below_widget`, popup stays open when clicked inside,
+/// so that it can be navigated.
+/// It is closed when clicked outside of it or when `Escape` is pressed.
+pub(crate) fn picker_popup<R>(
+    ui: &egui::Ui,
+    popup_id: egui::Id,
+    button: &egui::Response,
+    add_contents: impl FnOnce(&mut egui::Ui) -> Option<R>,
+) -> Option<R> {
+    if !ui.memory(|m| m.is_popup_open(popup_id)) {
+        return None;
+    }
+
+    let popup = egui::Area::new(popup_id)
+        .order(egui::Order::Foreground)
+        .constrain(true)
+        .fixed_pos(button.rect.left_bottom())
+        .show(ui.ctx(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, add_contents).inner
+        });
+
+    if popup.inner.is_some()
+        || ui.input(|i| i.key_pressed(egui::Key::Escape))
+        || (button.clicked_elsewhere() && popup.response.clicked_elsewhere())
+    {
+        ui.memory_mut(|m| m.close_popup());
+    }
+    popup.inner
+}
+
+/// Shows button that opens calendar to pick the date.
+fn date_picker(date: &mut CivilDate, ui: &mut egui::Ui) -> egui::Response {
+    let popup_id = ui.make_persistent_id("probe_date_picker");
+    let month_id = popup_id.with("month");
+
+    let mut response = ui.button("📅").on_hover_text("Pick date");
+    if response.clicked() {
+        ui.memory_mut(|m| m.toggle_popup(popup_id));
+
+        // Calendar opens at the month of the date.
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(month_id, (date.year, date.month)));
+    }
+
+    if let Some(picked) = picker_popup(ui, popup_id, &response, |ui| calendar(date, month_id, ui)) {
+        *date = picked;
+        response.mark_changed();
+    }
+    response
+}
+
+/// Shows days of a month, with buttons to switch months.
+/// Returns clicked date.
+fn calendar(date: &CivilDate, month_id: egui::Id, ui: &mut egui::Ui) -> Option<CivilDate> {
+    let (mut year, mut month) = ui
+        .ctx()
+        .data_mut(|d| d.get_temp(month_id))
+        .unwrap_or((date.year, date.month));
+
+    ui.horizontal(|ui| {
+        if ui.small_button("⏴").clicked() {
+            (year, month) = if month == 1 {
+                (year - 1, 12)
+            } else {
+                (year, month - 1)
+            };
+        }
+        ui.label(format!("{} {year}", MONTH_NAMES[month as usize - 1]));
+        if ui.small_button("⏵").clicked() {
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        }
+    });
+
+    let mut picked = None;
+    egui::Grid::new(month_id).show(ui, |ui| {
+        for name in WEEKDAY_NAMES {
+            ui.weak(name);
+        }
+        ui.end_row();
+
+        let first = CivilDate {
+            year,
+            month,
+            day: 1,
+        };
+        for _ in 0..first.weekday() {
+            ui.label("");
+        }
+
+        for day in 1..=days_in_month(year, month) {
+            let current = CivilDate { day, ..first };
+            if ui
+                .selectable_label(current == *date, day.to_string())
+                .clicked()
+            {
+                picked = Some(current);
+            }
+            if current.weekday() == 6 {
+                ui.end_row();
+            }
+        }
+    });
+
+    ui.ctx()
+        .data_mut(|d| d.insert_temp(month_id, (year, month)));
+    picked
+}
+
+/// Returns seconds since the Unix epoch and nanoseconds within the second.
+fn unix_timestamp(time: &SystemTime) -> (i64, u32) {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => (after.as_secs() as i64, after.subsec_nanos()),
+        Err(err) => {
+            let before = err.duration();
+            match before.subsec_nanos() {
+                0 => (-(before.as_secs() as i64), 0),
+                nanos => (-(before.as_secs() as i64) - 1, NANOS_PER_SEC - nanos),
+            }
+        }
+    }
+}
+
+fn from_unix_timestamp(secs: i64, nanos: u32) -> Option<SystemTime> {
+    let since_epoch = match u64::try_from(secs) {
+        Ok(secs) => UNIX_EPOCH.checked_add(Duration::from_secs(secs)),
+        Err(_) => UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs())),
+    };
+    since_epoch?.checked_add(Duration::from_nanos(nanos.into()))
+}
+
+/// System time is shown in UTC.
+impl DateTimeValue for SystemTime {
+    fn format(&self) -> String {
+        let (secs, nanos) = unix_timestamp(self);
+        let (date, time) = split_timestamp(secs, nanos);
+        format!("{date} {time} UTC")
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        let parsed = parse_date_time(text)?;
+        let secs = parsed.date.to_days() * SECS_PER_DAY + parsed.time.seconds_from_midnight()
+            - i64::from(parsed.offset.unwrap_or(0));
+        from_unix_timestamp(secs, parsed.time.nanosecond)
+            .ok_or_else(|| "time is out of range".to_owned())
+    }
+
+    fn calendar_date(&self) -> Option<CivilDate> {
+        let (secs, _) = unix_timestamp(self);
+        Some(CivilDate::from_days(secs.div_euclid(SECS_PER_DAY)))
+    }
+
+    fn with_calendar_date(&self, date: CivilDate) -> Option<Self> {
+        let (secs, nanos) = unix_timestamp(self);
+        let secs = date.to_days() * SECS_PER_DAY + secs.rem_euclid(SECS_PER_DAY);
+        from_unix_timestamp(secs, nanos)
+    }
+}
+
+impl EguiProbe for SystemTime {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        date_time_probe(self, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{path::join_path, EguiProbe, Style};
+
+/// Value with known default.
+///
+/// Label of modified value is marked and reset button is shown next to it.
+pub trait DefaultValue {
+    /// Returns `true` if the value equals its default.
+    fn is_default(&self) -> bool;
+
+    /// Replaces the value with its default.
+    fn reset_to_default(&mut self);
+}
+
+/// Modifier to mark the value when it differs from the default
+/// and show button to reset it.
+pub struct EguiProbeDefault<'a, T> {
+    pub value: &'a mut T,
+
+    /// Default value, taken when value is reset.
+    pub default: Option<T>,
+}
+
+impl<T> DefaultValue for EguiProbeDefault<'_, T>
+where
+    T: PartialEq,
+{
+    #[inline(always)]
+    fn is_default(&self) -> bool {
+        match &self.default {
+            Some(default) => *self.value == *default,
+            None => true,
+        }
+    }
+
+    #[inline(always)]
+    fn reset_to_default(&mut self) {
+        if let Some(default) = self.default.take() {
+            *self.value = default;
+        }
+    }
+}
+
+impl<T> EguiProbe for EguiProbeDefault<'_, T>
+where
+    T: EguiProbe + PartialEq,
+{
+    #[inline(always)]
+    fn as_default_value(&mut self) -> Option<&mut dyn DefaultValue> {
+        Some(self)
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        probe,
+        has_inner,
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        validate,
+        as_clipboard_value,
+        as_text_value,
+        as_multi_value,
+    );
+}
+
+/// Marks the value when it differs from the default
+/// and shows button to reset it.
+#[inline(always)]
+pub fn default_value<T>(value: &mut T, default: T) -> EguiProbeDefault<'_, T>
+where
+    T: EguiProbe + PartialEq,
+{
+    EguiProbeDefault {
+        value,
+        default: Some(default),
+    }
+}
+
+/// Modifier used by derive macro.
+///
+/// Value is compared with the default by `is_default` only when asked,
+/// so the default is not built unless the marker or reset button is shown.
+/// Reset is only requested, to be applied by the caller
+/// after the value is released.
+/// This allows combining with modifiers that borrow the value.
+#[doc(hidden)]
+pub struct EguiProbeReset<'a, P: ?Sized, D> {
+    pub value: &'a mut P,
+    pub is_default: D,
+    pub reset: &'a mut bool,
+}
+
+impl<P, D> DefaultValue for EguiProbeReset<'_, P, D>
+where
+    P: ?Sized,
+    D: Fn(&P) -> bool,
+{
+    #[inline(always)]
+    fn is_default(&self) -> bool {
+        (self.is_default)(self.value)
+    }
+
+    #[inline(always)]
+    fn reset_to_default(&mut self) {
+        *self.reset = true;
+    }
+}
+
+impl<P, D> EguiProbe for EguiProbeReset<'_, P, D>
+where
+    P: EguiProbe + ?Sized,
+    D: Fn(&P) -> bool,
+{
+    #[inline(always)]
+    fn as_default_value(&mut self) -> Option<&mut dyn DefaultValue> {
+        Some(self)
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        probe,
+        has_inner,
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        validate,
+        as_clipboard_value,
+        as_text_value,
+        as_multi_value,
+    );
+}
+
+/// Calls `f` with path of every inner value with known default.
+///
+/// Values without default are searched recursively.
+pub(crate) fn for_each_default(
+    value: &mut dyn EguiProbe,
+    path: &str,
+    f: &mut dyn FnMut(&str, &mut dyn DefaultValue),
+) {
+    value.iterate_inner(&mut |label, value| {
+        let path = join_path(path, label);
+        match value.as_default_value() {
+            Some(default_value) => f(&path, default_value),
+            None => {
+                if value.has_inner() {
+                    for_each_default(value, &path, f);
+                }
+            }
+        }
+    });
+}
+
+/// Adds marker showing that the value differs from the default.
+pub(crate) fn modified_marker(ui: &mut egui::Ui) {
+    let color = ui.visuals().selection.bg_fill;
+    ui.label(egui::RichText::new("⏺").small().color(color))
+        .on_hover_text("Modified");
+}
+
+/// Adds button to reset the value to default.
+///
+/// Button is disabled if the value is not modified or is read-only.
+/// Returns `true` if the button was clicked.
+pub(crate) fn reset_button(ui: &mut egui::Ui, style: &Style, modified: bool) -> bool {
+    let button = egui::Button::new(style.reset_button_text()).small();
+    ui.add_enabled(modified && !style.read_only, button)
+        .on_hover_text("Reset to default")
+        .clicked()
+}
+
+/// Adds button to reset all inner values to their defaults.
+///
+/// Button is shown only if some inner values have known default.
+/// This is checked once and remembered under `id`
+/// until the caller forgets it with [`forget_defaults`].
+/// Values are compared with their defaults only when the button is clicked.
+/// Paths of reset values are added to `changed_paths`.
+pub(crate) fn reset_all_button(
+    value: &mut dyn EguiProbe,
+    ui: &mut egui::Ui,
+    style: &Style,
+    id: egui::Id,
+    changed_paths: &mut Vec<String>,
+) {
+    let has_defaults = match ui.ctx().data(|d| d.get_temp::<bool>(id)) {
+        Some(has_defaults) => has_defaults,
+        None => {
+            let mut has_defaults = false;
+            for_each_default(value, "", &mut |_, _| has_defaults = true);
+            ui.ctx().data_mut(|d| d.insert_temp(id, has_defaults));
+            has_defaults
+        }
+    };
+
+    if !has_defaults {
+        return;
+    }
+
+    let button = egui::Button::new("Reset all").small();
+    if !ui.add_enabled(!style.read_only, button).clicked() {
+        return;
+    }
+
+    for_each_default(value, "", &mut |path, default_value| {
+        if !default_value.is_default() {
+            default_value.reset_to_default();
+            changed_paths.push(path.to_owned());
+        }
+    });
+}
+
+/// Forgets whether the value has inner defaults,
+/// so that it is checked again by [`reset_all_button`].
+pub(crate) fn forget_defaults(ctx: &egui::Context, id: egui::Id) {
+    ctx.data_mut(|d| d.remove::<bool>(id));
+}
@@ -0,0 +1,126 @@
+use std::{collections::VecDeque, ops::Range};
+
+use crate::{
+    collections::{clamp_range, iterate_sequence, EguiProbeFrozen, Sequence},
+    option::option_probe_with,
+    EguiProbe,
+};
+
+impl<T> EguiProbe for VecDeque<T>
+where
+    T: EguiProbe + Default,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        ui.horizontal(|ui| {
+            let r = ui.weak(format!("[{}]", self.len()));
+            if style.read_only {
+                return r;
+            }
+            let mut add = ui.small_button(style.add_button_text());
+            if add.clicked() {
+                self.push_back(T::default());
+                add.mark_changed();
+            }
+            r | add
+        })
+        .inner
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        iterate_sequence(self, 0..usize::MAX, None, f);
+    }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_sequence(self, range, None, f)
+    }
+}
+
+impl<T> Sequence for VecDeque<T> {
+    type Item = T;
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn for_each_item(&mut self, range: Range<usize>, f: &mut dyn FnMut(usize, &mut T)) {
+        let start = range.start;
+        for (i, value) in self.range_mut(range).enumerate() {
+            f(start + i, value);
+        }
+    }
+
+    fn insert_item(&mut self, idx: usize, item: T) {
+        self.insert(idx, item);
+    }
+
+    fn remove_item(&mut self, idx: usize) -> T {
+        self.remove(idx).expect("index out of bounds")
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, VecDeque<T>>
+where
+    T: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+        ui.weak(format!("[{}]", self.value.len()))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.value.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        for (i, value) in self.value.iter_mut().enumerate() {
+            f(&format!("[{i}]"), value);
+        }
+    }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        let len = self.value.len();
+        let range = clamp_range(range, len);
+        let start = range.start;
+        for (i, value) in self.value.range_mut(range).enumerate() {
+            f(&format!("[{}]", start + i), value);
+        }
+        len
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, Option<VecDeque<T>>>
+where
+    T: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        option_probe_with(self.value, ui, style, |value, ui, _style| {
+            ui.weak(format!("[{}]", value.len()))
+        })
+    }
+
+    fn has_inner(&mut self) -> bool {
+        match self.value {
+            Some(value) => !value.is_empty(),
+            None => false,
+        }
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        if let Some(vec) = self.value {
+            for (i, value) in vec.iter_mut().enumerate() {
+                f(&format!("[{i}]"), value);
+            }
+        }
+    }
+}
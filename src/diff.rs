@@ -0,0 +1,389 @@
+use std::collections::HashSet;
+
+use crate::{
+    collections::without_sequence_controls,
+    multi::{assign_probe, mixed_paths, pair_inner},
+    path::{is_within, join_path, visit_paths},
+    widget::{row_ui, ProbeHeader},
+    EguiProbe, ProbeLayout, Style,
+};
+
+/// Collects paths of values that differ between left and right values,
+/// including values present on one side only.
+fn diff_paths<T>(left: &mut T, right: &mut T) -> HashSet<String>
+where
+    T: EguiProbe,
+{
+    let mut paths = mixed_paths(left, &mut [&mut *right]);
+    collect_added(right, left, "", &mut paths);
+    paths
+}
+
+/// Collects paths of inner values of the right value missing on the left side.
+fn collect_added(
+    right: &mut dyn EguiProbe,
+    left: &mut dyn EguiProbe,
+    path: &str,
+    paths: &mut HashSet<String>,
+) {
+    pair_inner(right, left, |label, right, left| {
+        let path = join_path(path, label);
+        match left {
+            Some(left) => collect_added(right, left, &path, paths),
+            None => {
+                paths.insert(path.clone());
+                visit_paths(right, &mut |inner, _| {
+                    paths.insert(join_path(&path, inner));
+                });
+            }
+        }
+    });
+}
+
+/// Response of the [`ProbeDiff`] widget.
+pub struct ProbeDiffResponse {
+    /// Response of the whole diff widget.
+    /// It is marked as changed if any value was changed.
+    pub response: egui::Response,
+
+    /// Paths of the left values that were changed this frame,
+    /// either edited or copied from the right side.
+    pub left_changed_paths: Vec<String>,
+
+    /// Paths of the right values that were changed this frame,
+    /// either edited or copied from the left side.
+    pub right_changed_paths: Vec<String>,
+
+    /// Sorted paths of values that differ between sides.
+    ///
+    /// Parents of differing values are included only
+    /// if they can be compared as a whole.
+    pub diff_paths: Vec<String>,
+}
+
+impl ProbeDiffResponse {
+    /// Returns `true` if any value was changed this frame.
+    #[inline]
+    pub fn changed(&self) -> bool {
+        !self.left_changed_paths.is_empty() || !self.right_changed_paths.is_empty()
+    }
+
+    /// Returns `true` if values differ.
+    #[inline]
+    pub fn differs(&self) -> bool {
+        !self.diff_paths.is_empty()
+    }
+}
+
+/// Widget for comparing two values of the same type side by side.
+///
+/// Shows a table with labels, left values and right values.
+/// Rows of differing values are highlighted,
+/// subtrees without differences are collapsed initially.
+/// Differing values can be copied from one side to the other.
+///
+/// Values are compared via [`crate::MultiValue`],
+/// values that do not support it are never shown as differing
+/// unless their inner values do.
+///
+/// # Example
+///
+/// ```
+/// # use egui_probe::ProbeDiff;
+/// # egui::__run_test_ui(|ui| {
+/// let mut saved = 1.0f32;
+/// let mut current = 2.0f32;
+/// ProbeDiff::new("config", &mut saved, &mut current).show(ui);
+/// # });
+/// ```
+pub struct ProbeDiff<'a, T> {
+    id_source: egui::Id,
+    label: egui::WidgetText,
+    style: Style,
+    left: &'a mut T,
+    right: &'a mut T,
+}
+
+impl<'a, T> ProbeDiff<'a, T>
+where
+    T: EguiProbe,
+{
+    /// Creates a new `ProbeDiff` widget.
+    pub fn new(label: impl Into<egui::WidgetText>, left: &'a mut T, right: &'a mut T) -> Self {
+        let label = label.into();
+        ProbeDiff {
+            id_source: egui::Id::new(label.text()),
+            label,
+            style: Style::default(),
+            left,
+            right,
+        }
+    }
+
+    /// Shows values without allowing to edit or copy them.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.style.read_only = read_only;
+        self
+    }
+
+    /// Show UI to compare the values.
+    pub fn show(self, ui: &mut egui::Ui) -> ProbeDiffResponse {
+        let mut diff = diff_paths(self.left, self.right);
+
+        let mut left_changed_paths = Vec::new();
+        let mut right_changed_paths = Vec::new();
+
+        let mut response = ui
+            .allocate_ui(ui.available_size(), |ui| {
+                let child_ui = &mut ui.child_ui_with_id_source(
+                    ui.max_rect(),
+                    egui::Layout::top_down(egui::Align::Min),
+                    self.id_source,
+                );
+
+                let label = self.label.text().to_owned();
+
+                egui::Frame::none()
+                    .fill(child_ui.visuals().extreme_bg_color)
+                    .inner_margin(child_ui.spacing().item_spacing * 0.5)
+                    .show(child_ui, |child_ui| {
+                        child_ui.horizontal(|child_ui| {
+                            child_ui.label(self.label);
+                            match diff.len() {
+                                0 => child_ui.weak("No differences"),
+                                1 => child_ui.weak("1 difference"),
+                                n => child_ui.weak(format!("{n} differences")),
+                            };
+                        });
+                    });
+
+                let mut table = DiffTable {
+                    id: child_ui.make_persistent_id("probe_diff_rows"),
+                    labels: ProbeLayout::load(
+                        child_ui.ctx(),
+                        child_ui.make_persistent_id("probe_diff_layout"),
+                    ),
+                    lefts: ProbeLayout::load(
+                        child_ui.ctx(),
+                        child_ui.make_persistent_id("probe_diff_left"),
+                    ),
+                    style: &self.style,
+                    diff: &diff,
+                    left_changed_paths: &mut left_changed_paths,
+                    right_changed_paths: &mut right_changed_paths,
+                };
+
+                // Items are paired by index, so they are not reordered in the diff.
+                without_sequence_controls(&child_ui.ctx().clone(), || {
+                    if self.left.has_inner() || self.right.has_inner() {
+                        show_rows(
+                            Some(self.left),
+                            Some(self.right),
+                            &mut table,
+                            "",
+                            0,
+                            child_ui,
+                        );
+                    } else {
+                        show_row(
+                            &label,
+                            Some(self.left),
+                            Some(self.right),
+                            &mut table,
+                            String::new(),
+                            0,
+                            child_ui,
+                        );
+                    }
+                });
+
+                table.labels.store(child_ui.ctx());
+                table.lefts.store(child_ui.ctx());
+
+                let final_rect = child_ui.min_rect();
+                ui.advance_cursor_after_rect(final_rect);
+            })
+            .response;
+
+        if !left_changed_paths.is_empty() || !right_changed_paths.is_empty() {
+            response.mark_changed();
+            diff = diff_paths(self.left, self.right);
+        }
+
+        let mut diff_paths: Vec<String> = diff.into_iter().collect();
+        diff_paths.sort();
+
+        ProbeDiffResponse {
+            response,
+            left_changed_paths,
+            right_changed_paths,
+            diff_paths,
+        }
+    }
+}
+
+/// State shared by all rows of the diff table.
+struct DiffTable<'a> {
+    id: egui::Id,
+
+    // Width of labels column.
+    labels: ProbeLayout,
+
+    // Width of left values column.
+    lefts: ProbeLayout,
+    style: &'a Style,
+    diff: &'a HashSet<String>,
+    left_changed_paths: &'a mut Vec<String>,
+    right_changed_paths: &'a mut Vec<String>,
+}
+
+/// Shows rows of inner values of both sides.
+///
+/// Inner values are paired by label,
+/// values present on the right side only are shown last.
+fn show_rows(
+    left: Option<&mut (dyn EguiProbe + '_)>,
+    right: Option<&mut (dyn EguiProbe + '_)>,
+    table: &mut DiffTable,
+    path: &str,
+    indent: usize,
+    ui: &mut egui::Ui,
+) {
+    match (left, right) {
+        (Some(left), Some(right)) => {
+            let mut left_labels = HashSet::new();
+            let all_paired = pair_inner(left, right, |label, left, right| {
+                left_labels.insert(label.to_owned());
+                let path = join_path(path, label);
+                show_row(label, Some(left), right, table, path, indent, ui);
+            });
+
+            if !all_paired {
+                right.iterate_inner(&mut |label, right| {
+                    if !left_labels.contains(label) {
+                        let path = join_path(path, label);
+                        show_row(label, None, Some(right), table, path, indent, ui);
+                    }
+                });
+            }
+        }
+        (Some(left), None) => left.iterate_inner(&mut |label, left| {
+            let path = join_path(path, label);
+            show_row(label, Some(left), None, table, path, indent, ui);
+        }),
+        (None, Some(right)) => right.iterate_inner(&mut |label, right| {
+            let path = join_path(path, label);
+            show_row(label, None, Some(right), table, path, indent, ui);
+        }),
+        (None, None) => {}
+    }
+}
+
+/// Shows value of one side, or "—" if the side has no such value.
+fn side_probe(value: Option<&mut (dyn EguiProbe + '_)>, ui: &mut egui::Ui, style: &Style) -> bool {
+    match value {
+        Some(value) => value.probe(ui, style).changed(),
+        None => {
+            ui.weak("—").on_hover_text("Missing");
+            false
+        }
+    }
+}
+
+fn show_row(
+    label: &str,
+    mut left: Option<&mut (dyn EguiProbe + '_)>,
+    mut right: Option<&mut (dyn EguiProbe + '_)>,
+    table: &mut DiffTable,
+    path: String,
+    indent: usize,
+    ui: &mut egui::Ui,
+) {
+    let id = table.id.with(&path);
+
+    let differs = table.diff.contains(&path);
+    let subtree_differs = table.diff.iter().any(|other| is_within(other, &path));
+
+    let has_inner = left.as_deref_mut().is_some_and(|value| value.has_inner())
+        || right.as_deref_mut().is_some_and(|value| value.has_inner());
+
+    // Identical subtrees are collapsed until opened.
+    let mut header = has_inner.then(|| ProbeHeader::load_or(ui.ctx(), id, subtree_differs));
+
+    let background = ui.painter().add(egui::Shape::Noop);
+
+    let row = row_ui(ui, &path, label, indent, |ui, parts| {
+        let label_response = table
+            .labels
+            .inner_label_ui(indent, id.with("label"), ui, |ui| {
+                if let Some(header) = &mut header {
+                    parts.collapse = Some(header.collapse_button(ui).rect);
+                }
+                if subtree_differs {
+                    ui.colored_label(ui.visuals().warn_fg_color, label)
+                } else {
+                    ui.label(label)
+                }
+            });
+
+        let style = table.style;
+
+        let mut left_changed = false;
+        let left_response = table.lefts.inner_label_ui(0, id.with("left"), ui, |ui| {
+            ui.horizontal(|ui| left_changed = side_probe(left.as_deref_mut(), ui, style))
+                .response
+        });
+
+        let mut right_changed = false;
+        let mut right_rect = egui::Rect::NOTHING;
+        table.labels.inner_value_ui(id.with("right"), ui, |ui| {
+            right_changed = side_probe(right.as_deref_mut(), ui, style);
+
+            if let (Some(left), Some(right)) = (left.as_deref_mut(), right.as_deref_mut()) {
+                if subtree_differs && !style.read_only {
+                    let r = ui
+                        .small_button("←")
+                        .on_hover_text("Copy right value to the left");
+                    if r.clicked() && assign_probe(right, left) {
+                        left_changed = true;
+                    }
+
+                    let r = ui
+                        .small_button("→")
+                        .on_hover_text("Copy left value to the right");
+                    if r.clicked() && assign_probe(left, right) {
+                        right_changed = true;
+                    }
+                }
+            }
+
+            right_rect = ui.min_rect();
+        });
+
+        if left_changed {
+            table.left_changed_paths.push(path.clone());
+        }
+        if right_changed {
+            table.right_changed_paths.push(path.clone());
+        }
+
+        parts.open = header.as_ref().map(|header| header.is_open());
+        parts.label = label_response.rect;
+        parts.value = left_response.rect.union(right_rect);
+    });
+
+    if differs {
+        let color = ui.visuals().warn_fg_color.gamma_multiply(0.1);
+        ui.painter().set(
+            background,
+            egui::Shape::rect_filled(row.response.rect, 0.0, color),
+        );
+    }
+
+    if let Some(header) = header {
+        if header.is_open() {
+            show_rows(left, right, table, &path, indent + 1, ui);
+        }
+        header.store(ui.ctx());
+    }
+}
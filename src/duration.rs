@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use crate::{num::drag_value, ClipboardValue, EguiProbe, MultiValue, Style};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Unit in which duration is edited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DurationUnit {
+    Nanos,
+    Micros,
+    Millis,
+    Secs,
+    Mins,
+    Hours,
+}
+
+impl DurationUnit {
+    const ALL: [DurationUnit; 6] = [
+        DurationUnit::Nanos,
+        DurationUnit::Micros,
+        DurationUnit::Millis,
+        DurationUnit::Secs,
+        DurationUnit::Mins,
+        DurationUnit::Hours,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            DurationUnit::Nanos => "ns",
+            DurationUnit::Micros => "µs",
+            DurationUnit::Millis => "ms",
+            DurationUnit::Secs => "s",
+            DurationUnit::Mins => "min",
+            DurationUnit::Hours => "h",
+        }
+    }
+
+    fn nanos(self) -> u128 {
+        match self {
+            DurationUnit::Nanos => 1,
+            DurationUnit::Micros => 1_000,
+            DurationUnit::Millis => 1_000_000,
+            DurationUnit::Secs => NANOS_PER_SEC,
+            DurationUnit::Mins => 60 * NANOS_PER_SEC,
+            DurationUnit::Hours => 3600 * NANOS_PER_SEC,
+        }
+    }
+
+    /// Returns the largest unit that is not longer than the duration.
+    /// Zero duration is shown in seconds.
+    fn fit(duration: Duration) -> Self {
+        let nanos = duration.as_nanos();
+        Self::ALL
+            .into_iter()
+            .rev()
+            .find(|unit| nanos >= unit.nanos())
+            .unwrap_or(DurationUnit::Secs)
+    }
+}
+
+/// Converts number of nanoseconds into duration, saturating at the bounds.
+fn from_nanos(nanos: f64) -> Duration {
+    let nanos = nanos.round() as u128;
+    let secs = (nanos / NANOS_PER_SEC).min(u64::MAX as u128) as u64;
+    Duration::new(secs, (nanos % NANOS_PER_SEC) as u32)
+}
+
+/// Duration is shown as amount of selected unit.
+///
+/// Unit is picked to fit the duration when it is shown first
+/// and is kept while the duration changes.
+impl EguiProbe for Duration {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        let id = ui.make_persistent_id("probe_duration_unit");
+        let mut unit = ui
+            .ctx()
+            .data_mut(|d| d.get_temp(id))
+            .unwrap_or_else(|| DurationUnit::fit(*self));
+
+        let mut changed = false;
+
+        // Response covers the unit selector, so that it is part of the row.
+        let mut response = ui
+            .horizontal(|ui| {
+                let mut amount = self.as_nanos() as f64 / unit.nanos() as f64;
+                if drag_value(&mut amount, Some(0.0..=f64::MAX), ui, style).changed() {
+                    *self = from_nanos(amount * unit.nanos() as f64);
+                    changed = true;
+                }
+
+                egui::ComboBox::from_id_source(id)
+                    .selected_text(unit.name())
+                    .width(ui.spacing().interact_size.x)
+                    .show_ui(ui, |ui| {
+                        for option in DurationUnit::ALL {
+                            ui.selectable_value(&mut unit, option, option.name());
+                        }
+                    });
+            })
+            .response;
+
+        if changed {
+            response.mark_changed();
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(id, unit));
+        response
+    }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    datetime::picker_popup, option::option_probe_with, ClipboardValue, EguiProbe, MultiValue, Style,
+};
+
+/// Restricts paths that can be picked in the file browser.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PathFilter {
+    /// Extensions of files that can be picked, without the dot.
+    /// Files with any extension can be picked if empty.
+    pub extensions: &'static [&'static str],
+
+    /// Only directories can be picked.
+    pub dir_only: bool,
+}
+
+impl PathFilter {
+    fn accepts_file(&self, path: &Path) -> bool {
+        if self.dir_only {
+            return false;
+        }
+        if self.extensions.is_empty() {
+            return true;
+        }
+
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        extension.is_some_and(|extension| {
+            self.extensions
+                .iter()
+                .any(|accepted| accepted.eq_ignore_ascii_case(extension))
+        })
+    }
+}
+
+/// Modifier to restrict paths picked in the file browser.
+pub struct EguiProbeFilePath<'a, T> {
+    pub value: &'a mut T,
+    pub filter: PathFilter,
+}
+
+/// Shows the path with file browser that picks only paths accepted by the filter.
+///
+/// Add `#[egui_probe(path(extensions = ["png", "jpg"], dir_only))]`
+/// to the field to do the same with derive.
+#[inline(always)]
+pub fn file_path<T>(value: &mut T, filter: PathFilter) -> EguiProbeFilePath<'_, T>
+where
+    for<'a> EguiProbeFilePath<'a, T>: EguiProbe,
+{
+    EguiProbeFilePath { value, filter }
+}
+
+#[derive(Clone)]
+struct BrowserEntry {
+    name: OsString,
+    is_dir: bool,
+}
+
+#[derive(Clone)]
+struct BrowserState {
+    dir: PathBuf,
+    entries: Result<Vec<BrowserEntry>, String>,
+}
+
+impl BrowserState {
+    fn open(dir: PathBuf, filter: &PathFilter) -> Self {
+        let entries = read_entries(&dir, filter);
+        BrowserState { dir, entries }
+    }
+}
+
+/// Lists directories and files accepted by the filter.
+/// Directories are listed first.
+fn read_entries(dir: &Path, filter: &PathFilter) -> Result<Vec<BrowserEntry>, String> {
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(|err| err.to_string())? {
+        let Ok(entry) = entry else {
+            continue;
+        };
+
+        let path = entry.path();
+        let is_dir = path.is_dir();
+        if is_dir || filter.accepts_file(&path) {
+            entries.push(BrowserEntry {
+                name: entry.file_name(),
+                is_dir,
+            });
+        }
+    }
+
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    Ok(entries)
+}
+
+/// Returns directory to start browsing from.
+///
+/// This is the path itself if it is a directory, otherwise its closest existing ancestor.
+/// Relative paths are relative to the working directory.
+fn start_dir(path: &Path) -> PathBuf {
+    let path = match std::env::current_dir() {
+        Ok(cwd) => cwd.join(path),
+        Err(_) => path.to_owned(),
+    };
+
+    match path.ancestors().find(|dir| dir.is_dir()) {
+        Some(dir) => dir.to_owned(),
+        None => path,
+    }
+}
+
+/// Picked path is kept relative to the working directory if the original path is relative.
+fn keep_relative(original: &Path, picked: PathBuf) -> PathBuf {
+    if original.is_absolute() {
+        return picked;
+    }
+
+    let Ok(cwd) = std::env::current_dir() else {
+        return picked;
+    };
+
+    match picked.strip_prefix(&cwd) {
+        Ok(relative) if relative.as_os_str().is_empty() => PathBuf::from("."),
+        Ok(relative) => relative.to_owned(),
+        Err(_) => picked,
+    }
+}
+
+/// Shows entries of the directory.
+/// Clicked directories are opened, clicked files are picked.
+fn browser(state: &mut BrowserState, filter: &PathFilter, ui: &mut egui::Ui) -> Option<PathBuf> {
+    ui.set_min_width(ui.spacing().text_edit_width);
+
+    let mut open = None;
+    let mut picked = None;
+
+    ui.horizontal(|ui| {
+        let parent = state.dir.parent();
+        if ui
+            .add_enabled(parent.is_some(), egui::Button::new("⬆").small())
+            .on_hover_text("Parent directory")
+            .clicked()
+        {
+            open = parent.map(Path::to_owned);
+        }
+        ui.label(state.dir.display().to_string());
+    });
+    ui.separator();
+
+    egui::ScrollArea::vertical()
+        .max_height(ui.spacing().combo_height)
+        .show(ui, |ui| match &state.entries {
+            Err(err) => {
+                ui.colored_label(ui.visuals().error_fg_color, err);
+            }
+            Ok(entries) if entries.is_empty() => {
+                ui.weak("empty");
+            }
+            Ok(entries) => {
+                for entry in entries {
+                    let icon = if entry.is_dir { "📁" } else { "📄" };
+                    let text = format!("{icon} {}", entry.name.to_string_lossy());
+                    if ui.selectable_label(false, text).clicked() {
+                        let path = state.dir.join(&entry.name);
+                        match entry.is_dir {
+                            true => open = Some(path),
+                            false => picked = Some(path),
+                        }
+                    }
+                }
+            }
+        });
+
+    if filter.dir_only {
+        ui.separator();
+        if ui
+            .button("Select")
+            .on_hover_text("Pick this directory")
+            .clicked()
+        {
+            picked = Some(state.dir.clone());
+        }
+    }
+
+    if let Some(dir) = open {
+        *state = BrowserState::open(dir, filter);
+    }
+    picked
+}
+
+/// Shows button that opens file browser, returns picked path.
+fn browse_button(path: &Path, filter: &PathFilter, ui: &mut egui::Ui) -> Option<PathBuf> {
+    let popup_id = ui.make_persistent_id("probe_file_browser");
+
+    let hover = match filter.dir_only {
+        false => "Browse files",
+        true => "Browse directories",
+    };
+    let response = ui.button("🗁").on_hover_text(hover);
+    if response.clicked() {
+        ui.memory_mut(|m| m.toggle_popup(popup_id));
+
+        let state = BrowserState::open(start_dir(path), filter);
+        ui.ctx().data_mut(|d| d.insert_temp(popup_id, state));
+    }
+
+    let mut state = ui
+        .ctx()
+        .data_mut(|d| d.get_temp::<BrowserState>(popup_id))?;
+    let picked = picker_popup(ui, popup_id, &response, |ui| {
+        browser(&mut state, filter, ui)
+    });
+
+    let open = ui.memory(|m| m.is_popup_open(popup_id));
+    ui.ctx().data_mut(|d| match open {
+        false => d.remove::<BrowserState>(popup_id),
+        true => d.insert_temp(popup_id, state),
+    });
+
+    picked.map(|picked| keep_relative(path, picked))
+}
+
+/// Seconds after which existence of unchanged path is checked again.
+const STATUS_INTERVAL: f64 = 2.0;
+
+/// Result of the last check of the path on the file system.
+#[derive(Clone)]
+struct PathStatus {
+    path: PathBuf,
+
+    /// `None` if the path does not exist,
+    /// otherwise whether it is a directory.
+    is_dir: Option<bool>,
+    time: f64,
+}
+
+/// Checks the path on the file system only when it changes
+/// or the last check is older than [`STATUS_INTERVAL`],
+/// so that slow file systems don't block every frame.
+fn check_path(path: &Path, ui: &egui::Ui) -> Option<bool> {
+    let id = ui.id().with("path_status");
+    let time = ui.input(|i| i.time);
+
+    let cached = ui.ctx().data(|d| d.get_temp::<PathStatus>(id));
+    if let Some(status) = cached {
+        if status.path == path && time - status.time < STATUS_INTERVAL {
+            return status.is_dir;
+        }
+    }
+
+    let is_dir = std::fs::metadata(path).ok().map(|meta| meta.is_dir());
+    let status = PathStatus {
+        path: path.to_owned(),
+        is_dir,
+        time,
+    };
+    ui.ctx().data_mut(|d| d.insert_temp(id, status));
+    is_dir
+}
+
+/// Shows whether the path exists and is accepted by the filter.
+fn path_status(path: &Path, filter: &PathFilter, ui: &mut egui::Ui) {
+    let problem = match check_path(path, ui) {
+        None => "Path does not exist".to_owned(),
+        Some(true) => {
+            if !filter.dir_only && !filter.extensions.is_empty() {
+                "Expected file".to_owned()
+            } else {
+                ui.weak("📁").on_hover_text("Directory exists");
+                return;
+            }
+        }
+        Some(false) if filter.dir_only => "Expected directory".to_owned(),
+        Some(false) if filter.accepts_file(path) => {
+            ui.weak("📄").on_hover_text("File exists");
+            return;
+        }
+        Some(false) => {
+            let extensions: Vec<_> = filter
+                .extensions
+                .iter()
+                .map(|ext| format!(".{ext}"))
+                .collect();
+            format!("Expected {} file", extensions.join(" or "))
+        }
+    };
+
+    ui.colored_label(ui.visuals().warn_fg_color, "⚠")
+        .on_hover_text(problem);
+}
+
+/// Shows the path as editable text, followed by its status and the browse button.
+fn path_probe(
+    path: &mut PathBuf,
+    filter: &PathFilter,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response {
+    let mut changed = false;
+
+    // Response covers the status and the button, so that they are part of the row.
+    let mut response = ui
+        .horizontal(|ui| {
+            if style.read_only {
+                ui.label(path.display().to_string());
+            } else {
+                let mut text = path.to_string_lossy().into_owned();
+                if ui.add(egui::TextEdit::singleline(&mut text)).changed() {
+                    *path = PathBuf::from(text);
+                    changed = true;
+                }
+            }
+
+            if !path.as_os_str().is_empty() {
+                path_status(path, filter, ui);
+            }
+
+            if !style.read_only {
+                if let Some(picked) = browse_button(path, filter, ui) {
+                    *path = picked;
+                    changed = true;
+                }
+            }
+        })
+        .response;
+
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
+
+/// Path is shown as text with indicator whether it exists,
+/// and with file browser to pick it.
+impl EguiProbe for PathBuf {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        path_probe(self, &PathFilter::default(), ui, style)
+    }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
+
+impl EguiProbe for EguiProbeFilePath<'_, PathBuf> {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        path_probe(self.value, &self.filter, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
+}
+
+impl EguiProbe for EguiProbeFilePath<'_, Option<PathBuf>> {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        let filter = self.filter;
+        option_probe_with(self.value, ui, style, |path, ui, style| {
+            path_probe(path, &filter, ui, style)
+        })
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
+}
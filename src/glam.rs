@@ -0,0 +1,332 @@
+use core::{
+    fmt::Display,
+    ops::{RangeFrom, RangeFull, RangeInclusive, RangeToInclusive},
+};
+
+use glam::{
+    Affine2, Affine3A, DMat2, DMat3, DMat4, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3,
+    Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+
+use crate::{
+    algebra::{
+        components_probe, matrix_probe, normalized_components, quaternion_probe,
+        EguiProbeNormalized,
+    },
+    angle,
+    num::EguiProbeRange,
+    ClipboardValue, EguiProbe, MultiValue, Style,
+};
+
+/// Shows components of the vector in a row.
+///
+/// Components are clamped to the range if one is given.
+fn vector_probe<V, T, const N: usize>(
+    value: &mut V,
+    range: Option<RangeInclusive<T>>,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response
+where
+    V: Copy + Into<[T; N]> + From<[T; N]>,
+    T: egui::emath::Numeric + Display,
+{
+    let mut components: [T; N] = (*value).into();
+    let r = components_probe(&mut components, range, ui, style);
+    if r.changed() {
+        *value = V::from(components);
+    }
+    r
+}
+
+macro_rules! impl_for_vectors {
+    ($vec:ident: $scalar:ident; $n:literal) => {
+        impl EguiProbe for $vec {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                ui.horizontal(|ui| vector_probe::<_, $scalar, $n>(self, None, ui, style))
+                    .inner
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+
+        impl EguiProbe for EguiProbeRange<'_, $vec, RangeFull> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                let range = $scalar::MIN..=$scalar::MAX;
+                ui.horizontal(|ui| {
+                    vector_probe::<_, $scalar, $n>(self.value, Some(range), ui, style)
+                })
+                .inner
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
+            }
+        }
+
+        impl EguiProbe for EguiProbeRange<'_, $vec, RangeFrom<$scalar>> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                let range = self.range.start..=$scalar::MAX;
+                ui.horizontal(|ui| {
+                    let r = vector_probe::<_, $scalar, $n>(self.value, Some(range), ui, style);
+                    ui.weak(format!("{}..", self.range.start));
+                    r
+                })
+                .inner
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
+            }
+        }
+
+        impl EguiProbe for EguiProbeRange<'_, $vec, RangeToInclusive<$scalar>> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                let range = $scalar::MIN..=self.range.end;
+                ui.horizontal(|ui| {
+                    let r = vector_probe::<_, $scalar, $n>(self.value, Some(range), ui, style);
+                    ui.weak(format!("..={}", self.range.end));
+                    r
+                })
+                .inner
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
+            }
+        }
+
+        impl EguiProbe for EguiProbeRange<'_, $vec, RangeInclusive<$scalar>> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                let range = self.range.clone();
+                ui.horizontal(|ui| {
+                    let r = vector_probe::<_, $scalar, $n>(self.value, Some(range), ui, style);
+                    ui.weak(format!("{}..={}", self.range.start(), self.range.end()));
+                    r
+                })
+                .inner
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
+            }
+        }
+    };
+
+    ($($vec:ident: $scalar:ident; $n:literal),* $(,)?) => {
+        $(impl_for_vectors!($vec: $scalar; $n);)*
+    };
+}
+
+impl_for_vectors!(
+    Vec2: f32; 2,
+    Vec3: f32; 3,
+    Vec3A: f32; 3,
+    Vec4: f32; 4,
+    DVec2: f64; 2,
+    DVec3: f64; 3,
+    DVec4: f64; 4,
+    IVec2: i32; 2,
+    IVec3: i32; 3,
+    IVec4: i32; 4,
+    UVec2: u32; 2,
+    UVec3: u32; 3,
+    UVec4: u32; 4,
+);
+
+macro_rules! impl_normalized {
+    ($($vec:ident: $n:literal),*) => {
+        $(
+            impl EguiProbe for EguiProbeNormalized<'_, $vec> {
+                fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                    let mut components: [_; $n] = (*self.value).into();
+                    let r = ui
+                        .horizontal(|ui| normalized_components(&mut components, ui, style))
+                        .inner;
+                    if r.changed() {
+                        *self.value = components.into();
+                    }
+                    r
+                }
+
+                #[inline(always)]
+                fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                    Some(self.value)
+                }
+            }
+        )*
+    };
+}
+
+impl_normalized!(Vec2: 2, Vec3: 3, Vec3A: 3, Vec4: 4, DVec2: 2, DVec3: 3, DVec4: 4);
+
+/// Shows the quaternion with the editor shared by all math libraries.
+fn quat_probe(
+    value: &mut Quat,
+    normalized: bool,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response {
+    let mut components = value.to_array();
+    let r = quaternion_probe(&mut components, normalized, ui, style);
+    if r.changed() {
+        *value = Quat::from_array(components);
+    }
+    r
+}
+
+impl EguiProbe for Quat {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        quat_probe(self, false, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
+
+impl EguiProbe for EguiProbeNormalized<'_, Quat> {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        quat_probe(self.value, true, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
+}
+
+macro_rules! impl_for_matrices {
+    ($($mat:ident: $n:literal),* $(,)?) => {
+        $(
+            impl EguiProbe for $mat {
+                fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                    let mut elements = self.to_cols_array();
+                    let r = matrix_probe(&mut elements, $n, false, ui, style);
+                    if r.changed() {
+                        *self = $mat::from_cols_array(&elements);
+                    }
+                    r
+                }
+
+                #[inline(always)]
+                fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                    Some(self)
+                }
+
+                #[inline(always)]
+                fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_matrices!(
+    Mat2: 2,
+    Mat3: 3,
+    Mat3A: 3,
+    Mat4: 4,
+    DMat2: 2,
+    DMat3: 3,
+    DMat4: 4,
+);
+
+/// Affine transform is shown decomposed into translation, rotation and scale.
+///
+/// Transform is recomposed only when one of them is changed,
+/// so that shear is not lost by just showing the transform.
+impl EguiProbe for Affine2 {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Affine2")
+    }
+
+    #[inline(always)]
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        let decomposed = self.to_scale_angle_translation();
+        let (mut scale, mut rotation, mut translation) = decomposed;
+
+        f("translation", &mut translation);
+        f("rotation", &mut angle(&mut rotation));
+        f("scale", &mut scale);
+
+        if (scale, rotation, translation) != decomposed {
+            *self = Affine2::from_scale_angle_translation(scale, rotation, translation);
+        }
+    }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
+
+/// Affine transform is shown decomposed into translation, rotation and scale.
+///
+/// Transform is recomposed only when one of them is changed,
+/// so that shear is not lost by just showing the transform.
+impl EguiProbe for Affine3A {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Affine3A")
+    }
+
+    #[inline(always)]
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        let decomposed = self.to_scale_rotation_translation();
+        let (mut scale, mut rotation, mut translation) = decomposed;
+
+        f("translation", &mut translation);
+        f("rotation", &mut rotation);
+        f("scale", &mut scale);
+
+        if (scale, rotation, translation) != decomposed {
+            *self = Affine3A::from_scale_rotation_translation(scale, rotation, translation);
+        }
+    }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
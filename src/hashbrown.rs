@@ -10,7 +10,7 @@ use crate::{
     option::option_probe_with,
     EguiProbe, Style,
 };
-use crate::map::HashMapProbe;
+use crate::map::add_key_probe;
 
 impl<K, V, S> EguiProbe for HashMap<K, V, S>
 where
@@ -19,37 +19,13 @@ where
     S: std::hash::BuildHasher,
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
-        ui.horizontal(|ui| {
-            let mut probe = HashMapProbe::load(ui.ctx(), ui.make_persistent_id("HashMapProbe"));
-
-            let mut reduce_text_width = 0.0;
-
-            let r = ui.weak(format!("[{}]", self.len()));
-            reduce_text_width += r.rect.width() + ui.spacing().item_spacing.x;
-
-            let r = ui.small_button(style.add_button_text());
-            if r.clicked() {
-                if let Ok(key) = K::from_str(&probe.state.new_key) {
-                    match self.entry(key) {
-                        Entry::Occupied(_) => {
-                            probe.key_error();
-                        }
-                        Entry::Vacant(entry) => {
-                            entry.insert(V::default());
-                            probe.key_accepted();
-                        }
-                    }
-                } else {
-                    probe.key_error();
-                }
+        add_key_probe(self.len(), ui, style, |key| match self.entry(key) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(V::default());
+                true
             }
-
-            reduce_text_width += r.rect.width() + ui.spacing().item_spacing.x;
-
-            probe.new_key_edit(ui, reduce_text_width);
-            probe.store(ui.ctx());
         })
-        .response
     }
 
     fn has_inner(&mut self) -> bool {
@@ -97,7 +73,7 @@ where
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
         option_probe_with(self.value, ui, style, |value, ui, _style| {
-            ui.weak(format!("[{}]", value.len()));
+            ui.weak(format!("[{}]", value.len()))
         })
     }
 
@@ -0,0 +1,132 @@
+use std::collections::BinaryHeap;
+
+use crate::{
+    collections::{DeleteMe, EguiProbeFrozen},
+    option::option_probe_with,
+    EguiProbe,
+};
+
+/// Items of `BinaryHeap` can't be accessed mutably in place.
+/// Each item is shown through a copy, in ascending order.
+/// The heap is rebuilt only if some copy was changed or removed.
+fn iterate_heap<T>(
+    heap: &mut BinaryHeap<T>,
+    removable: bool,
+    f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+) where
+    T: EguiProbe + Clone + Ord,
+{
+    let mut order: Vec<(usize, &T)> = heap.iter().enumerate().collect();
+    order.sort_by(|a, b| a.1.cmp(b.1));
+
+    let mut edits = Vec::new();
+    for (idx, (pos, value)) in order.into_iter().enumerate() {
+        let mut copy = value.clone();
+        let label = format!("[{idx}]");
+        if removable {
+            let mut item = DeleteMe {
+                value: &mut copy,
+                delete: false,
+            };
+            f(&label, &mut item);
+            if item.delete {
+                edits.push((pos, None));
+                continue;
+            }
+        } else {
+            f(&label, &mut copy);
+        }
+        if copy != *value {
+            edits.push((pos, Some(copy)));
+        }
+    }
+
+    if edits.is_empty() {
+        return;
+    }
+
+    let mut vec = core::mem::take(heap).into_vec();
+    let mut removed = Vec::new();
+    for (pos, edit) in edits {
+        match edit {
+            Some(value) => vec[pos] = value,
+            None => removed.push(pos),
+        }
+    }
+    // Remove from the back so that pending positions stay valid.
+    removed.sort_unstable_by(|a, b| b.cmp(a));
+    for pos in removed {
+        vec.swap_remove(pos);
+    }
+    *heap = BinaryHeap::from(vec);
+}
+
+impl<T> EguiProbe for BinaryHeap<T>
+where
+    T: EguiProbe + Clone + Default + Ord,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        ui.horizontal(|ui| {
+            let r = ui.weak(format!("[{}]", self.len()));
+            if style.read_only {
+                return r;
+            }
+            let mut add = ui.small_button(style.add_button_text());
+            if add.clicked() {
+                self.push(T::default());
+                add.mark_changed();
+            }
+            r | add
+        })
+        .inner
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        iterate_heap(self, true, f);
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, BinaryHeap<T>>
+where
+    T: EguiProbe + Clone + Ord,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+        ui.weak(format!("[{}]", self.value.len()))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.value.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        iterate_heap(self.value, false, f);
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, Option<BinaryHeap<T>>>
+where
+    T: EguiProbe + Clone + Ord,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        option_probe_with(self.value, ui, style, |value, ui, _style| {
+            ui.weak(format!("[{}]", value.len()))
+        })
+    }
+
+    fn has_inner(&mut self) -> bool {
+        match self.value {
+            Some(value) => !value.is_empty(),
+            None => false,
+        }
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        if let Some(heap) = self.value {
+            iterate_heap(heap, false, f);
+        }
+    }
+}
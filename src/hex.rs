@@ -0,0 +1,281 @@
+use crate::{EguiProbe, Style};
+
+/// Maximum height of the scrolled rows of the hex editor.
+const MAX_ROWS_HEIGHT: f32 = 300.0;
+
+/// Modifier to edit bytes in a hex editor.
+///
+/// Shows bytes as a grid of hex digits with offsets of rows
+/// and printable characters aside.
+/// Click a byte and type hex digits to overwrite it,
+/// or paste a hex string to overwrite bytes starting from the clicked one.
+/// Bytes typed or pasted past the end are appended to a `Vec<u8>`.
+pub struct EguiProbeHex<'a, T> {
+    pub value: &'a mut T,
+}
+
+/// Shows bytes in a hex editor.
+///
+/// Add `#[egui_probe(hex)]` to the field to do the same with derive.
+#[inline(always)]
+pub fn hex<T>(value: &mut T) -> EguiProbeHex<'_, T>
+where
+    for<'a> EguiProbeHex<'a, T>: EguiProbe,
+{
+    EguiProbeHex { value }
+}
+
+/// Byte buffer edited with the hex editor.
+pub(crate) trait HexBytes {
+    fn bytes_mut(&mut self) -> &mut [u8];
+
+    /// Returns `true` if bytes can be appended to the buffer.
+    fn can_push(&self) -> bool {
+        false
+    }
+
+    /// Appends the byte to the buffer.
+    fn push_byte(&mut self, byte: u8) {
+        let _ = byte;
+    }
+}
+
+/// Position of the editing cursor.
+#[derive(Clone, Copy, Default)]
+struct HexCursor {
+    offset: usize,
+
+    /// Whether the next typed digit replaces the low half of the byte.
+    low: bool,
+}
+
+/// Returns label of a cell that moves the cursor when clicked.
+///
+/// Text of cells is not selectable, so that clicks reach the cell.
+fn cell_label(text: egui::RichText) -> egui::Label {
+    let sense = egui::Sense {
+        click: true,
+        drag: false,
+        focusable: false,
+    };
+    egui::Label::new(text).selectable(false).sense(sense)
+}
+
+/// Parses bytes from hex string, like `"de ad be ef"` or `"0xdeadbeef"`.
+///
+/// Returns `None` if the string is not a valid hex string.
+fn parse_hex(text: &str) -> Option<Vec<u8>> {
+    let text: String = text.split_whitespace().collect();
+    let text = text.strip_prefix("0x").unwrap_or(&text);
+
+    if !text.is_ascii() || text.len() % 2 != 0 {
+        return None;
+    }
+
+    (0..text.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(&text[idx..idx + 2], 16).ok())
+        .collect()
+}
+
+/// Writes the byte at the offset, appending it if the offset is at the end.
+///
+/// Returns `false` if the byte can not be written.
+fn write_byte(bytes: &mut dyn HexBytes, offset: usize, byte: u8) -> bool {
+    if let Some(b) = bytes.bytes_mut().get_mut(offset) {
+        *b = byte;
+        return true;
+    }
+
+    if offset == bytes.bytes_mut().len() && bytes.can_push() {
+        bytes.push_byte(byte);
+        return true;
+    }
+
+    false
+}
+
+/// Shows hex editor for the bytes.
+pub(crate) fn hex_probe(
+    bytes: &mut dyn HexBytes,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response {
+    let id = ui.make_persistent_id("probe_hex");
+    let mut cursor: HexCursor = ui.ctx().data_mut(|d| d.get_temp(id)).unwrap_or_default();
+
+    let per_row = style.bytes_per_row().max(1);
+    let can_push = bytes.can_push() && !style.read_only;
+
+    // Last offset the cursor can be at.
+    let end = |bytes: &mut dyn HexBytes| {
+        let len = bytes.bytes_mut().len();
+        if can_push {
+            len
+        } else {
+            len.saturating_sub(1)
+        }
+    };
+    // Cursor is past the end after the last byte of fixed size buffer is typed.
+    cursor.offset = cursor.offset.min(bytes.bytes_mut().len());
+
+    let has_focus = ui.memory(|m| m.has_focus(id));
+    let mut changed = false;
+
+    if has_focus {
+        ui.memory_mut(|m| {
+            m.set_focus_lock_filter(
+                id,
+                egui::EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            )
+        });
+
+        let events = ui.input(|i| i.events.clone());
+        for event in events {
+            match event {
+                egui::Event::Text(text) if !style.read_only => {
+                    for digit in text.chars().filter_map(|c| c.to_digit(16)) {
+                        let digit = digit as u8;
+                        let old = bytes.bytes_mut().get(cursor.offset).copied().unwrap_or(0);
+                        let byte = if cursor.low {
+                            old & 0xf0 | digit
+                        } else {
+                            digit << 4 | old & 0x0f
+                        };
+                        if !write_byte(bytes, cursor.offset, byte) {
+                            break;
+                        }
+                        changed = true;
+
+                        if cursor.low {
+                            cursor.offset += 1;
+                        }
+                        cursor.low = !cursor.low;
+                    }
+                }
+                egui::Event::Paste(text) if !style.read_only => {
+                    if let Some(pasted) = parse_hex(&text) {
+                        for byte in pasted {
+                            if !write_byte(bytes, cursor.offset, byte) {
+                                break;
+                            }
+                            changed = true;
+                            cursor.offset += 1;
+                        }
+                        cursor.low = false;
+                    }
+                }
+                egui::Event::Key {
+                    key, pressed: true, ..
+                } => {
+                    let offset = match key {
+                        egui::Key::ArrowLeft => cursor.offset.checked_sub(1),
+                        egui::Key::ArrowRight => Some(cursor.offset + 1),
+                        egui::Key::ArrowUp => cursor.offset.checked_sub(per_row),
+                        egui::Key::ArrowDown => Some(cursor.offset + per_row),
+                        _ => None,
+                    };
+                    if let Some(offset) = offset {
+                        cursor.offset = offset.min(end(bytes));
+                        cursor.low = false;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let last = end(bytes);
+    let bytes = bytes.bytes_mut();
+    let len = bytes.len();
+    let rows = (len + can_push as usize + per_row - 1) / per_row;
+
+    // Enough digits to show offset of any byte.
+    let offset_digits = ((usize::BITS - len.leading_zeros() + 3) / 4).max(4) as usize;
+
+    let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+
+    let mut response = ui
+        .vertical(|ui| {
+            egui::ScrollArea::vertical()
+                .id_source(id.with("rows"))
+                .max_height(MAX_ROWS_HEIGHT)
+                .auto_shrink([true, true])
+                .show_rows(ui, row_height, rows, |ui, range| {
+                    // Offset of the clicked byte, in either column.
+                    let mut clicked = None;
+
+                    for row in range {
+                        let start = row * per_row;
+                        ui.horizontal(|ui| {
+                            ui.add(
+                                egui::Label::new(
+                                    egui::RichText::new(format!("{start:0offset_digits$x}"))
+                                        .monospace()
+                                        .weak(),
+                                )
+                                .selectable(false),
+                            );
+
+                            let mut ascii = String::new();
+                            for offset in start..start + per_row {
+                                let mut text = match bytes.get(offset) {
+                                    Some(byte) => {
+                                        ascii.push(match *byte {
+                                            byte @ 0x20..=0x7e => byte as char,
+                                            _ => '.',
+                                        });
+                                        egui::RichText::new(format!("{byte:02x}"))
+                                    }
+                                    None if offset == len && can_push => {
+                                        egui::RichText::new("··").weak()
+                                    }
+                                    None => egui::RichText::new("  "),
+                                }
+                                .monospace();
+
+                                if has_focus && offset == cursor.offset {
+                                    text = text.background_color(ui.visuals().selection.bg_fill);
+                                }
+                                if ui.add(cell_label(text)).clicked() {
+                                    clicked = Some(offset);
+                                }
+                            }
+
+                            let r = ui.add(cell_label(egui::RichText::new(&ascii).monospace()));
+                            if let Some(pos) = r.hover_pos().filter(|_| r.clicked()) {
+                                let count = ascii.chars().count().max(1);
+                                let width = r.rect.width() / count as f32;
+                                let idx = ((pos.x - r.rect.min.x) / width) as usize;
+                                clicked = Some(start + idx.min(count - 1));
+                            }
+                        });
+                    }
+
+                    // Keeps the focus while the editor is shown,
+                    // cells alone sense clicks.
+                    let r = ui.interact(ui.min_rect(), id, egui::Sense::focusable_noninteractive());
+                    if let Some(offset) = clicked {
+                        cursor = HexCursor {
+                            offset: offset.min(last),
+                            low: false,
+                        };
+                        r.request_focus();
+                    }
+                    r
+                })
+                .inner
+        })
+        .inner;
+
+    ui.ctx().data_mut(|d| d.insert_temp(id, cursor));
+
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
@@ -0,0 +1,231 @@
+use std::collections::VecDeque;
+
+use crate::{EguiProbe, Probe, ProbeResponse};
+
+const UNDO_SHORTCUT: egui::KeyboardShortcut =
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+
+const REDO_SHORTCUTS: [egui::KeyboardShortcut; 2] = [
+    egui::KeyboardShortcut::new(
+        egui::Modifiers::COMMAND.plus(egui::Modifiers::SHIFT),
+        egui::Key::Z,
+    ),
+    egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Y),
+];
+
+/// Undo/redo history of a value edited via [`Probe`].
+///
+/// Each edit gesture is recorded as a single step.
+/// Dragging a value or typing into a text field is considered one gesture
+/// until pointer is released and text field loses focus.
+///
+/// UI state of the probe, like collapsed headers, is kept in `egui::Context`
+/// separately from the value and is not affected by undo and redo.
+pub struct ProbeHistory<T> {
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    max_len: usize,
+
+    // Value before current edit gesture.
+    snapshot: Option<T>,
+
+    // Whether edit gesture is in progress.
+    editing: bool,
+
+    // Widget focused when value was edited last time.
+    editing_focus: Option<egui::Id>,
+}
+
+impl<T> Default for ProbeHistory<T> {
+    #[inline]
+    fn default() -> Self {
+        ProbeHistory::new()
+    }
+}
+
+impl<T> ProbeHistory<T> {
+    /// Creates new empty history that keeps up to 100 steps.
+    pub fn new() -> Self {
+        ProbeHistory::with_max_len(100)
+    }
+
+    /// Creates new empty history that keeps up to `max_len` steps.
+    ///
+    /// History with `max_len` of zero records nothing.
+    pub fn with_max_len(max_len: usize) -> Self {
+        ProbeHistory {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            max_len,
+            snapshot: None,
+            editing: false,
+            editing_focus: None,
+        }
+    }
+
+    /// Returns `true` if there is a step to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty() || self.editing
+    }
+
+    /// Returns `true` if there is a step to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all recorded steps.
+    ///
+    /// Should be called when value is replaced without using the history.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.snapshot = None;
+        self.editing = false;
+        self.editing_focus = None;
+    }
+}
+
+impl<T> ProbeHistory<T>
+where
+    T: Clone,
+{
+    /// Reverts the value to the state before last edit.
+    ///
+    /// Returns `true` if value was changed.
+    pub fn undo(&mut self, value: &mut T) -> bool {
+        self.commit(value);
+
+        match self.undo.pop_back() {
+            None => false,
+            Some(prev) => {
+                self.redo.push(core::mem::replace(value, prev));
+                self.snapshot = Some(value.clone());
+                true
+            }
+        }
+    }
+
+    /// Re-applies last undone edit.
+    ///
+    /// Returns `true` if value was changed.
+    pub fn redo(&mut self, value: &mut T) -> bool {
+        self.commit(value);
+
+        match self.redo.pop() {
+            None => false,
+            Some(next) => {
+                let prev = core::mem::replace(value, next);
+                self.push_undo(prev);
+                self.snapshot = Some(value.clone());
+                true
+            }
+        }
+    }
+
+    /// Handles undo and redo keyboard shortcuts.
+    ///
+    /// Shortcuts are ignored while any widget has keyboard focus,
+    /// so that text fields can handle them on their own.
+    fn shortcuts(&mut self, value: &mut T, ui: &egui::Ui) -> bool {
+        if ui.memory(|m| m.focus().is_some()) {
+            return false;
+        }
+
+        let redo = ui.input_mut(|i| {
+            REDO_SHORTCUTS
+                .iter()
+                .any(|shortcut| i.consume_shortcut(shortcut))
+        });
+        if redo {
+            return self.redo(value);
+        }
+
+        let undo = ui.input_mut(|i| i.consume_shortcut(&UNDO_SHORTCUT));
+        if undo {
+            return self.undo(value);
+        }
+
+        false
+    }
+
+    /// Records state of the value after it was shown.
+    fn record(&mut self, value: &T, changed: bool, ui: &egui::Ui) {
+        if self.snapshot.is_none() {
+            // First time the value is shown.
+            // Nothing to compare with, so the value becomes initial state.
+            self.snapshot = Some(value.clone());
+            return;
+        }
+
+        let focused = ui.memory(|m| m.focus());
+
+        if changed {
+            self.editing = true;
+            self.editing_focus = focused;
+            return;
+        }
+
+        if self.editing {
+            let dragging = ui.input(|i| i.pointer.any_down());
+            if !dragging && (self.editing_focus.is_none() || focused != self.editing_focus) {
+                self.commit(value);
+            }
+        }
+    }
+
+    /// Finishes current edit gesture, recording it as single step.
+    fn commit(&mut self, value: &T) {
+        if !self.editing {
+            return;
+        }
+
+        self.editing = false;
+        self.editing_focus = None;
+
+        if let Some(prev) = self.snapshot.replace(value.clone()) {
+            self.push_undo(prev);
+        }
+        self.redo.clear();
+    }
+
+    /// Records step to undo, forgetting the oldest steps above the limit.
+    fn push_undo(&mut self, prev: T) {
+        self.undo.push_back(prev);
+        while self.undo.len() > self.max_len {
+            self.undo.pop_front();
+        }
+    }
+}
+
+impl<'a, T> Probe<'a, T>
+where
+    T: EguiProbe + Clone,
+{
+    /// Show probbing UI to edit the value
+    /// and record edits into the history.
+    ///
+    /// Handles `Ctrl+Z` to undo and `Ctrl+Shift+Z` or `Ctrl+Y` to redo.
+    pub fn show_with_history(
+        self,
+        history: &mut ProbeHistory<T>,
+        ui: &mut egui::Ui,
+    ) -> ProbeResponse {
+        let value = self.value;
+        let restored = history.shortcuts(value, ui);
+
+        let mut r = Probe {
+            value: &mut *value,
+            ..self
+        }
+        .show(ui);
+
+        history.record(value, r.changed(), ui);
+
+        if restored {
+            r.response.mark_changed();
+            r.changed_paths.insert(0, String::new());
+        }
+
+        r
+    }
+}
@@ -3,27 +3,163 @@
 //! This libraty provides a trait for value editing in egui.
 //! Powerful derive macros are provided for easy implementation on structs and enums.
 
+/// Implements listed `EguiProbe` methods of a wrapper
+/// by forwarding them to the wrapped value.
+///
+/// Wrappers implement methods they modify themselves and forward the rest, e.g.
+/// `forward_probe_methods!(|this| this.value => probe, has_inner, iterate_inner);`
+macro_rules! forward_probe_methods {
+    (|$this:ident| $value:expr => $($method:ident),+ $(,)?) => {
+        $(forward_probe_methods!(@$method |$this| $value);)+
+    };
+    (@probe |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn probe(&mut self, ui: &mut egui::Ui, style: &$crate::Style) -> egui::Response {
+            let $this = self;
+            $value.probe(ui, style)
+        }
+    };
+    (@has_inner |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn has_inner(&mut self) -> bool {
+            let $this = self;
+            $value.has_inner()
+        }
+    };
+    (@iterate_inner |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn $crate::EguiProbe)) {
+            let $this = self;
+            $value.iterate_inner(f)
+        }
+    };
+    (@iterate_inner_range |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn iterate_inner_range(
+            &mut self,
+            range: ::std::ops::Range<usize>,
+            f: &mut dyn FnMut(&str, &mut dyn $crate::EguiProbe),
+        ) -> usize {
+            let $this = self;
+            $value.iterate_inner_range(range, f)
+        }
+    };
+    (@tooltip |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn tooltip(&mut self) -> Option<&str> {
+            let $this = self;
+            $value.tooltip()
+        }
+    };
+    (@validate |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn validate(&mut self) -> Result<(), $crate::Validation> {
+            let $this = self;
+            $value.validate()
+        }
+    };
+    (@as_clipboard_value |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn as_clipboard_value(&mut self) -> Option<&mut dyn $crate::ClipboardValue> {
+            let $this = self;
+            $value.as_clipboard_value()
+        }
+    };
+    (@as_text_value |$this:ident| $value:expr) => {
+        #[cfg(feature = "serde")]
+        #[inline(always)]
+        fn as_text_value(&mut self) -> Option<&mut dyn $crate::TextValue> {
+            let $this = self;
+            $value.as_text_value()
+        }
+    };
+    (@as_default_value |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn as_default_value(&mut self) -> Option<&mut dyn $crate::DefaultValue> {
+            let $this = self;
+            $value.as_default_value()
+        }
+    };
+    (@as_multi_value |$this:ident| $value:expr) => {
+        #[inline(always)]
+        fn as_multi_value(&mut self) -> Option<&mut dyn $crate::MultiValue> {
+            let $this = self;
+            $value.as_multi_value()
+        }
+    };
+}
+
 mod algebra;
 mod array;
 mod boolean;
+mod clipboard;
 mod collections;
 mod color;
+mod datetime;
+mod default;
+mod deque;
+mod diff;
+mod duration;
+mod file_path;
+mod heap;
+mod hex;
+mod history;
+mod list;
 mod map;
+mod multi;
 mod num;
 mod option;
+mod path;
+mod read_only;
 mod set;
+mod table;
 mod text;
+mod tooltip;
 mod ui;
+mod validate;
 mod vec;
 mod widget;
+mod wrapper;
 #[cfg(any(feature = "smallvec1", feature = "smallvec2"))]
 mod small_vec;
 #[cfg(feature = "hashbrown")]
 mod hashbrown;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "serde")]
+mod serde_text;
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub use egui;
 
-pub use self::widget::{Probe, ProbeLayout};
+pub use self::{
+    algebra::{normalized, EguiProbeNormalized},
+    clipboard::{clipboard_value, ClipboardValue, EguiProbeClipboard},
+    collections::{clone_items, EguiProbeCloneItems},
+    default::{default_value, DefaultValue, EguiProbeDefault},
+    diff::{ProbeDiff, ProbeDiffResponse},
+    file_path::{file_path, EguiProbeFilePath, PathFilter},
+    hex::{hex, EguiProbeHex},
+    history::ProbeHistory,
+    multi::{multi_value, EguiProbeMultiValue, MultiValue},
+    path::{has_path, leaf_paths, probe_path_mut, visit_paths},
+    table::{table, EguiProbeTable},
+    validate::{validation_issues, Severity, Validation, ValidationIssue},
+    widget::{Probe, ProbeLayout, ProbeMulti, ProbeResponse},
+};
+
+#[cfg(feature = "serde")]
+pub use self::serde_text::{text_value, EguiProbeTextValue, TextFormat, TextValue};
 
 #[derive(Clone, Copy, Debug)]
 pub enum BooleanStyle {
@@ -59,6 +195,20 @@ pub struct Style {
     pub field_indent_size: Option<f32>,
     pub add_button_char: Option<char>,
     pub remove_button_char: Option<char>,
+    pub reset_button_char: Option<char>,
+
+    /// Number of bytes in a row of the hex editor.
+    pub hex_bytes_per_row: Option<usize>,
+
+    /// Number of inner values shown at once.
+    /// Values with more inner values show them in pages,
+    /// with a button to show the next page.
+    ///
+    /// `None` shows all inner values.
+    pub page_size: Option<usize>,
+
+    /// Shows values without allowing to edit them.
+    pub read_only: bool,
 }
 
 impl Default for Style {
@@ -70,6 +220,10 @@ impl Default for Style {
             field_indent_size: None,
             add_button_char: None,
             remove_button_char: None,
+            reset_button_char: None,
+            hex_bytes_per_row: None,
+            page_size: Some(100),
+            read_only: false,
         }
     }
 }
@@ -82,6 +236,14 @@ impl Style {
     pub fn remove_button_text(&self) -> String {
         self.remove_button_char.unwrap_or('-').to_string()
     }
+
+    pub fn reset_button_text(&self) -> String {
+        self.reset_button_char.unwrap_or('⟲').to_string()
+    }
+
+    pub fn bytes_per_row(&self) -> usize {
+        self.hex_bytes_per_row.unwrap_or(16)
+    }
 }
 
 /// Provides ability to show probbing UI to values.
@@ -104,19 +266,116 @@ pub trait EguiProbe {
     fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
         let _ = f;
     }
+
+    /// Calls `f` only for inner values with indices in the range,
+    /// with the same labels and in the same order as [`EguiProbe::iterate_inner`].
+    ///
+    /// Returns number of all inner values.
+    /// Collections override it to reach values of the range without visiting others,
+    /// so that only visible rows of large collections are shown.
+    #[inline(always)]
+    fn iterate_inner_range(
+        &mut self,
+        range: core::ops::Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        let mut idx = 0;
+        self.iterate_inner(&mut |label, value| {
+            if range.contains(&idx) {
+                f(label, value);
+            }
+            idx += 1;
+        });
+        idx
+    }
+
+    /// Returns text to show on hover over the label of the value.
+    #[inline(always)]
+    fn tooltip(&mut self) -> Option<&str> {
+        None
+    }
+
+    /// Validates the value.
+    ///
+    /// Returns issue of the value itself, inner values are validated separately.
+    /// Label of the value with issue is highlighted and the message is shown next to it.
+    #[inline(always)]
+    fn validate(&mut self) -> Result<(), Validation> {
+        Ok(())
+    }
+
+    /// Returns the value as [`ClipboardValue`] if it can be copied, pasted and reset.
+    ///
+    /// If it does, context menu of the label allows to copy the value,
+    /// paste previously copied value of the same type and reset it to default.
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        None
+    }
+
+    /// Returns the value as [`TextValue`] if it can be edited as text.
+    ///
+    /// If it does, context menu of the label allows to edit the value as RON or JSON text.
+    #[cfg(feature = "serde")]
+    #[inline(always)]
+    fn as_text_value(&mut self) -> Option<&mut dyn TextValue> {
+        None
+    }
+
+    /// Returns the value as [`DefaultValue`] if it has known default.
+    ///
+    /// If it does, label of the value is marked when it differs from the default
+    /// and button to reset it is shown.
+    #[inline(always)]
+    fn as_default_value(&mut self) -> Option<&mut dyn DefaultValue> {
+        None
+    }
+
+    /// Returns the value as [`MultiValue`] if it can be compared with and assigned
+    /// to values of other instances edited together with [`ProbeMulti`].
+    ///
+    /// Values that can't are compared and assigned through their inner values.
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        None
+    }
+}
+
+/// Probe that shows UI built by the closure.
+pub struct EguiProbeFn<F> {
+    pub f: F,
 }
 
-impl<F> EguiProbe for F
+impl<F> EguiProbe for EguiProbeFn<F>
 where
     F: FnMut(&mut egui::Ui, &Style) -> egui::Response,
 {
+    #[inline(always)]
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
-        (self)(ui, style)
+        (self.f)(ui, style)
     }
 }
 
+/// Shows UI built by the closure.
+///
+/// Closures do not implement [`EguiProbe`] themselves,
+/// as it would conflict with implementations for `Box` and other wrappers.
+#[inline(always)]
+pub fn probe_fn<F>(f: F) -> EguiProbeFn<F>
+where
+    F: FnMut(&mut egui::Ui, &Style) -> egui::Response,
+{
+    EguiProbeFn { f }
+}
+
 pub fn angle(value: &mut f32) -> impl EguiProbe + '_ {
-    move |ui: &mut egui::Ui, _style: &Style| ui.drag_angle(value)
+    probe_fn(move |ui: &mut egui::Ui, style: &Style| {
+        if style.read_only {
+            ui.label(format!("{:.1}°", value.to_degrees()))
+        } else {
+            ui.drag_angle(value)
+        }
+    })
 }
 
 #[cfg(feature = "derive")]
@@ -126,25 +385,49 @@ pub use egui_probe_proc::EguiProbe;
 #[doc(hidden)]
 pub mod private {
     use crate::{
-        boolean::toggle_switch,
+        boolean::EguiProbeToggleSwitch,
         collections::EguiProbeFrozen,
         color::{
             EguiProbeRgb, EguiProbeRgba, EguiProbeRgbaPremultiplied, EguiProbeRgbaUnmultiplied,
         },
         num::EguiProbeRange,
+        read_only::EguiProbeReadOnly,
         text::EguiProbeMultiline,
+        tooltip::EguiProbeTooltip,
+        validate::EguiProbeValidated,
     };
 
     use super::*;
 
     pub use core::stringify;
 
+    // Field wrappers are returned as named types with public `value` fields
+    // instead of `impl EguiProbe`.
+    // Validators and default comparisons generated by the derive run lazily
+    // and reach the field through these fields, e.g. `probe.value.string`.
+
+    /// Probe that shows UI built by the function of the value.
+    pub struct EguiProbeWith<'a, T, F> {
+        pub value: &'a mut T,
+        pub f: F,
+    }
+
+    impl<T, F> EguiProbe for EguiProbeWith<'_, T, F>
+    where
+        F: FnMut(&mut T, &mut egui::Ui, &Style) -> egui::Response,
+    {
+        #[inline(always)]
+        fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+            (self.f)(self.value, ui, style)
+        }
+    }
+
     #[inline(always)]
-    pub fn probe_with<'a, T, F>(mut f: F, value: &'a mut T) -> impl EguiProbe + 'a
+    pub fn probe_with<'a, T, F>(f: F, value: &'a mut T) -> EguiProbeWith<'a, T, F>
     where
         F: FnMut(&mut T, &mut egui::Ui, &Style) -> egui::Response + 'a,
     {
-        move |ui: &mut egui::Ui, style: &Style| f(value, ui, style)
+        EguiProbeWith { value, f }
     }
 
     #[inline(always)]
@@ -173,12 +456,47 @@ pub mod private {
     }
 
     #[inline(always)]
-    pub fn probe_toggle_switch<'a>(value: &'a mut bool) -> impl EguiProbe + 'a {
-        move |ui: &mut egui::Ui, _: &Style| toggle_switch(value, ui)
+    pub fn probe_toggle_switch<'a>(value: &'a mut bool) -> EguiProbeToggleSwitch<'a> {
+        EguiProbeToggleSwitch { value }
+    }
+
+    #[inline(always)]
+    pub fn probe_normalized<'a, T>(value: &'a mut T) -> EguiProbeNormalized<'a, T>
+    where
+        EguiProbeNormalized<'a, T>: EguiProbe,
+    {
+        EguiProbeNormalized { value }
     }
 
     #[inline(always)]
-    pub fn probe_frozen<'a, T>(value: &'a mut T) -> impl EguiProbe + 'a
+    pub fn probe_hex<'a, T>(value: &'a mut T) -> EguiProbeHex<'a, T>
+    where
+        EguiProbeHex<'a, T>: EguiProbe,
+    {
+        EguiProbeHex { value }
+    }
+
+    #[inline(always)]
+    pub fn probe_file_path<'a, T>(
+        filter: PathFilter,
+        value: &'a mut T,
+    ) -> EguiProbeFilePath<'a, T>
+    where
+        EguiProbeFilePath<'a, T>: EguiProbe,
+    {
+        EguiProbeFilePath { value, filter }
+    }
+
+    #[inline(always)]
+    pub fn probe_table<'a, T>(value: &'a mut T) -> EguiProbeTable<'a, T>
+    where
+        EguiProbeTable<'a, T>: EguiProbe,
+    {
+        EguiProbeTable { value }
+    }
+
+    #[inline(always)]
+    pub fn probe_frozen<'a, T>(value: &'a mut T) -> EguiProbeFrozen<'a, T>
     where
         EguiProbeFrozen<'a, T>: EguiProbe,
     {
@@ -186,7 +504,93 @@ pub mod private {
     }
 
     #[inline(always)]
-    pub fn probe_rgb<'a, T>(value: &'a mut T) -> impl EguiProbe + 'a
+    pub fn probe_read_only<'a, T>(value: &'a mut T) -> EguiProbeReadOnly<'a, T>
+    where
+        T: EguiProbe + ?Sized,
+    {
+        EguiProbeReadOnly { value }
+    }
+
+    #[inline(always)]
+    pub fn read_only_style(style: &Style) -> Style {
+        Style {
+            read_only: true,
+            ..*style
+        }
+    }
+
+    #[inline(always)]
+    pub fn probe_tooltip<'a, T>(tooltip: &'a str, value: &'a mut T) -> EguiProbeTooltip<'a, T>
+    where
+        T: EguiProbe + ?Sized,
+    {
+        EguiProbeTooltip { value, tooltip }
+    }
+
+    #[inline(always)]
+    pub fn probe_validated<'a, T, F, E>(
+        validator: F,
+        value: &'a mut T,
+    ) -> EguiProbeValidated<'a, T, impl Fn(&T) -> Result<(), Validation>>
+    where
+        T: EguiProbe + ?Sized,
+        F: Fn(&T) -> Result<(), E>,
+        E: Into<Validation>,
+    {
+        EguiProbeValidated {
+            value,
+            validator: move |value: &T| validator(value).map_err(Into::into),
+        }
+    }
+
+    #[inline(always)]
+    pub fn probe_clipboard<'a, T>(value: &'a mut T) -> crate::EguiProbeClipboard<'a, T>
+    where
+        T: EguiProbe + crate::ClipboardValue,
+    {
+        crate::EguiProbeClipboard { value }
+    }
+
+    #[inline(always)]
+    pub fn probe_multi<'a, T>(value: &'a mut T) -> crate::EguiProbeMultiValue<'a, T>
+    where
+        T: EguiProbe + crate::MultiValue,
+    {
+        crate::EguiProbeMultiValue { value }
+    }
+
+    #[cfg(feature = "serde")]
+    #[inline(always)]
+    pub fn probe_text_value<'a, T>(value: &'a mut T) -> crate::EguiProbeTextValue<'a, T>
+    where
+        T: EguiProbe + crate::TextValue,
+    {
+        crate::EguiProbeTextValue { value }
+    }
+
+    /// Marks the value when it differs from the default.
+    ///
+    /// Reset is requested through the flag,
+    /// so that the caller could apply it after `value` is released.
+    #[inline(always)]
+    pub fn probe_reset<'a, P, D>(
+        is_default: D,
+        reset: &'a mut bool,
+        value: &'a mut P,
+    ) -> crate::default::EguiProbeReset<'a, P, D>
+    where
+        P: EguiProbe + ?Sized,
+        D: Fn(&P) -> bool,
+    {
+        crate::default::EguiProbeReset {
+            value,
+            is_default,
+            reset,
+        }
+    }
+
+    #[inline(always)]
+    pub fn probe_rgb<'a, T>(value: &'a mut T) -> EguiProbeRgb<'a, T>
     where
         EguiProbeRgb<'a, T>: EguiProbe,
     {
@@ -194,7 +598,7 @@ pub mod private {
     }
 
     #[inline(always)]
-    pub fn probe_rgba<'a, T>(value: &'a mut T) -> impl EguiProbe + 'a
+    pub fn probe_rgba<'a, T>(value: &'a mut T) -> EguiProbeRgba<'a, T>
     where
         EguiProbeRgba<'a, T>: EguiProbe,
     {
@@ -202,7 +606,7 @@ pub mod private {
     }
 
     #[inline(always)]
-    pub fn probe_rgba_premultiplied<'a, T>(value: &'a mut T) -> impl EguiProbe + 'a
+    pub fn probe_rgba_premultiplied<'a, T>(value: &'a mut T) -> EguiProbeRgbaPremultiplied<'a, T>
     where
         EguiProbeRgbaPremultiplied<'a, T>: EguiProbe,
     {
@@ -210,7 +614,7 @@ pub mod private {
     }
 
     #[inline(always)]
-    pub fn probe_rgba_unmultiplied<'a, T>(value: &'a mut T) -> impl EguiProbe + 'a
+    pub fn probe_rgba_unmultiplied<'a, T>(value: &'a mut T) -> EguiProbeRgbaUnmultiplied<'a, T>
     where
         EguiProbeRgbaUnmultiplied<'a, T>: EguiProbe,
     {
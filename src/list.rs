@@ -0,0 +1,101 @@
+use std::collections::LinkedList;
+
+use crate::{
+    collections::{DeleteMe, EguiProbeFrozen},
+    option::option_probe_with,
+    EguiProbe,
+};
+
+impl<T> EguiProbe for LinkedList<T>
+where
+    T: EguiProbe + Default,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        ui.horizontal(|ui| {
+            let r = ui.weak(format!("[{}]", self.len()));
+            if style.read_only {
+                return r;
+            }
+            let mut add = ui.small_button(style.add_button_text());
+            if add.clicked() {
+                self.push_back(T::default());
+                add.mark_changed();
+            }
+            r | add
+        })
+        .inner
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        // `LinkedList` has no `retain`, so deleted items are collected and removed afterwards.
+        let mut deleted = Vec::new();
+        for (idx, value) in self.iter_mut().enumerate() {
+            let mut item = DeleteMe {
+                value,
+                delete: false,
+            };
+            f(&format!("[{idx}]"), &mut item);
+            if item.delete {
+                deleted.push(idx);
+            }
+        }
+
+        if !deleted.is_empty() {
+            *self = core::mem::take(self)
+                .into_iter()
+                .enumerate()
+                .filter(|(idx, _)| !deleted.contains(idx))
+                .map(|(_, value)| value)
+                .collect();
+        }
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, LinkedList<T>>
+where
+    T: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
+        ui.weak(format!("[{}]", self.value.len()))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.value.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        for (i, value) in self.value.iter_mut().enumerate() {
+            f(&format!("[{i}]"), value);
+        }
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, Option<LinkedList<T>>>
+where
+    T: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        option_probe_with(self.value, ui, style, |value, ui, _style| {
+            ui.weak(format!("[{}]", value.len()))
+        })
+    }
+
+    fn has_inner(&mut self) -> bool {
+        match self.value {
+            Some(value) => !value.is_empty(),
+            None => false,
+        }
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        if let Some(list) = self.value {
+            for (i, value) in list.iter_mut().enumerate() {
+                f(&format!("[{i}]"), value);
+            }
+        }
+    }
+}
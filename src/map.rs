@@ -1,5 +1,5 @@
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::{btree_map, hash_map::Entry, BTreeMap, HashMap},
     fmt::Display,
     str::FromStr,
 };
@@ -83,6 +83,51 @@ impl HashMapProbe {
     }
 }
 
+/// Shows number of entries, add button and text field for a new key.
+///
+/// When add button is clicked and the key is parsed, `insert` is called with it.
+/// It should return `false` if the key is already present.
+pub(crate) fn add_key_probe<K>(
+    len: usize,
+    ui: &mut egui::Ui,
+    style: &Style,
+    insert: impl FnOnce(K) -> bool,
+) -> egui::Response
+where
+    K: FromStr,
+{
+    if style.read_only {
+        return ui.weak(format!("[{len}]"));
+    }
+
+    ui.horizontal(|ui| {
+        let mut probe = HashMapProbe::load(ui.ctx(), ui.make_persistent_id("HashMapProbe"));
+
+        let mut reduce_text_width = 0.0;
+
+        let r = ui.weak(format!("[{len}]"));
+        reduce_text_width += r.rect.width() + ui.spacing().item_spacing.x;
+
+        let mut r = ui.small_button(style.add_button_text());
+        if r.clicked() {
+            match K::from_str(&probe.state.new_key).map(insert) {
+                Ok(true) => {
+                    probe.key_accepted();
+                    r.mark_changed();
+                }
+                _ => probe.key_error(),
+            }
+        }
+
+        reduce_text_width += r.rect.width() + ui.spacing().item_spacing.x;
+
+        probe.new_key_edit(ui, reduce_text_width);
+        probe.store(ui.ctx());
+        r
+    })
+    .inner
+}
+
 impl<K, V, S> EguiProbe for HashMap<K, V, S>
 where
     K: Display + FromStr + Eq + std::hash::Hash,
@@ -90,37 +135,13 @@ where
     S: std::hash::BuildHasher,
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
-        ui.horizontal(|ui| {
-            let mut probe = HashMapProbe::load(ui.ctx(), ui.make_persistent_id("HashMapProbe"));
-
-            let mut reduce_text_width = 0.0;
-
-            let r = ui.weak(format!("[{}]", self.len()));
-            reduce_text_width += r.rect.width() + ui.spacing().item_spacing.x;
-
-            let r = ui.small_button(style.add_button_text());
-            if r.clicked() {
-                if let Ok(key) = K::from_str(&probe.state.new_key) {
-                    match self.entry(key) {
-                        Entry::Occupied(_) => {
-                            probe.key_error();
-                        }
-                        Entry::Vacant(entry) => {
-                            entry.insert(V::default());
-                            probe.key_accepted();
-                        }
-                    }
-                } else {
-                    probe.key_error();
-                }
+        add_key_probe(self.len(), ui, style, |key| match self.entry(key) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(V::default());
+                true
             }
-
-            reduce_text_width += r.rect.width() + ui.spacing().item_spacing.x;
-
-            probe.new_key_edit(ui, reduce_text_width);
-            probe.store(ui.ctx());
         })
-        .response
     }
 
     fn has_inner(&mut self) -> bool {
@@ -168,7 +189,85 @@ where
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
         option_probe_with(self.value, ui, style, |value, ui, _style| {
-            ui.weak(format!("[{}]", value.len()));
+            ui.weak(format!("[{}]", value.len()))
+        })
+    }
+
+    fn has_inner(&mut self) -> bool {
+        match self.value {
+            Some(value) => !value.is_empty(),
+            None => false,
+        }
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        if let Some(map) = self.value {
+            for (key, value) in map.iter_mut() {
+                f(&key.to_string(), value);
+            }
+        }
+    }
+}
+
+impl<K, V> EguiProbe for BTreeMap<K, V>
+where
+    K: Display + FromStr + Ord,
+    V: EguiProbe + Default,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        add_key_probe(self.len(), ui, style, |key| match self.entry(key) {
+            btree_map::Entry::Occupied(_) => false,
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(V::default());
+                true
+            }
+        })
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        self.retain(|key, value| {
+            let mut item = DeleteMe {
+                value,
+                delete: false,
+            };
+            f(&key.to_string(), &mut item);
+            !item.delete
+        });
+    }
+}
+
+impl<K, V> EguiProbe for EguiProbeFrozen<'_, BTreeMap<K, V>>
+where
+    K: Display + Ord,
+    V: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak(format!("[{}]", self.value.len()))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.value.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        for (key, value) in self.value.iter_mut() {
+            f(&key.to_string(), value);
+        }
+    }
+}
+
+impl<K, V> EguiProbe for EguiProbeFrozen<'_, Option<BTreeMap<K, V>>>
+where
+    K: Display + Ord,
+    V: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        option_probe_with(self.value, ui, style, |value, ui, _style| {
+            ui.weak(format!("[{}]", value.len()))
         })
     }
 
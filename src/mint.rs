@@ -0,0 +1,96 @@
+use core::fmt::Display;
+
+use egui::emath::Numeric;
+
+use crate::{
+    algebra::{components_probe, matrix_probe, quaternion_probe},
+    EguiProbe, MultiValue, Style,
+};
+
+macro_rules! impl_for_vectors {
+    ($($vec:ident: $n:literal),* $(,)?) => {
+        $(
+            impl<T> EguiProbe for mint::$vec<T>
+            where
+                T: Numeric + Display,
+            {
+                fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                    let components: &mut [T; $n] = self.as_mut();
+                    ui.horizontal(|ui| components_probe(components, None, ui, style))
+                        .inner
+                }
+
+                #[inline(always)]
+                fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_vectors!(
+    Vector2: 2,
+    Vector3: 3,
+    Vector4: 4,
+    Point2: 2,
+    Point3: 3,
+);
+
+macro_rules! impl_for_matrices {
+    ($($mat:ident: $rows:literal x $columns:literal, $row_major:literal),* $(,)?) => {
+        $(
+            impl<T> EguiProbe for mint::$mat<T>
+            where
+                T: Numeric + Display,
+            {
+                fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                    let elements: &mut [T; $rows * $columns] = self.as_mut();
+                    matrix_probe(elements, $rows, $row_major, ui, style)
+                }
+
+                #[inline(always)]
+                fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_matrices!(
+    ColumnMatrix2: 2 x 2, false,
+    ColumnMatrix2x3: 2 x 3, false,
+    ColumnMatrix2x4: 2 x 4, false,
+    ColumnMatrix3x2: 3 x 2, false,
+    ColumnMatrix3: 3 x 3, false,
+    ColumnMatrix3x4: 3 x 4, false,
+    ColumnMatrix4x2: 4 x 2, false,
+    ColumnMatrix4x3: 4 x 3, false,
+    ColumnMatrix4: 4 x 4, false,
+    RowMatrix2: 2 x 2, true,
+    RowMatrix2x3: 2 x 3, true,
+    RowMatrix2x4: 2 x 4, true,
+    RowMatrix3x2: 3 x 2, true,
+    RowMatrix3: 3 x 3, true,
+    RowMatrix3x4: 3 x 4, true,
+    RowMatrix4x2: 4 x 2, true,
+    RowMatrix4x3: 4 x 3, true,
+    RowMatrix4: 4 x 4, true,
+);
+
+impl<T> EguiProbe for mint::Quaternion<T>
+where
+    T: Numeric + Display,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        // Vector part comes first, so components are in `x, y, z, w` order.
+        let components: &mut [T; 4] = self.as_mut();
+        quaternion_probe(components, false, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+};
+
+use crate::{
+    path::{join_path, probe_path_mut},
+    EguiProbe, Style,
+};
+
+/// Value that can be edited together with other values of the same type.
+///
+/// Implemented for all types that implement `Clone` and `PartialEq`.
+pub trait MultiValue {
+    /// Returns the value as `Any` to compare with or assign to other values.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns `true` if other value has the same type and is equal.
+    fn eq_value(&self, other: &dyn Any) -> bool;
+
+    /// Replaces the value with the other one.
+    ///
+    /// Returns `false` if other value has different type.
+    fn assign_value(&mut self, other: &dyn Any) -> bool;
+}
+
+impl<T> MultiValue for T
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_value(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<T>() == Some(self)
+    }
+
+    fn assign_value(&mut self, other: &dyn Any) -> bool {
+        match other.downcast_ref::<T>() {
+            Some(other) => {
+                self.clone_from(other);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Values that differ between instances edited together.
+pub(crate) struct Mixed {
+    /// Paths of differing values.
+    paths: HashSet<String>,
+
+    /// Paths of differing values revealed for editing.
+    revealed: HashSet<String>,
+    id: egui::Id,
+
+    /// Generation of instances the paths are collected for.
+    /// `None` if paths are collected every frame.
+    generation: Option<egui::Id>,
+}
+
+#[derive(Clone, Default)]
+struct MixedState {
+    paths: HashSet<String>,
+    revealed: HashSet<String>,
+    generation: Option<egui::Id>,
+}
+
+impl Mixed {
+    /// Loads paths collected in previous frames for the same generation of instances,
+    /// or collects them anew.
+    pub(crate) fn load(
+        cx: &egui::Context,
+        id: egui::Id,
+        generation: Option<egui::Id>,
+        collect: impl FnOnce() -> HashSet<String>,
+    ) -> Mixed {
+        let state: MixedState = cx.data_mut(|d| d.remove_temp(id)).unwrap_or_default();
+
+        let paths = match generation {
+            Some(generation) if state.generation == Some(generation) => state.paths,
+            _ => collect(),
+        };
+
+        let mut revealed = state.revealed;
+        revealed.retain(|path| paths.contains(path));
+        Mixed {
+            paths,
+            revealed,
+            id,
+            generation,
+        }
+    }
+
+    /// Replaces paths of differing values after instances were changed.
+    pub(crate) fn update(&mut self, paths: HashSet<String>) {
+        self.revealed.retain(|path| paths.contains(path));
+        self.paths = paths;
+    }
+
+    pub(crate) fn store(self, cx: &egui::Context) {
+        let state = MixedState {
+            paths: self.paths,
+            revealed: self.revealed,
+            generation: self.generation,
+        };
+        cx.data_mut(|d| d.insert_temp(self.id, state));
+    }
+
+    /// Returns `true` if value with this path differs and is not revealed.
+    fn is_hidden(&self, path: &str) -> bool {
+        self.paths.contains(path) && !self.revealed.contains(path)
+    }
+}
+
+/// Collects paths of values that differ between the first instance and others.
+pub(crate) fn mixed_paths<T>(first: &mut T, rest: &mut [&mut T]) -> HashSet<String>
+where
+    T: EguiProbe,
+{
+    let mut paths = HashSet::new();
+    for other in rest.iter_mut() {
+        collect_mixed(first, &mut **other, "", &mut paths);
+    }
+    paths
+}
+
+/// Collects paths of values that differ between two instances,
+/// walking both instances together.
+///
+/// Values that can not be compared are never mixed.
+fn collect_mixed(
+    value: &mut dyn EguiProbe,
+    other: &mut dyn EguiProbe,
+    path: &str,
+    paths: &mut HashSet<String>,
+) {
+    if let Some(multi_value) = value.as_multi_value() {
+        let differs = match other.as_multi_value() {
+            None => true,
+            Some(other) => !multi_value.eq_value(other.as_any()),
+        };
+        if !differs {
+            // Inner values of equal values are equal too.
+            return;
+        }
+        paths.insert(path.to_owned());
+    }
+
+    pair_inner(value, other, |label, value, other| {
+        let path = join_path(path, label);
+        match other {
+            Some(other) => collect_mixed(value, other, &path, paths),
+            None => collect_missing(value, &path, paths),
+        }
+    });
+}
+
+/// Calls `f` for each inner value paired with inner value of the other value
+/// with the same label, or with `None` if the other value has no such inner value.
+///
+/// Returns `true` if all inner values of the other value are paired.
+pub(crate) fn pair_inner<F>(value: &mut dyn EguiProbe, other: &mut dyn EguiProbe, mut f: F) -> bool
+where
+    F: FnMut(&str, &mut dyn EguiProbe, Option<&mut dyn EguiProbe>),
+{
+    let other_len = other.iterate_inner_range(0..0, &mut |_, _| {});
+
+    // Indices of other inner values by label, collected on first mismatch.
+    let mut indices: Option<HashMap<String, usize>> = None;
+
+    let mut idx = 0;
+    let mut paired = 0;
+    value.iterate_inner(&mut |label, value| {
+        // Inner value with the same label is usually at the same index.
+        let mut found = false;
+        other.iterate_inner_range(idx..idx + 1, &mut |other_label, other| {
+            if other_label == label {
+                found = true;
+                f(label, &mut *value, Some(other));
+            }
+        });
+
+        if !found {
+            let indices = indices.get_or_insert_with(|| {
+                let mut indices = HashMap::new();
+                let mut idx = 0;
+                other.iterate_inner(&mut |label, _| {
+                    indices.entry(label.to_owned()).or_insert(idx);
+                    idx += 1;
+                });
+                indices
+            });
+            if let Some(&other_idx) = indices.get(label) {
+                other.iterate_inner_range(other_idx..other_idx + 1, &mut |_, other| {
+                    found = true;
+                    f(label, &mut *value, Some(other));
+                });
+            }
+        }
+
+        if found {
+            paired += 1;
+        } else {
+            f(label, value, None);
+        }
+        idx += 1;
+    });
+
+    paired == other_len
+}
+
+/// Collects paths of values that can be compared
+/// when other instance has no value at the path.
+fn collect_missing(value: &mut dyn EguiProbe, path: &str, paths: &mut HashSet<String>) {
+    if value.as_multi_value().is_some() {
+        paths.insert(path.to_owned());
+    }
+
+    value.iterate_inner(&mut |label, value| {
+        collect_missing(value, &join_path(path, label), paths);
+    });
+}
+
+/// Writes one value to another value of the same type.
+///
+/// Values that can not be assigned are written through their inner values
+/// with the same labels.
+/// Returns `true` if anything was written.
+pub(crate) fn assign_probe(from: &mut dyn EguiProbe, to: &mut dyn EguiProbe) -> bool {
+    if let Some(multi_value) = from.as_multi_value() {
+        return match to.as_multi_value() {
+            Some(other) => other.assign_value(multi_value.as_any()),
+            None => false,
+        };
+    }
+
+    let mut assigned = false;
+    from.iterate_inner(&mut |label, from| {
+        let mut found = false;
+        to.iterate_inner(&mut |other, to| {
+            if !found && other == label {
+                found = true;
+                assigned |= assign_probe(from, to);
+            }
+        });
+    });
+    assigned
+}
+
+/// Writes value at the path of the first instance to all other instances.
+///
+/// Values that can not be assigned are written through their inner values.
+/// Instances that have no value at the path are left unchanged.
+pub(crate) fn propagate<T>(first: &mut T, rest: &mut [&mut T], path: &str)
+where
+    T: EguiProbe,
+{
+    probe_path_mut(first, path, |value| {
+        for other in rest.iter_mut() {
+            probe_path_mut(&mut **other, path, |other| assign_probe(value, other));
+        }
+    });
+}
+
+/// Shows probing UI of the value,
+/// or "—" if it differs between instances and is not revealed yet.
+///
+/// Clicking "—" reveals the value of the first instance.
+pub(crate) fn mixed_probe(
+    value: &mut dyn EguiProbe,
+    ui: &mut egui::Ui,
+    style: &Style,
+    mixed: Option<&mut Mixed>,
+    path: &str,
+) -> egui::Response {
+    match mixed {
+        Some(mixed) if mixed.is_hidden(path) => {
+            let r = ui
+                .add(egui::Button::new("—").small())
+                .on_hover_text("Values differ, click to edit");
+            if r.clicked() {
+                mixed.revealed.insert(path.to_owned());
+            }
+            r
+        }
+        _ => value.probe(ui, style),
+    }
+}
+
+/// Modifier to allow editing the value together with values of other instances.
+pub struct EguiProbeMultiValue<'a, T> {
+    pub value: &'a mut T,
+}
+
+impl<T> EguiProbe for EguiProbeMultiValue<'_, T>
+where
+    T: EguiProbe + MultiValue,
+{
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(&mut *self.value)
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        probe,
+        has_inner,
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        validate,
+        as_clipboard_value,
+        as_text_value,
+        as_default_value,
+    );
+}
+
+/// Allows editing the value together with values of other instances
+/// in [`crate::ProbeMulti`].
+///
+/// Value must implement `Clone` and `PartialEq`.
+#[inline(always)]
+pub fn multi_value<T>(value: &mut T) -> EguiProbeMultiValue<'_, T>
+where
+    T: EguiProbe + MultiValue,
+{
+    EguiProbeMultiValue { value }
+}
@@ -0,0 +1,189 @@
+use nalgebra::{Isometry2, Isometry3, Point, Quaternion, SMatrix, UnitComplex, UnitQuaternion};
+
+use crate::{
+    algebra::{components_probe, matrix_probe, quaternion_probe},
+    angle, ClipboardValue, EguiProbe, MultiValue, Style,
+};
+
+macro_rules! impl_for_scalars {
+    ($scalar:ident) => {
+        impl<const R: usize, const C: usize> EguiProbe for SMatrix<$scalar, R, C>
+        where
+            Self: Default,
+        {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                // Column vectors are shown in a row, like vectors of other libraries.
+                if C == 1 {
+                    ui.horizontal(|ui| components_probe(self.as_mut_slice(), None, ui, style))
+                        .inner
+                } else {
+                    matrix_probe(self.as_mut_slice(), R, false, ui, style)
+                }
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+
+        impl<const D: usize> EguiProbe for Point<$scalar, D> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                ui.horizontal(|ui| components_probe(self.coords.as_mut_slice(), None, ui, style))
+                    .inner
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+    };
+
+    ($($scalar:ident),*) => {
+        $(impl_for_scalars!($scalar);)*
+    };
+}
+
+impl_for_scalars!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+macro_rules! impl_for_real_scalars {
+    ($scalar:ident) => {
+        impl EguiProbe for Quaternion<$scalar> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                // Coordinates are stored in `x, y, z, w` order.
+                let mut components = self.coords.into();
+                let r = quaternion_probe(&mut components, false, ui, style);
+                if r.changed() {
+                    self.coords = components.into();
+                }
+                r
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+
+        /// Raw components of unit quaternion are normalized once editing is finished.
+        impl EguiProbe for UnitQuaternion<$scalar> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                let mut components = self.coords.into();
+                let r = quaternion_probe(&mut components, true, ui, style);
+                if r.changed() {
+                    self.as_mut_unchecked().coords = components.into();
+                }
+                r
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+
+        impl EguiProbe for UnitComplex<$scalar> {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                let mut rotation = self.angle() as f32;
+                let r = angle(&mut rotation).probe(ui, style);
+                if r.changed() {
+                    *self = UnitComplex::new(rotation as $scalar);
+                }
+                r
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+
+        impl EguiProbe for Isometry2<$scalar> {
+            #[inline(always)]
+            fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+                ui.weak("Isometry2")
+            }
+
+            #[inline(always)]
+            fn has_inner(&mut self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+                f("translation", &mut self.translation.vector);
+                f("rotation", &mut self.rotation);
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+
+        impl EguiProbe for Isometry3<$scalar> {
+            #[inline(always)]
+            fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+                ui.weak("Isometry3")
+            }
+
+            #[inline(always)]
+            fn has_inner(&mut self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+                f("translation", &mut self.translation.vector);
+                f("rotation", &mut self.rotation);
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
+            }
+        }
+    };
+
+    ($($scalar:ident),*) => {
+        $(impl_for_real_scalars!($scalar);)*
+    };
+}
+
+impl_for_real_scalars!(f32, f64);
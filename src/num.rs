@@ -1,6 +1,9 @@
-use core::ops::{RangeFrom, RangeFull, RangeInclusive, RangeToInclusive};
+use core::{
+    fmt::Display,
+    ops::{RangeFrom, RangeFull, RangeInclusive, RangeToInclusive},
+};
 
-use crate::{option::option_probe_with, EguiProbe, Style};
+use crate::{option::option_probe_with, ClipboardValue, EguiProbe, MultiValue, Style};
 
 /// Bundles value and a range to show probbing UI to edit the value in that range.
 pub struct EguiProbeRange<'a, T, R> {
@@ -36,53 +39,107 @@ pub fn non_negative<'a>(value: &'a mut f32) -> EguiProbeRange<'a, f32, RangeFrom
 //     EguiProbeRange { value, range }
 // }
 
+/// Shows `DragValue` to edit the number or a label in read-only mode.
+pub(crate) fn drag_value<T>(
+    value: &mut T,
+    range: Option<RangeInclusive<T>>,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response
+where
+    T: egui::emath::Numeric + Display,
+{
+    if style.read_only {
+        return ui.label(value.to_string());
+    }
+
+    let mut drag_value = egui::DragValue::new(value);
+    if let Some(range) = range {
+        drag_value = drag_value.clamp_range(range);
+    }
+    ui.add(drag_value)
+}
+
 macro_rules! impl_for_num_types {
     ($num_type:ident) => {
         impl EguiProbe for $num_type {
             #[inline(always)]
-            fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
-                ui.add(egui::DragValue::new(self))
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                drag_value(self, None, ui, style)
+            }
+
+            #[inline(always)]
+            fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+                Some(self)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self)
             }
         }
 
         impl EguiProbe for EguiProbeRange<'_, $num_type, RangeFull> {
             #[inline(always)]
-            fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=$num_type::MAX;
-                ui.add(egui::DragValue::new(self.value).clamp_range(range))
+                drag_value(self.value, Some(range), ui, style)
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
             }
         }
 
         impl EguiProbe for EguiProbeRange<'_, $num_type, RangeFrom<$num_type>> {
             #[inline(always)]
-            fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.start..=$num_type::MAX;
                 ui.horizontal(|ui| {
-                    ui.add(egui::DragValue::new(self.value).clamp_range(range));
+                    let r = drag_value(self.value, Some(range), ui, style);
                     ui.weak(format!("{}..", self.range.start));
-                }).response
+                    r
+                }).inner
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
             }
         }
 
         impl EguiProbe for EguiProbeRange<'_, $num_type, RangeToInclusive<$num_type>> {
             #[inline(always)]
-            fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=self.range.end;
                 ui.horizontal(|ui| {
-                    ui.add(egui::DragValue::new(self.value).clamp_range(range));
+                    let r = drag_value(self.value, Some(range), ui, style);
                     ui.weak(format!("..={}", self.range.end));
-                }).response
+                    r
+                }).inner
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
             }
         }
 
         impl EguiProbe for EguiProbeRange<'_, $num_type, RangeInclusive<$num_type>> {
             #[inline(always)]
-            fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.clone();
                 ui.horizontal(|ui| {
-                    ui.add(egui::DragValue::new(self.value).clamp_range(range));
+                    let r = drag_value(self.value, Some(range), ui, style);
                     ui.weak(format!("{}..={}", self.range.start(), self.range.end()));
-                }).response
+                    r
+                }).inner
+            }
+
+            #[inline(always)]
+            fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                Some(self.value)
             }
         }
 
@@ -90,8 +147,8 @@ macro_rules! impl_for_num_types {
             #[inline(always)]
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=$num_type::MAX;
-                option_probe_with(self.value, ui, style, |value, ui, _| {
-                    ui.add(egui::DragValue::new(value).clamp_range(range));
+                option_probe_with(self.value, ui, style, |value, ui, style| {
+                    drag_value(value, Some(range), ui, style)
                 })
             }
         }
@@ -100,9 +157,10 @@ macro_rules! impl_for_num_types {
             #[inline(always)]
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.start..=$num_type::MAX;
-                option_probe_with(self.value, ui, style, |value, ui, _| {
-                    ui.add(egui::DragValue::new(value).clamp_range(range));
+                option_probe_with(self.value, ui, style, |value, ui, style| {
+                    let r = drag_value(value, Some(range), ui, style);
                     ui.weak(format!("{}..", self.range.start));
+                    r
                 })
             }
         }
@@ -111,9 +169,10 @@ macro_rules! impl_for_num_types {
             #[inline(always)]
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = $num_type::MIN..=self.range.end;
-                option_probe_with(self.value, ui, style, |value, ui, _| {
-                    ui.add(egui::DragValue::new(value).clamp_range(range));
+                option_probe_with(self.value, ui, style, |value, ui, style| {
+                    let r = drag_value(value, Some(range), ui, style);
                     ui.weak(format!("..={}", self.range.end));
+                    r
                 })
             }
         }
@@ -122,9 +181,10 @@ macro_rules! impl_for_num_types {
             #[inline(always)]
             fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
                 let range = self.range.clone();
-                option_probe_with(self.value, ui, style, |value, ui, _| {
-                    ui.add(egui::DragValue::new(value).clamp_range(range));
+                option_probe_with(self.value, ui, style, |value, ui, style| {
+                    let r = drag_value(value, Some(range), ui, style);
                     ui.weak(format!("{}..={}", self.range.start(), self.range.end()));
+                    r
                 })
             }
         }
@@ -1,4 +1,6 @@
-use crate::{EguiProbe, Style};
+use std::ops::Range;
+
+use crate::{EguiProbe, Style, Validation};
 
 impl<T> EguiProbe for Option<T>
 where
@@ -6,9 +8,7 @@ where
 {
     #[inline(always)]
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
-        option_probe_with(self, ui, style, |value, ui, style| {
-            value.probe(ui, style);
-        })
+        option_probe_with(self, ui, style, |value, ui, style| value.probe(ui, style))
     }
 
     #[inline(always)]
@@ -25,6 +25,26 @@ where
             value.iterate_inner(f);
         }
     }
+
+    #[inline(always)]
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        match self {
+            Some(value) => value.iterate_inner_range(range, f),
+            None => 0,
+        }
+    }
+
+    #[inline(always)]
+    fn validate(&mut self) -> Result<(), Validation> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
 }
 
 #[inline(always)]
@@ -32,26 +52,39 @@ pub fn option_probe_with<T>(
     value: &mut Option<T>,
     ui: &mut egui::Ui,
     style: &Style,
-    probe: impl FnOnce(&mut T, &mut egui::Ui, &Style),
+    probe: impl FnOnce(&mut T, &mut egui::Ui, &Style) -> egui::Response,
 ) -> egui::Response
 where
     T: Default,
 {
+    if style.read_only {
+        return match value {
+            None => ui.weak("None"),
+            Some(value) => probe(value, ui, style),
+        };
+    }
+
     ui.horizontal(|ui| {
         let mut checked = value.is_some();
 
-        if ui.selectable_label(!checked, "None").clicked() {
+        let none = ui.selectable_label(!checked, "None");
+        if none.clicked() {
             checked = false;
         }
-        if ui.selectable_label(checked, "Some").clicked() {
+        let some = ui.selectable_label(checked, "Some");
+        if some.clicked() {
             checked = true;
         }
+
+        let mut response = none | some;
         if checked != value.is_some() {
             *value = checked.then(T::default);
+            response.mark_changed();
         }
         if let Some(value) = value {
-            probe(value, ui, style);
+            response |= probe(value, ui, style);
         }
+        response
     })
-    .response
+    .inner
 }
@@ -0,0 +1,176 @@
+//! Addressing of inner values by path.
+//!
+//! Path consists of labels produced by [`EguiProbe::iterate_inner`]
+//! from the root value to the addressed one.
+//! Labels are separated by `.`, except for labels in brackets,
+//! like indices of collections, which are appended as is.
+//! For example `inner.small_vec_1[3]`.
+//! Labels that contain `.`, brackets, quotes or backslashes, and empty labels,
+//! are quoted, with quotes and backslashes inside escaped by backslash.
+//! For example `map."a.b"[0]`.
+//! Empty path addresses the root value.
+
+use std::borrow::Cow;
+
+use crate::{read_only::EguiProbeReadOnly, EguiProbe};
+
+/// Returns `true` if the label is appended to the path as is, without separator.
+fn is_bracketed(label: &str) -> bool {
+    label
+        .strip_prefix('[')
+        .and_then(|label| label.strip_suffix(']'))
+        .is_some_and(|inner| !inner.contains(['[', ']', '"', '\\']))
+}
+
+/// Returns `true` if the label needs no quotes.
+fn is_plain(label: &str) -> bool {
+    !label.is_empty() && !label.contains(['.', '[', ']', '"', '\\'])
+}
+
+/// Appends label of an inner value to the path of its parent.
+pub(crate) fn join_path(path: &str, label: &str) -> String {
+    if is_bracketed(label) {
+        return format!("{path}{label}");
+    }
+
+    let mut joined = String::with_capacity(path.len() + label.len() + 3);
+    joined.push_str(path);
+    if !path.is_empty() {
+        joined.push('.');
+    }
+
+    if is_plain(label) {
+        joined.push_str(label);
+    } else {
+        joined.push('"');
+        for c in label.chars() {
+            if matches!(c, '"' | '\\') {
+                joined.push('\\');
+            }
+            joined.push(c);
+        }
+        joined.push('"');
+    }
+    joined
+}
+
+/// Returns `true` if the path addresses the parent value or any of its descendants.
+pub(crate) fn is_within(path: &str, parent: &str) -> bool {
+    match path.strip_prefix(parent) {
+        None => false,
+        Some(rest) => parent.is_empty() || rest.is_empty() || rest.starts_with(['.', '[']),
+    }
+}
+
+/// Splits first label from the path, unquoting it if needed.
+/// Returns `None` if path is empty.
+fn split_path(path: &str) -> Option<(Cow<'_, str>, &str)> {
+    if path.is_empty() {
+        return None;
+    }
+
+    let (head, tail) = if let Some(quoted) = path.strip_prefix('"') {
+        let mut label = String::new();
+        let mut end = quoted.len();
+        let mut chars = quoted.char_indices();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => {
+                    end = idx + 1;
+                    break;
+                }
+                '\\' => label.extend(chars.next().map(|(_, c)| c)),
+                _ => label.push(c),
+            }
+        }
+        (Cow::Owned(label), &quoted[end..])
+    } else {
+        let end = if path.starts_with('[') {
+            path.find(']').map_or(path.len(), |idx| idx + 1)
+        } else {
+            path.find(['.', '[']).unwrap_or(path.len())
+        };
+        let (head, tail) = path.split_at(end);
+        (Cow::Borrowed(head), tail)
+    };
+
+    Some((head, tail.strip_prefix('.').unwrap_or(tail)))
+}
+
+fn find_path(value: &mut dyn EguiProbe, path: &str, f: &mut dyn FnMut(&mut dyn EguiProbe)) {
+    match split_path(path) {
+        None => f(value),
+        Some((head, tail)) => {
+            let mut found = false;
+            value.iterate_inner(&mut |label, value| {
+                if !found && label == head.as_ref() {
+                    found = true;
+                    find_path(value, tail, f);
+                }
+            });
+        }
+    }
+}
+
+/// Finds inner value by path and calls `f` with it.
+///
+/// Returns `None` if there is no value with this path.
+///
+/// # Example
+///
+/// ```ignore
+/// probe_path_mut(&mut value, "inner.small_vec_1[3]", |v| v.has_inner());
+/// ```
+pub fn probe_path_mut<R>(
+    value: &mut dyn EguiProbe,
+    path: &str,
+    f: impl FnOnce(&mut dyn EguiProbe) -> R,
+) -> Option<R> {
+    let mut f = Some(f);
+    let mut result = None;
+
+    find_path(value, path, &mut |value| {
+        if let Some(f) = f.take() {
+            result = Some(f(value));
+        }
+    });
+
+    result
+}
+
+/// Returns `true` if value has inner value with this path.
+pub fn has_path(value: &mut dyn EguiProbe, path: &str) -> bool {
+    probe_path_mut(value, path, |_| ()).is_some()
+}
+
+fn visit(value: &mut dyn EguiProbe, path: &str, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+    value.iterate_inner(&mut |label, value| {
+        let path = join_path(path, label);
+        f(&path, &mut EguiProbeReadOnly { value: &mut *value });
+        if value.has_inner() {
+            visit(value, &path, f);
+        }
+    });
+}
+
+/// Visits all inner values recursively, parents before their children.
+///
+/// Values are passed to `f` as read-only,
+/// they are shown without allowing to edit them
+/// and can't be pasted to or reset.
+/// Reference is mutable only because [`EguiProbe`] methods take `&mut self`.
+/// Use [`probe_path_mut`] to modify the value at a path.
+pub fn visit_paths(value: &mut dyn EguiProbe, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+    visit(value, "", f);
+}
+
+/// Collects paths of all inner values that have no inner values themselves.
+pub fn leaf_paths(value: &mut dyn EguiProbe) -> Vec<String> {
+    let mut paths = Vec::new();
+    visit_paths(value, &mut |path, value| {
+        if !value.has_inner() {
+            paths.push(path.to_owned());
+        }
+    });
+    paths
+}
@@ -0,0 +1,42 @@
+use std::ops::Range;
+
+use crate::{EguiProbe, Style};
+
+/// Modifier to show value and its inner values without allowing to edit them.
+pub struct EguiProbeReadOnly<'a, T: ?Sized> {
+    pub value: &'a mut T,
+}
+
+impl<T> EguiProbe for EguiProbeReadOnly<'_, T>
+where
+    T: EguiProbe + ?Sized,
+{
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        let style = Style {
+            read_only: true,
+            ..*style
+        };
+        self.value.probe(ui, &style)
+    }
+
+    #[inline(always)]
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        self.value.iterate_inner(&mut |label, value| {
+            f(label, &mut EguiProbeReadOnly { value });
+        });
+    }
+
+    #[inline(always)]
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        self.value.iterate_inner_range(range, &mut |label, value| {
+            f(label, &mut EguiProbeReadOnly { value });
+        })
+    }
+
+    forward_probe_methods!(|this| this.value => has_inner, tooltip, validate);
+}
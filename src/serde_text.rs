@@ -0,0 +1,323 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use std::{any::Any, sync::Arc};
+
+use crate::{ClipboardValue, EguiProbe, Style};
+
+/// Text format used to edit values as text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextFormat {
+    #[default]
+    Ron,
+    Json,
+}
+
+impl TextFormat {
+    pub const ALL: [TextFormat; 2] = [TextFormat::Ron, TextFormat::Json];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextFormat::Ron => "RON",
+            TextFormat::Json => "JSON",
+        }
+    }
+}
+
+/// Value that can be edited as serialized text.
+///
+/// Implemented for all types that implement `Serialize` and `DeserializeOwned`.
+pub trait TextValue {
+    /// Serializes the value into text.
+    fn to_text(&self, format: TextFormat) -> Result<String, String>;
+
+    /// Replaces the value with one parsed from the text.
+    ///
+    /// Value is left unchanged if parsing fails.
+    fn set_from_text(&mut self, text: &str, format: TextFormat) -> Result<(), String>;
+}
+
+impl<T> TextValue for T
+where
+    T: Serialize + DeserializeOwned,
+{
+    fn to_text(&self, format: TextFormat) -> Result<String, String> {
+        match format {
+            TextFormat::Ron => ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+                .map_err(|err| err.to_string()),
+            TextFormat::Json => serde_json::to_string_pretty(self).map_err(|err| err.to_string()),
+        }
+    }
+
+    fn set_from_text(&mut self, text: &str, format: TextFormat) -> Result<(), String> {
+        *self = match format {
+            TextFormat::Ron => ron::from_str(text).map_err(|err| err.to_string())?,
+            TextFormat::Json => serde_json::from_str(text).map_err(|err| err.to_string())?,
+        };
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default)]
+struct TextEditState {
+    format: TextFormat,
+    text: String,
+
+    // Serialized value the text was last synchronized with.
+    source: String,
+    error: Option<String>,
+}
+
+/// Shows the value as editable text.
+///
+/// Value is replaced only when edited text is parsed successfully,
+/// otherwise parse error is shown below the text.
+/// Text is updated when the value is changed elsewhere,
+/// unless it has an error.
+pub(crate) fn text_value_ui(
+    value: &mut dyn TextValue,
+    ui: &mut egui::Ui,
+    style: &Style,
+    id: egui::Id,
+) -> egui::Response {
+    let mut state = ui
+        .ctx()
+        .data_mut(|d| d.get_temp::<TextEditState>(id))
+        .unwrap_or_default();
+
+    let r = ui.vertical(|ui| {
+        let mut format = state.format;
+        ui.horizontal(|ui| {
+            for option in TextFormat::ALL {
+                ui.selectable_value(&mut format, option, option.name());
+            }
+        });
+
+        if format != state.format {
+            state.format = format;
+            state.source.clear();
+            state.error = None;
+        }
+
+        if state.error.is_none() {
+            match value.to_text(state.format) {
+                Ok(text) if text != state.source => {
+                    state.text.clone_from(&text);
+                    state.source = text;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    ui.colored_label(ui.visuals().error_fg_color, err);
+                    return false;
+                }
+            }
+        }
+
+        let text_edit = egui::TextEdit::multiline(&mut state.text)
+            .code_editor()
+            .desired_rows(1)
+            .interactive(!style.read_only);
+
+        let mut changed = false;
+        if ui.add(text_edit).changed() {
+            match value.set_from_text(&state.text, state.format) {
+                Ok(()) => {
+                    state.source = value.to_text(state.format).unwrap_or_default();
+                    state.error = None;
+                    changed = true;
+                }
+                Err(err) => state.error = Some(err),
+            }
+        }
+
+        if let Some(error) = &state.error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+
+        changed
+    });
+
+    ui.ctx().data_mut(|d| d.insert_temp(id, state));
+
+    let mut response = r.response;
+    if r.inner {
+        response.mark_changed();
+    }
+    response
+}
+
+/// Returns `true` if the value is edited as text.
+pub(crate) fn text_mode(cx: &egui::Context, id: egui::Id) -> bool {
+    cx.data_mut(|d| d.get_temp(id)).unwrap_or(false)
+}
+
+/// Adds button to switch between editing the value as text and with probe UI.
+pub(crate) fn text_mode_button(ui: &mut egui::Ui, id: egui::Id, as_text: &mut bool) {
+    let text = if *as_text {
+        "Edit as value"
+    } else {
+        "Edit as text"
+    };
+    if ui.button(text).clicked() {
+        *as_text = !*as_text;
+        ui.ctx().data_mut(|d| d.insert_temp(id, *as_text));
+        ui.close_menu();
+    }
+}
+
+/// Adds button to copy the value as RON text into the system clipboard.
+pub(crate) fn copy_text_button(value: &dyn TextValue, ui: &mut egui::Ui) {
+    if ui.button("Copy as text").clicked() {
+        if let Ok(text) = value.to_text(TextFormat::Ron) {
+            ui.ctx().output_mut(|o| o.copied_text = text);
+        }
+        ui.close_menu();
+    }
+}
+
+/// Row waiting for text pasted after "Paste text" was chosen.
+#[derive(Clone, Default)]
+struct PendingPaste {
+    // Error of the last pasted text.
+    error: Option<String>,
+}
+
+/// Adds button to paste RON or JSON text from the system clipboard.
+///
+/// The row then waits for the pasted text, see [`paste_text`].
+pub(crate) fn paste_text_button(ui: &mut egui::Ui, id: egui::Id) {
+    if ui.button("Paste text").clicked() {
+        ui.ctx()
+            .data_mut(|d| d.insert_temp(id, PendingPaste::default()));
+        ui.close_menu();
+    }
+}
+
+/// Replaces the value with RON or JSON text pasted from the system clipboard
+/// after "Paste text" was chosen in the context menu of the row.
+///
+/// While the row waits for the text, its label is outlined and a hint is shown below.
+/// Waiting stops when the value is replaced, `Escape` is pressed
+/// or something else is clicked.
+///
+/// Returns `true` if the value was replaced.
+pub(crate) fn paste_text(
+    value: &mut dyn TextValue,
+    response: &egui::Response,
+    id: egui::Id,
+) -> bool {
+    let ctx = &response.ctx;
+    let Some(mut pending) = ctx.data(|d| d.get_temp::<PendingPaste>(id)) else {
+        return false;
+    };
+
+    let cancel = ctx.input(|i| {
+        i.key_pressed(egui::Key::Escape) || (i.pointer.any_pressed() && !response.hovered())
+    });
+    if cancel {
+        ctx.data_mut(|d| d.remove::<PendingPaste>(id));
+        return false;
+    }
+
+    // Text pasted into a focused widget is not for this row.
+    let pasted = match ctx.memory(|m| m.focus().is_some()) {
+        true => None,
+        false => ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                egui::Event::Paste(text) => Some(text.clone()),
+                _ => None,
+            })
+        }),
+    };
+
+    if let Some(text) = pasted {
+        let mut errors = Vec::new();
+        for format in TextFormat::ALL {
+            match value.set_from_text(&text, format) {
+                Ok(()) => {
+                    ctx.data_mut(|d| d.remove::<PendingPaste>(id));
+                    return true;
+                }
+                Err(err) => errors.push(format!("{}: {err}", format.name())),
+            }
+        }
+        pending.error = Some(errors.join("\n"));
+        ctx.data_mut(|d| d.insert_temp(id, pending.clone()));
+    }
+
+    let visuals = &ctx.style().visuals;
+    ctx.layer_painter(response.layer_id)
+        .rect_stroke(response.rect, 2.0, visuals.selection.stroke);
+    egui::show_tooltip_for(ctx, id, &response.rect, |ui| {
+        ui.label("Paste RON or JSON text");
+        if let Some(error) = &pending.error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+    });
+
+    false
+}
+
+/// Modifier to allow editing the value as text from the label context menu.
+pub struct EguiProbeTextValue<'a, T> {
+    pub value: &'a mut T,
+}
+
+impl<T> EguiProbe for EguiProbeTextValue<'_, T>
+where
+    T: EguiProbe + TextValue,
+{
+    #[inline(always)]
+    fn as_text_value(&mut self) -> Option<&mut dyn TextValue> {
+        Some(&mut *self.value)
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        probe,
+        has_inner,
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        validate,
+        as_clipboard_value,
+        as_default_value,
+        as_multi_value,
+    );
+}
+
+/// Allows combining with [`crate::EguiProbeClipboard`].
+impl<T> ClipboardValue for EguiProbeTextValue<'_, T>
+where
+    T: ClipboardValue,
+{
+    #[inline(always)]
+    fn copy_value(&self) -> Arc<dyn Any + Send + Sync> {
+        self.value.copy_value()
+    }
+
+    #[inline(always)]
+    fn can_paste(&self, copied: &(dyn Any + Send + Sync)) -> bool {
+        self.value.can_paste(copied)
+    }
+
+    #[inline(always)]
+    fn paste_value(&mut self, copied: &(dyn Any + Send + Sync)) -> bool {
+        self.value.paste_value(copied)
+    }
+
+    #[inline(always)]
+    fn reset_value(&mut self) {
+        self.value.reset_value();
+    }
+}
+
+/// Allows editing the value as text from the label context menu.
+///
+/// Value must implement `Serialize` and `DeserializeOwned`.
+#[inline(always)]
+pub fn text_value<T>(value: &mut T) -> EguiProbeTextValue<'_, T>
+where
+    T: EguiProbe + TextValue,
+{
+    EguiProbeTextValue { value }
+}
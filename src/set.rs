@@ -1 +1,178 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
 
+use crate::{
+    collections::EguiProbeFrozen, map::add_key_probe, option::option_probe_with, EguiProbe, Style,
+};
+
+/// Row of a set element.
+/// Element itself is shown as a label, value column holds a remove button.
+pub(crate) struct SetElement {
+    pub frozen: bool,
+    pub delete: bool,
+}
+
+impl EguiProbe for SetElement {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        if self.frozen || style.read_only {
+            return ui.label("");
+        }
+
+        let mut r = ui.small_button(style.remove_button_text());
+        if r.clicked() {
+            self.delete = true;
+            r.mark_changed();
+        }
+        r
+    }
+}
+
+fn iterate_frozen<'a, T>(
+    set: impl Iterator<Item = &'a T>,
+    f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+) where
+    T: Display + 'a,
+{
+    for value in set {
+        let mut item = SetElement {
+            frozen: true,
+            delete: false,
+        };
+        f(&value.to_string(), &mut item);
+    }
+}
+
+impl<T, S> EguiProbe for HashSet<T, S>
+where
+    T: Display + FromStr + Eq + std::hash::Hash,
+    S: std::hash::BuildHasher,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        add_key_probe(self.len(), ui, style, |value| self.insert(value))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        self.retain(|value| {
+            let mut item = SetElement {
+                frozen: false,
+                delete: false,
+            };
+            f(&value.to_string(), &mut item);
+            !item.delete
+        });
+    }
+}
+
+impl<T, S> EguiProbe for EguiProbeFrozen<'_, HashSet<T, S>>
+where
+    T: Display,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak(format!("[{}]", self.value.len()))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.value.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        iterate_frozen(self.value.iter(), f);
+    }
+}
+
+impl<T, S> EguiProbe for EguiProbeFrozen<'_, Option<HashSet<T, S>>>
+where
+    T: Display,
+    S: Default,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        option_probe_with(self.value, ui, style, |value, ui, _style| {
+            ui.weak(format!("[{}]", value.len()))
+        })
+    }
+
+    fn has_inner(&mut self) -> bool {
+        match self.value {
+            Some(value) => !value.is_empty(),
+            None => false,
+        }
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        if let Some(set) = self.value {
+            iterate_frozen(set.iter(), f);
+        }
+    }
+}
+
+impl<T> EguiProbe for BTreeSet<T>
+where
+    T: Display + FromStr + Ord,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        add_key_probe(self.len(), ui, style, |value| self.insert(value))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        self.retain(|value| {
+            let mut item = SetElement {
+                frozen: false,
+                delete: false,
+            };
+            f(&value.to_string(), &mut item);
+            !item.delete
+        });
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, BTreeSet<T>>
+where
+    T: Display,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak(format!("[{}]", self.value.len()))
+    }
+
+    fn has_inner(&mut self) -> bool {
+        !self.value.is_empty()
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        iterate_frozen(self.value.iter(), f);
+    }
+}
+
+impl<T> EguiProbe for EguiProbeFrozen<'_, Option<BTreeSet<T>>>
+where
+    T: Display,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        option_probe_with(self.value, ui, style, |value, ui, _style| {
+            ui.weak(format!("[{}]", value.len()))
+        })
+    }
+
+    fn has_inner(&mut self) -> bool {
+        match self.value {
+            Some(value) => !value.is_empty(),
+            None => false,
+        }
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        if let Some(set) = self.value {
+            iterate_frozen(set.iter(), f);
+        }
+    }
+}
@@ -1,5 +1,7 @@
+use std::ops::Range;
+
 use crate::{
-    collections::{DeleteMe, EguiProbeFrozen},
+    collections::{iterate_sequence, iterate_slice_range, EguiProbeFrozen, Sequence},
     option::option_probe_with,
     EguiProbe,
 };
@@ -11,13 +13,18 @@ impl<T, const N: usize> EguiProbe for smallvec1::SmallVec<[T;N]>
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
         ui.horizontal(|ui| {
-            ui.weak(format!("[{}]", self.len()));
-            let r = ui.small_button(style.add_button_text());
-            if r.clicked() {
+            let r = ui.weak(format!("[{}]", self.len()));
+            if style.read_only {
+                return r;
+            }
+            let mut add = ui.small_button(style.add_button_text());
+            if add.clicked() {
                 self.push(T::default());
+                add.mark_changed();
             }
+            r | add
         })
-        .response
+        .inner
     }
 
     fn has_inner(&mut self) -> bool {
@@ -25,16 +32,39 @@ impl<T, const N: usize> EguiProbe for smallvec1::SmallVec<[T;N]>
     }
 
     fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
-        let mut idx = 0;
-        self.retain_mut(|value| {
-            let mut item = DeleteMe {
-                value,
-                delete: false,
-            };
-            f(&format!("[{idx}]"), &mut item);
-            idx += 1;
-            !item.delete
-        });
+        iterate_sequence(self, 0..usize::MAX, None, f);
+    }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_sequence(self, range, None, f)
+    }
+}
+
+#[cfg(feature = "smallvec1")]
+impl<T, const N: usize> Sequence for smallvec1::SmallVec<[T;N]> {
+    type Item = T;
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn for_each_item(&mut self, range: Range<usize>, f: &mut dyn FnMut(usize, &mut T)) {
+        let start = range.start;
+        for (i, value) in self[range].iter_mut().enumerate() {
+            f(start + i, value);
+        }
+    }
+
+    fn insert_item(&mut self, idx: usize, item: T) {
+        self.insert(idx, item);
+    }
+
+    fn remove_item(&mut self, idx: usize) -> T {
+        self.remove(idx)
     }
 }
 
@@ -56,6 +86,14 @@ where
             f(&format!("[{i}]"), value);
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_slice_range(self.value, range, f)
+    }
 }
 
 #[cfg(feature = "smallvec1")]
@@ -65,7 +103,7 @@ where
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
         option_probe_with(self.value, ui, style, |value, ui, _style| {
-            ui.weak(format!("[{}]", value.len()));
+            ui.weak(format!("[{}]", value.len()))
         })
     }
 
@@ -83,6 +121,17 @@ where
             }
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        match self.value {
+            Some(vec) => iterate_slice_range(vec, range, f),
+            None => 0,
+        }
+    }
 }
 
 
@@ -93,13 +142,18 @@ impl<T, const N: usize> EguiProbe for smallvec2::SmallVec<T,N>
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
         ui.horizontal(|ui| {
-            ui.weak(format!("[{}]", self.len()));
-            let r = ui.small_button(style.add_button_text());
-            if r.clicked() {
+            let r = ui.weak(format!("[{}]", self.len()));
+            if style.read_only {
+                return r;
+            }
+            let mut add = ui.small_button(style.add_button_text());
+            if add.clicked() {
                 self.push(T::default());
+                add.mark_changed();
             }
+            r | add
         })
-            .response
+        .inner
     }
 
     fn has_inner(&mut self) -> bool {
@@ -107,16 +161,39 @@ impl<T, const N: usize> EguiProbe for smallvec2::SmallVec<T,N>
     }
 
     fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
-        let mut idx = 0;
-        self.retain_mut(|value| {
-            let mut item = DeleteMe {
-                value,
-                delete: false,
-            };
-            f(&format!("[{idx}]"), &mut item);
-            idx += 1;
-            !item.delete
-        });
+        iterate_sequence(self, 0..usize::MAX, None, f);
+    }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_sequence(self, range, None, f)
+    }
+}
+
+#[cfg(feature = "smallvec2")]
+impl<T, const N: usize> Sequence for smallvec2::SmallVec<T,N> {
+    type Item = T;
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn for_each_item(&mut self, range: Range<usize>, f: &mut dyn FnMut(usize, &mut T)) {
+        let start = range.start;
+        for (i, value) in self[range].iter_mut().enumerate() {
+            f(start + i, value);
+        }
+    }
+
+    fn insert_item(&mut self, idx: usize, item: T) {
+        self.insert(idx, item);
+    }
+
+    fn remove_item(&mut self, idx: usize) -> T {
+        self.remove(idx)
     }
 }
 
@@ -138,6 +215,14 @@ impl<T, const N: usize> EguiProbe for EguiProbeFrozen<'_, smallvec2::SmallVec<T,
             f(&format!("[{i}]"), value);
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_slice_range(self.value, range, f)
+    }
 }
 
 #[cfg(feature = "smallvec2")]
@@ -147,7 +232,7 @@ impl<T, const N: usize> EguiProbe for EguiProbeFrozen<'_, Option<smallvec2::Smal
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
         option_probe_with(self.value, ui, style, |value, ui, _style| {
-            ui.weak(format!("[{}]", value.len()));
+            ui.weak(format!("[{}]", value.len()))
         })
     }
 
@@ -165,4 +250,15 @@ impl<T, const N: usize> EguiProbe for EguiProbeFrozen<'_, Option<smallvec2::Smal
             }
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        match self.value {
+            Some(vec) => iterate_slice_range(vec, range, f),
+            None => 0,
+        }
+    }
 }
\ No newline at end of file
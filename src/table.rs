@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+
+use crate::{EguiProbe, ProbeLayout, Style};
+
+/// Maximum height of the scrolled rows of the table.
+const MAX_ROWS_HEIGHT: f32 = 300.0;
+
+/// Modifier to show a sequence as a grid,
+/// with one row per item and one column per inner value of the item.
+///
+/// Items without inner values are shown in a single column.
+/// Cells show values without their inner values.
+pub struct EguiProbeTable<'a, T> {
+    pub value: &'a mut T,
+}
+
+impl<T> EguiProbe for EguiProbeTable<'_, Vec<T>>
+where
+    T: EguiProbe + Default,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        table_probe(self.value, ui, style)
+    }
+}
+
+/// Shows a vector as a grid.
+///
+/// Add `#[egui_probe(table)]` to the field to do the same with derive.
+#[inline(always)]
+pub fn table<T>(value: &mut Vec<T>) -> EguiProbeTable<'_, Vec<T>>
+where
+    T: EguiProbe + Default,
+{
+    EguiProbeTable { value }
+}
+
+/// Column the rows are sorted by.
+#[derive(Clone, Copy, PartialEq)]
+struct TableSort {
+    column: usize,
+    descending: bool,
+}
+
+impl TableSort {
+    /// Cycles sorting of the clicked column
+    /// through ascending, descending and unsorted.
+    fn next(sort: Option<TableSort>, column: usize) -> Option<TableSort> {
+        match sort {
+            Some(sort) if sort.column == column && sort.descending => None,
+            Some(sort) if sort.column == column => Some(TableSort {
+                column,
+                descending: true,
+            }),
+            _ => Some(TableSort {
+                column,
+                descending: false,
+            }),
+        }
+    }
+}
+
+/// Value of a cell used to sort rows.
+enum SortKey {
+    Number(f64),
+    Text(String),
+}
+
+/// Returns sort key of the value,
+/// or `None` if values of this type can not be sorted.
+fn sort_key(value: &mut dyn EguiProbe) -> Option<SortKey> {
+    let multi_value = value.as_multi_value()?;
+    let any = multi_value.as_any();
+
+    macro_rules! numbers {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = any.downcast_ref::<$ty>() {
+                    return Some(SortKey::Number(*value as f64));
+                }
+            )*
+        };
+    }
+
+    numbers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+    if let Some(value) = any.downcast_ref::<bool>() {
+        return Some(SortKey::Number(*value as u8 as f64));
+    }
+    if let Some(value) = any.downcast_ref::<char>() {
+        return Some(SortKey::Text(value.to_string()));
+    }
+    if let Some(value) = any.downcast_ref::<String>() {
+        return Some(SortKey::Text(value.clone()));
+    }
+    None
+}
+
+/// Compares sort keys, values that can not be sorted come last.
+fn cmp_keys(a: &Option<SortKey>, b: &Option<SortKey>) -> Ordering {
+    match (a, b) {
+        (Some(SortKey::Number(a)), Some(SortKey::Number(b))) => a.total_cmp(b),
+        (Some(SortKey::Text(a)), Some(SortKey::Text(b))) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+/// Calls `f` with each cell of the item and its column.
+fn for_each_cell(item: &mut dyn EguiProbe, f: &mut dyn FnMut(usize, &mut dyn EguiProbe)) {
+    if !item.has_inner() {
+        f(0, item);
+        return;
+    }
+
+    let mut column = 0;
+    item.iterate_inner(&mut |_, value| {
+        f(column, value);
+        column += 1;
+    });
+}
+
+/// Returns labels of columns taken from the first item.
+fn column_labels<T>(items: &mut [T]) -> Vec<String>
+where
+    T: EguiProbe,
+{
+    let mut labels = Vec::new();
+    if let Some(item) = items.first_mut() {
+        if item.has_inner() {
+            item.iterate_inner(&mut |label, _| labels.push(label.to_owned()));
+        } else {
+            labels.push("value".to_owned());
+        }
+    }
+    labels
+}
+
+/// Returns indices of items in the order they are shown.
+fn sorted_order<T>(items: &mut [T], sort: Option<TableSort>) -> Vec<usize>
+where
+    T: EguiProbe,
+{
+    let mut order: Vec<usize> = (0..items.len()).collect();
+
+    if let Some(sort) = sort {
+        let keys: Vec<Option<SortKey>> = items
+            .iter_mut()
+            .map(|item| {
+                let mut key = None;
+                for_each_cell(item, &mut |column, value| {
+                    if column == sort.column {
+                        key = sort_key(value);
+                    }
+                });
+                key
+            })
+            .collect();
+
+        order.sort_by(|&a, &b| {
+            let ordering = cmp_keys(&keys[a], &keys[b]);
+            match (sort.descending, &keys[a], &keys[b]) {
+                (true, Some(_), Some(_)) => ordering.reverse(),
+                _ => ordering,
+            }
+        });
+    }
+
+    order
+}
+
+fn table_probe<T>(items: &mut Vec<T>, ui: &mut egui::Ui, style: &Style) -> egui::Response
+where
+    T: EguiProbe + Default,
+{
+    let id = ui.make_persistent_id("probe_table");
+    let sort_id = id.with("sort");
+
+    let mut sort: Option<TableSort> = ui.ctx().data_mut(|d| d.get_temp(sort_id));
+
+    let labels = column_labels(items);
+    let mut index_layout = ProbeLayout::load(ui.ctx(), id.with("index"));
+    let mut layouts: Vec<ProbeLayout> = (0..labels.len())
+        .map(|column| ProbeLayout::load(ui.ctx(), id.with(("column", column))))
+        .collect();
+
+    let mut changed = false;
+
+    let mut response = ui
+        .vertical(|ui| {
+            ui.horizontal(|ui| {
+                index_layout.inner_label_ui(0, "index", ui, |ui| ui.weak("#"));
+
+                for (column, label) in labels.iter().enumerate() {
+                    layouts[column].inner_label_ui(0, ("header", column), ui, |ui| {
+                        let text = match sort {
+                            Some(sort) if sort.column == column && sort.descending => {
+                                format!("{label} ⏷")
+                            }
+                            Some(sort) if sort.column == column => format!("{label} ⏶"),
+                            _ => label.clone(),
+                        };
+
+                        let r = ui
+                            .add(
+                                egui::Label::new(egui::RichText::new(text).strong())
+                                    .sense(egui::Sense::click()),
+                            )
+                            .on_hover_text("Click to sort");
+                        if r.clicked() {
+                            sort = TableSort::next(sort, column);
+                        }
+                        r
+                    });
+                }
+            });
+
+            let order = sorted_order(items, sort);
+            let mut remove = None;
+
+            // Only visible rows are shown, so that tables with many rows stay fast.
+            egui::ScrollArea::vertical()
+                .id_source(id.with("rows"))
+                .max_height(MAX_ROWS_HEIGHT)
+                .auto_shrink([true, true])
+                .show_rows(
+                    ui,
+                    ui.spacing().interact_size.y,
+                    order.len(),
+                    |ui, range| {
+                        for &idx in &order[range] {
+                            ui.horizontal(|ui| {
+                                index_layout.inner_label_ui(0, ("index", idx), ui, |ui| {
+                                    ui.weak(format!("[{idx}]"))
+                                });
+
+                                for_each_cell(&mut items[idx], &mut |column, value| {
+                                    if let Some(layout) = layouts.get_mut(column) {
+                                        layout.inner_label_ui(0, (idx, column), ui, |ui| {
+                                            let r = value.probe(ui, style);
+                                            changed |= r.changed();
+                                            r
+                                        });
+                                    }
+                                });
+
+                                if !style.read_only
+                                    && ui
+                                        .small_button(style.remove_button_text())
+                                        .on_hover_text("Remove row")
+                                        .clicked()
+                                {
+                                    remove = Some(idx);
+                                }
+                            });
+                        }
+                    },
+                );
+
+            if let Some(idx) = remove {
+                items.remove(idx);
+                changed = true;
+            }
+
+            if !style.read_only
+                && ui
+                    .small_button(style.add_button_text())
+                    .on_hover_text("Add row")
+                    .clicked()
+            {
+                items.push(T::default());
+                changed = true;
+            }
+        })
+        .response;
+
+    ui.ctx().data_mut(|d| match sort {
+        None => d.remove::<TableSort>(sort_id),
+        Some(sort) => d.insert_temp(sort_id, sort),
+    });
+
+    index_layout.store(ui.ctx());
+    for layout in layouts {
+        layout.store(ui.ctx());
+    }
+
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
@@ -0,0 +1,731 @@
+//! Headless testing of [`EguiProbe`] implementations.
+//!
+//! [`ProbeHarness`] shows [`Probe`] in a headless `egui::Context`,
+//! simulates user input on rows addressed by path
+//! and collects rows shown in the last frame.
+//!
+//! Paths have the same format as in [`crate::probe_path_mut`].
+//!
+//! # Example
+//!
+//! ```
+//! use egui_probe::testing::ProbeHarness;
+//!
+//! let mut harness = ProbeHarness::new(false);
+//! harness.click("");
+//! assert!(harness.value);
+//! ```
+//!
+//! # Snapshots
+//!
+//! [`ProbeHarness::snapshot`] describes rows of the last frame
+//! and texts painted in them as stable text,
+//! so that changes of the shown tree can be caught by comparing with a golden file
+//! using [`ProbeHarness::assert_snapshot`].
+//!
+//! Shapes are produced by the headless context, no GPU is needed.
+
+use std::{fmt::Write as _, path::PathBuf};
+
+use crate::{
+    EguiProbe, Probe, ProbeDiff, ProbeHistory, ProbeMulti, ProbeResponse, ValidationIssue,
+};
+
+/// Frames to run at most while waiting for UI to settle.
+const MAX_SETTLE_FRAMES: usize = 16;
+
+/// Row of the probe table shown in the last frame.
+#[derive(Clone, Debug)]
+pub struct Row {
+    /// Path of the value shown in the row.
+    /// Root value has empty path.
+    pub path: String,
+    pub label: String,
+    pub indent: usize,
+
+    /// `None` if value has no inner values.
+    /// Otherwise whether the row is expanded.
+    pub open: Option<bool>,
+
+    pub rect: egui::Rect,
+    pub label_rect: egui::Rect,
+    pub collapse_rect: Option<egui::Rect>,
+    pub value_rect: egui::Rect,
+
+    /// Texts painted in the value column, from left to right.
+    pub texts: Vec<String>,
+
+    /// Texts painted in the label column, from left to right.
+    pub label_texts: Vec<String>,
+}
+
+impl Row {
+    /// Returns texts painted in the value column separated by spaces.
+    pub fn value_text(&self) -> String {
+        self.texts.join(" ")
+    }
+}
+
+fn rows_id() -> egui::Id {
+    egui::Id::new("egui_probe_testing_rows")
+}
+
+/// Records row shown by the probe if it is shown by [`ProbeHarness`].
+pub(crate) fn record_row(cx: &egui::Context, row: Row) {
+    cx.data_mut(|d| {
+        if let Some(rows) = d.get_temp_mut_or_default::<Option<Vec<Row>>>(rows_id()) {
+            rows.push(row);
+        }
+    });
+}
+
+/// Text painted in the last frame.
+/// Texts are kept in painting order, so that texts of popups come last.
+struct PaintedText {
+    rect: egui::Rect,
+    text: String,
+}
+
+fn collect_texts(shape: &egui::Shape, clip_rect: egui::Rect, texts: &mut Vec<PaintedText>) {
+    match shape {
+        egui::Shape::Vec(shapes) => {
+            for shape in shapes {
+                collect_texts(shape, clip_rect, texts);
+            }
+        }
+        egui::Shape::Text(text) => {
+            let rect = text.galley.rect.translate(text.pos.to_vec2());
+            if clip_rect.intersects(rect) && !text.galley.text().is_empty() {
+                texts.push(PaintedText {
+                    rect,
+                    text: text.galley.text().to_owned(),
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns path of the golden file for the snapshot.
+fn snapshot_path(name: &str) -> PathBuf {
+    let dir = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_else(|| ".".into());
+    PathBuf::from(dir)
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.snap"))
+}
+
+/// Shows [`Probe`] for a value in a headless `egui::Context`
+/// and simulates user input.
+///
+/// [`ProbeHarness::multi`] shows [`ProbeMulti`] for several values instead.
+///
+/// Animations are disabled, so rows are expanded and collapsed immediately.
+pub struct ProbeHarness<T> {
+    /// Value edited by the probe.
+    pub value: T,
+
+    ctx: egui::Context,
+    show: fn(&mut T, &mut egui::Ui, bool) -> ProbeResponse,
+    read_only: bool,
+    screen_size: egui::Vec2,
+    time: f64,
+    events: Vec<egui::Event>,
+    shapes: Vec<egui::epaint::ClippedShape>,
+    pixels_per_point: f32,
+    texts: Vec<PaintedText>,
+    rows: Vec<Row>,
+    changed_paths: Vec<String>,
+    issues: Vec<ValidationIssue>,
+    copied_text: String,
+}
+
+impl<T> ProbeHarness<T>
+where
+    T: EguiProbe,
+{
+    /// Creates new harness and shows the value.
+    pub fn new(value: T) -> Self {
+        ProbeHarness::with_show(value, |value, ui, read_only| {
+            Probe::new("value", value).read_only(read_only).show(ui)
+        })
+    }
+}
+
+impl<T> ProbeHarness<Vec<T>>
+where
+    T: EguiProbe,
+{
+    /// Creates new harness and shows the values together with [`ProbeMulti`].
+    pub fn multi(values: Vec<T>) -> Self {
+        ProbeHarness::with_show(values, |values, ui, read_only| {
+            let mut values: Vec<&mut T> = values.iter_mut().collect();
+            ProbeMulti::new("value", &mut values)
+                .read_only(read_only)
+                .show(ui)
+        })
+    }
+}
+
+impl<T> ProbeHarness<(T, ProbeHistory<T>)>
+where
+    T: EguiProbe + Clone,
+{
+    /// Creates new harness and shows the value with [`Probe::show_with_history`].
+    ///
+    /// The history is the second element of the harness value.
+    pub fn history(value: T, history: ProbeHistory<T>) -> Self {
+        ProbeHarness::with_show((value, history), |(value, history), ui, read_only| {
+            Probe::new("value", value)
+                .read_only(read_only)
+                .show_with_history(history, ui)
+        })
+    }
+}
+
+impl<T> ProbeHarness<(T, T)>
+where
+    T: EguiProbe,
+{
+    /// Creates new harness and compares the values with [`ProbeDiff`].
+    ///
+    /// Changed paths are collected from both sides.
+    pub fn diff(left: T, right: T) -> Self {
+        ProbeHarness::with_show((left, right), |(left, right), ui, read_only| {
+            let response = ProbeDiff::new("value", left, right)
+                .read_only(read_only)
+                .show(ui);
+            let mut changed_paths = response.left_changed_paths;
+            changed_paths.extend(response.right_changed_paths);
+            ProbeResponse {
+                response: response.response,
+                changed_paths,
+                issues: Vec::new(),
+            }
+        })
+    }
+}
+
+impl<T> ProbeHarness<T> {
+    /// Creates new harness that shows the value with a custom function,
+    /// e.g. to show [`Probe`] with options the other constructors don't set.
+    ///
+    /// The function receives whether the harness is read-only.
+    pub fn with_show(value: T, show: fn(&mut T, &mut egui::Ui, bool) -> ProbeResponse) -> Self {
+        let ctx = egui::Context::default();
+
+        let mut style = (*ctx.style()).clone();
+        style.animation_time = 0.0;
+        ctx.set_style(style);
+
+        let mut harness = ProbeHarness {
+            value,
+            ctx,
+            show,
+            read_only: false,
+            screen_size: egui::vec2(800.0, 600.0),
+            time: 0.0,
+            events: Vec::new(),
+            shapes: Vec::new(),
+            pixels_per_point: 1.0,
+            texts: Vec::new(),
+            rows: Vec::new(),
+            changed_paths: Vec::new(),
+            issues: Vec::new(),
+            copied_text: String::new(),
+        };
+        harness.run();
+        harness
+    }
+
+    /// Shows value without allowing to edit it.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self.run();
+        self
+    }
+
+    /// Sets size of the headless screen.
+    pub fn screen_size(mut self, size: egui::Vec2) -> Self {
+        self.screen_size = size;
+        self.run();
+        self
+    }
+
+    /// Returns context the probe is shown in.
+    pub fn ctx(&self) -> &egui::Context {
+        &self.ctx
+    }
+
+    /// Runs single frame, delivering pending input events.
+    ///
+    /// Returns `true` if another frame was requested.
+    pub fn step(&mut self) -> bool {
+        let raw_input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                self.screen_size,
+            )),
+            time: Some(self.time),
+            events: core::mem::take(&mut self.events),
+            ..Default::default()
+        };
+        self.time += 1.0 / 60.0;
+
+        self.ctx
+            .data_mut(|d| d.insert_temp(rows_id(), Some(Vec::<Row>::new())));
+
+        let mut response = None;
+        let output = self.ctx.run(raw_input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                response = Some((self.show)(&mut self.value, ui, self.read_only));
+            });
+        });
+
+        let mut rows = self
+            .ctx
+            .data_mut(|d| d.remove_temp::<Option<Vec<Row>>>(rows_id()))
+            .flatten()
+            .unwrap_or_default();
+
+        self.texts.clear();
+        for clipped in &output.shapes {
+            collect_texts(&clipped.shape, clipped.clip_rect, &mut self.texts);
+        }
+        for row in &mut rows {
+            let mut texts: Vec<_> = self
+                .texts
+                .iter()
+                .filter(|text| row.rect.contains(text.rect.center()))
+                .collect();
+            texts.sort_by(|a, b| a.rect.min.x.total_cmp(&b.rect.min.x));
+            let (texts, label_texts): (Vec<_>, Vec<_>) = texts
+                .into_iter()
+                .partition(|text| row.value_rect.contains(text.rect.center()));
+            row.texts = texts.into_iter().map(|text| text.text.clone()).collect();
+            row.label_texts = label_texts
+                .into_iter()
+                .map(|text| text.text.clone())
+                .collect();
+        }
+        self.rows = rows;
+        if !output.platform_output.copied_text.is_empty() {
+            self.copied_text = output.platform_output.copied_text;
+        }
+        self.shapes = output.shapes;
+        self.pixels_per_point = output.pixels_per_point;
+
+        if let Some(response) = response {
+            for path in response.changed_paths {
+                if !self.changed_paths.contains(&path) {
+                    self.changed_paths.push(path);
+                }
+            }
+            self.issues = response.issues;
+        }
+
+        output
+            .viewport_output
+            .get(&egui::ViewportId::ROOT)
+            .is_some_and(|viewport| viewport.repaint_delay.is_zero())
+    }
+
+    /// Runs frames until UI settles, delivering pending input events.
+    pub fn run(&mut self) {
+        for _ in 0..MAX_SETTLE_FRAMES {
+            if !self.step() && self.events.is_empty() {
+                break;
+            }
+        }
+    }
+
+    /// Returns rows shown in the last frame, in order of appearance.
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Returns `true` if row with the path was shown in the last frame.
+    pub fn has_row(&self, path: &str) -> bool {
+        self.rows.iter().any(|row| row.path == path)
+    }
+
+    /// Returns row with the path shown in the last frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such row.
+    pub fn row(&self, path: &str) -> &Row {
+        match self.rows.iter().find(|row| row.path == path) {
+            Some(row) => row,
+            None => panic!(
+                "No row with path `{path}`. Shown rows: {:?}",
+                self.rows.iter().map(|row| &row.path).collect::<Vec<_>>()
+            ),
+        }
+    }
+
+    /// Returns shapes painted in the last frame.
+    pub fn shapes(&self) -> &[egui::epaint::ClippedShape] {
+        &self.shapes
+    }
+
+    /// Tessellates shapes painted in the last frame into meshes.
+    pub fn tessellate(&self) -> Vec<egui::ClippedPrimitive> {
+        self.ctx
+            .tessellate(self.shapes.clone(), self.pixels_per_point)
+    }
+
+    /// Returns stable textual description of the last frame.
+    ///
+    /// Each row is described with its path, label, indentation
+    /// and whether it is expanded,
+    /// followed by texts painted in the label and value columns.
+    /// Coordinates are left out, so that snapshot does not depend
+    /// on font metrics and spacing of the `egui` version.
+    pub fn snapshot(&self) -> String {
+        let mut out = String::new();
+
+        for row in &self.rows {
+            let open = match row.open {
+                None => "-",
+                Some(false) => "closed",
+                Some(true) => "open",
+            };
+            let _ = writeln!(
+                out,
+                "row {:?} label {:?} indent {} {open}",
+                row.path, row.label, row.indent
+            );
+            let _ = writeln!(out, "  label {:?}", row.label_texts);
+            let _ = writeln!(out, "  value {:?}", row.texts);
+        }
+
+        out
+    }
+
+    /// Compares [`ProbeHarness::snapshot`] with golden file
+    /// `tests/snapshots/<name>.snap` in the crate directory.
+    ///
+    /// Golden file is written instead
+    /// if `UPDATE_SNAPSHOTS` environment variable is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if golden file does not exist or snapshot differs from it.
+    /// Actual snapshot is written next to it with `.snap.new` extension.
+    pub fn assert_snapshot(&self, name: &str) {
+        let snapshot = self.snapshot();
+        let path = snapshot_path(name);
+
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).unwrap();
+            }
+            std::fs::write(&path, snapshot).unwrap();
+            return;
+        }
+
+        let new_path = path.with_extension("snap.new");
+
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(expected) => expected,
+            Err(err) => {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir).unwrap();
+                }
+                std::fs::write(&new_path, &snapshot).unwrap();
+                panic!(
+                    "Snapshot `{name}` has no golden file {}: {err}\n\
+                     Actual snapshot is written to {}, \
+                     set `UPDATE_SNAPSHOTS` environment variable to accept it",
+                    path.display(),
+                    new_path.display(),
+                );
+            }
+        };
+
+        if expected == snapshot {
+            return;
+        }
+
+        std::fs::write(&new_path, &snapshot).unwrap();
+
+        let expected_lines: Vec<_> = expected.lines().collect();
+        let actual_lines: Vec<_> = snapshot.lines().collect();
+        let line = (0..expected_lines.len().max(actual_lines.len()))
+            .find(|&idx| expected_lines.get(idx) != actual_lines.get(idx))
+            .unwrap_or(expected_lines.len());
+        let expected = expected_lines.get(line).copied().unwrap_or_default();
+        let actual = actual_lines.get(line).copied().unwrap_or_default();
+
+        panic!(
+            "Snapshot `{name}` differs from {} at line {}\n\
+             expected: {expected}\n  \
+             actual: {actual}\n\
+             Actual snapshot is written to {}",
+            path.display(),
+            line + 1,
+            new_path.display(),
+        );
+    }
+
+    /// Returns paths of values changed by the last simulated input.
+    pub fn changed_paths(&self) -> &[String] {
+        &self.changed_paths
+    }
+
+    /// Returns validation issues reported in the last frame.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Returns text last copied into the system clipboard.
+    pub fn copied_text(&self) -> &str {
+        &self.copied_text
+    }
+
+    fn begin_input(&mut self) {
+        self.changed_paths.clear();
+    }
+
+    fn pointer_button(&mut self, pos: egui::Pos2, button: egui::PointerButton, pressed: bool) {
+        self.events.push(egui::Event::PointerButton {
+            pos,
+            button,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        });
+    }
+
+    fn key(&mut self, key: egui::Key, modifiers: egui::Modifiers) {
+        for pressed in [true, false] {
+            self.events.push(egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed,
+                repeat: false,
+                modifiers,
+            });
+        }
+    }
+
+    /// Returns position of the first widget in the value column of the row.
+    fn value_pos(&self, path: &str) -> egui::Pos2 {
+        let rect = self.row(path).value_rect;
+        egui::pos2(rect.min.x + 4.0, rect.center().y)
+    }
+
+    fn click_button_at(&mut self, pos: egui::Pos2, button: egui::PointerButton) {
+        self.begin_input();
+
+        self.events.push(egui::Event::PointerMoved(pos));
+        self.step();
+        self.pointer_button(pos, button, true);
+        self.step();
+        self.pointer_button(pos, button, false);
+        self.run();
+    }
+
+    /// Clicks at the position on the screen.
+    pub fn click_at(&mut self, pos: egui::Pos2) {
+        self.click_button_at(pos, egui::PointerButton::Primary);
+    }
+
+    /// Clicks with secondary button at the position on the screen.
+    pub fn secondary_click_at(&mut self, pos: egui::Pos2) {
+        self.click_button_at(pos, egui::PointerButton::Secondary);
+    }
+
+    /// Opens context menu of the row label.
+    ///
+    /// Items of the menu can be clicked with [`ProbeHarness::click_any_text`].
+    pub fn context_menu(&mut self, path: &str) {
+        self.secondary_click_at(self.row(path).label_rect.center());
+    }
+
+    /// Clicks the first widget in the value column of the row.
+    pub fn click(&mut self, path: &str) {
+        self.click_at(self.value_pos(path));
+    }
+
+    /// Returns rect of the text painted in the value column of the row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such text.
+    pub fn text_rect(&self, path: &str, text: &str) -> egui::Rect {
+        let row = self.row(path);
+        let found = self
+            .texts
+            .iter()
+            .find(|painted| painted.text == text && row.value_rect.contains(painted.rect.center()));
+
+        match found {
+            Some(painted) => painted.rect,
+            None => panic!(
+                "No text `{text}` in row `{path}`. Texts in the row: {:?}",
+                row.texts
+            ),
+        }
+    }
+
+    /// Clicks text painted in the value column of the row,
+    /// like `"Some"` or `"+"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such text.
+    pub fn click_text(&mut self, path: &str, text: &str) {
+        self.click_at(self.text_rect(path, text).center());
+    }
+
+    /// Returns `true` if the text is painted anywhere on the screen.
+    pub fn has_any_text(&self, text: &str) -> bool {
+        self.texts.iter().any(|painted| painted.text == text)
+    }
+
+    /// Returns rect of the text painted anywhere on the screen,
+    /// like an item of a menu or a combo box popup.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such text.
+    pub fn any_text_rect(&self, text: &str) -> egui::Rect {
+        match self.texts.iter().rev().find(|painted| painted.text == text) {
+            Some(painted) => painted.rect,
+            None => panic!(
+                "No text `{text}` is shown. Shown texts: {:?}",
+                self.texts
+                    .iter()
+                    .map(|painted| &painted.text)
+                    .collect::<Vec<_>>()
+            ),
+        }
+    }
+
+    /// Clicks text painted anywhere on the screen.
+    ///
+    /// See [`ProbeHarness::any_text_rect`].
+    pub fn click_any_text(&mut self, text: &str) {
+        self.click_at(self.any_text_rect(text).center());
+    }
+
+    /// Expands or collapses the row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row has no inner values.
+    pub fn toggle(&mut self, path: &str) {
+        match self.row(path).collapse_rect {
+            Some(rect) => self.click_at(rect.center()),
+            None => panic!("Row `{path}` has no inner values"),
+        }
+    }
+
+    /// Expands the row if it is collapsed.
+    pub fn expand(&mut self, path: &str) {
+        if self.row(path).open == Some(false) {
+            self.toggle(path);
+        }
+    }
+
+    /// Expands all rows recursively.
+    pub fn expand_all(&mut self) {
+        while let Some(path) = self
+            .rows
+            .iter()
+            .find(|row| row.open == Some(false))
+            .map(|row| row.path.clone())
+        {
+            self.toggle(&path);
+            assert_eq!(
+                self.row(&path).open,
+                Some(true),
+                "Row `{path}` is not expanded"
+            );
+        }
+    }
+
+    /// Presses and releases the key, delivering it to the focused widget.
+    pub fn press_key(&mut self, key: egui::Key) {
+        self.begin_input();
+        self.key(key, egui::Modifiers::NONE);
+        self.run();
+    }
+
+    /// Presses and releases the keyboard shortcut.
+    pub fn press_shortcut(&mut self, shortcut: egui::KeyboardShortcut) {
+        self.begin_input();
+        self.key(shortcut.logical_key, shortcut.modifiers);
+        self.run();
+    }
+
+    /// Pastes text from the system clipboard while hovering the row label.
+    pub fn paste(&mut self, path: &str, text: &str) {
+        self.begin_input();
+
+        self.events.push(egui::Event::PointerMoved(
+            self.row(path).label_rect.center(),
+        ));
+        self.step();
+        self.events.push(egui::Event::Paste(text.to_owned()));
+        self.run();
+    }
+
+    /// Focuses widget at the position on the screen,
+    /// replaces its text and removes focus.
+    ///
+    /// Works with text fields and drag values.
+    pub fn type_text_at(&mut self, pos: egui::Pos2, text: &str) {
+        self.click_at(pos);
+
+        self.key(egui::Key::A, egui::Modifiers::COMMAND);
+        self.events.push(egui::Event::Text(text.to_owned()));
+        self.step();
+
+        self.key(egui::Key::Escape, egui::Modifiers::NONE);
+        self.run();
+    }
+
+    /// Types text into the first widget in the value column of the row.
+    ///
+    /// See [`ProbeHarness::type_text_at`].
+    pub fn type_text(&mut self, path: &str, text: &str) {
+        self.type_text_at(self.value_pos(path), text);
+    }
+
+    /// Drags from one position on the screen to another.
+    pub fn drag_at(&mut self, from: egui::Pos2, to: egui::Pos2) {
+        const STEPS: usize = 4;
+
+        self.begin_input();
+
+        self.events.push(egui::Event::PointerMoved(from));
+        self.step();
+        self.pointer_button(from, egui::PointerButton::Primary, true);
+        self.step();
+
+        for i in 1..=STEPS {
+            let pos = from.lerp(to, i as f32 / STEPS as f32);
+            self.events.push(egui::Event::PointerMoved(pos));
+            self.step();
+        }
+
+        self.pointer_button(to, egui::PointerButton::Primary, false);
+        self.run();
+    }
+
+    /// Drags the first widget in the value column of the row by `delta`.
+    pub fn drag(&mut self, path: &str, delta: egui::Vec2) {
+        let from = self.value_pos(path);
+        self.drag_at(from, from + delta);
+    }
+
+    /// Drags the first widget in the value column of the row
+    /// onto the value column of another row.
+    pub fn drag_to(&mut self, path: &str, target: &str) {
+        let from = self.value_pos(path);
+        let to = self.row(target).value_rect.center();
+        self.drag_at(from, to);
+    }
+}
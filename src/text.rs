@@ -1,15 +1,43 @@
-use crate::{option::option_probe_with, EguiProbe, Style};
+use std::borrow::Cow;
+
+use crate::{option::option_probe_with, ClipboardValue, EguiProbe, MultiValue, Style};
 
 impl EguiProbe for String {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        if style.read_only {
+            return ui.label(self.as_str());
+        }
         ui.add(egui::TextEdit::singleline(self))
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
 
 impl EguiProbe for &str {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        if style.read_only {
+            return ui.label(*self);
+        }
+        ui.add(egui::TextEdit::singleline(self))
+    }
+}
+
+impl EguiProbe for Cow<'_, str> {
+    #[inline(always)]
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        if style.read_only {
+            return ui.label(&**self);
+        }
         ui.add(egui::TextEdit::singleline(self))
     }
 }
@@ -21,32 +49,48 @@ pub struct EguiProbeMultiline<'a, T> {
 
 impl EguiProbe for EguiProbeMultiline<'_, String> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
-        ui.add(egui::TextEdit::multiline(self.string))
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        multiline_probe(self.string, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.string)
     }
 }
 
 impl EguiProbe for EguiProbeMultiline<'_, &str> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
-        ui.add(egui::TextEdit::multiline(self.string))
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        multiline_probe(self.string, ui, style)
     }
 }
 
 impl EguiProbe for EguiProbeMultiline<'_, Option<String>> {
     #[inline(always)]
     fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
-        option_probe_with(self.string, ui, style, |string, ui, _| {
-            ui.add(egui::TextEdit::multiline(string));
+        option_probe_with(self.string, ui, style, |string, ui, style| {
+            multiline_probe(string, ui, style)
         })
     }
 }
 
 impl EguiProbe for EguiProbeMultiline<'_, Option<&str>> {
     #[inline(always)]
-    fn probe(&mut self, ui: &mut egui::Ui, _: &Style) -> egui::Response {
-        option_probe_with(self.string, ui, &Style::default(), |string, ui, _| {
-            ui.add(egui::TextEdit::multiline(string));
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        option_probe_with(self.string, ui, style, |string, ui, style| {
+            multiline_probe(string, ui, style)
         })
     }
 }
+
+fn multiline_probe(
+    string: &mut dyn egui::TextBuffer,
+    ui: &mut egui::Ui,
+    style: &Style,
+) -> egui::Response {
+    if style.read_only {
+        return ui.label(string.as_str());
+    }
+    ui.add(egui::TextEdit::multiline(string))
+}
@@ -0,0 +1,151 @@
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::{
+    datetime::{
+        date_time_probe, format_offset, parse_date, parse_date_time, parse_time, CivilDate,
+        CivilTime, DateTimeValue, ParsedDateTime,
+    },
+    EguiProbe, MultiValue, Style,
+};
+
+fn civil_date(date: Date) -> CivilDate {
+    CivilDate {
+        year: date.year(),
+        month: u8::from(date.month()).into(),
+        day: date.day().into(),
+    }
+}
+
+fn time_date(date: CivilDate) -> Result<Date, String> {
+    // Parsed dates have valid months and days, only the year may be out of range.
+    let month = Month::try_from(date.month as u8).map_err(|err| err.to_string())?;
+    Date::from_calendar_date(date.year, month, date.day as u8).map_err(|err| err.to_string())
+}
+
+fn civil_time(time: Time) -> CivilTime {
+    CivilTime {
+        hour: time.hour().into(),
+        minute: time.minute().into(),
+        second: time.second().into(),
+        nanosecond: time.nanosecond(),
+    }
+}
+
+fn time_time(time: CivilTime) -> Result<Time, String> {
+    Time::from_hms_nano(
+        time.hour as u8,
+        time.minute as u8,
+        time.second as u8,
+        time.nanosecond,
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn primitive_date_time(parsed: &ParsedDateTime) -> Result<PrimitiveDateTime, String> {
+    Ok(PrimitiveDateTime::new(
+        time_date(parsed.date)?,
+        time_time(parsed.time)?,
+    ))
+}
+
+impl DateTimeValue for Date {
+    fn format(&self) -> String {
+        civil_date(*self).to_string()
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        time_date(parse_date(text)?)
+    }
+
+    fn calendar_date(&self) -> Option<CivilDate> {
+        Some(civil_date(*self))
+    }
+
+    fn with_calendar_date(&self, date: CivilDate) -> Option<Self> {
+        time_date(date).ok()
+    }
+}
+
+impl DateTimeValue for Time {
+    fn format(&self) -> String {
+        civil_time(*self).to_string()
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        time_time(parse_time(text)?)
+    }
+}
+
+impl DateTimeValue for PrimitiveDateTime {
+    fn format(&self) -> String {
+        format!("{} {}", civil_date(self.date()), civil_time(self.time()))
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        let parsed = parse_date_time(text)?;
+        if parsed.offset.is_some() {
+            return Err("primitive date and time has no offset".to_owned());
+        }
+        primitive_date_time(&parsed)
+    }
+
+    fn calendar_date(&self) -> Option<CivilDate> {
+        Some(civil_date(self.date()))
+    }
+
+    fn with_calendar_date(&self, date: CivilDate) -> Option<Self> {
+        Some(PrimitiveDateTime::new(time_date(date).ok()?, self.time()))
+    }
+}
+
+/// Date and time are shown at the offset of the value, followed by the offset.
+///
+/// Text without offset keeps offset of the value.
+impl DateTimeValue for OffsetDateTime {
+    fn format(&self) -> String {
+        format!(
+            "{} {} {}",
+            civil_date(self.date()),
+            civil_time(self.time()),
+            format_offset(self.offset().whole_seconds())
+        )
+    }
+
+    fn parse(&self, text: &str) -> Result<Self, String> {
+        let parsed = parse_date_time(text)?;
+        let offset = match parsed.offset {
+            None => self.offset(),
+            Some(offset) => UtcOffset::from_whole_seconds(offset).map_err(|err| err.to_string())?,
+        };
+        Ok(primitive_date_time(&parsed)?.assume_offset(offset))
+    }
+
+    fn calendar_date(&self) -> Option<CivilDate> {
+        Some(civil_date(self.date()))
+    }
+
+    fn with_calendar_date(&self, date: CivilDate) -> Option<Self> {
+        let date_time = PrimitiveDateTime::new(time_date(date).ok()?, self.time());
+        Some(date_time.assume_offset(self.offset()))
+    }
+}
+
+macro_rules! impl_for_types {
+    ($($ty:ident),*) => {
+        $(
+            impl EguiProbe for $ty {
+                #[inline(always)]
+                fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                    date_time_probe(self, ui, style)
+                }
+
+                #[inline(always)]
+                fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_types!(Date, Time, PrimitiveDateTime, OffsetDateTime);
@@ -0,0 +1,30 @@
+use crate::EguiProbe;
+
+/// Modifier to show tooltip on hover over the label of the value.
+pub struct EguiProbeTooltip<'a, T: ?Sized> {
+    pub value: &'a mut T,
+    pub tooltip: &'a str,
+}
+
+impl<T> EguiProbe for EguiProbeTooltip<'_, T>
+where
+    T: EguiProbe + ?Sized,
+{
+    #[inline(always)]
+    fn tooltip(&mut self) -> Option<&str> {
+        Some(self.tooltip)
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        probe,
+        has_inner,
+        iterate_inner,
+        iterate_inner_range,
+        validate,
+        as_clipboard_value,
+        as_text_value,
+        as_default_value,
+        as_multi_value,
+    );
+}
@@ -1,6 +1,6 @@
 use egui::{epaint::Shadow, Frame, Margin, Rounding, Stroke};
 
-use crate::{num::non_negative, EguiProbe};
+use crate::{num::non_negative, ClipboardValue, EguiProbe, MultiValue};
 
 impl EguiProbe for Stroke {
     fn probe(&mut self, ui: &mut egui::Ui, _style: &crate::Style) -> egui::Response {
@@ -17,6 +17,16 @@ impl EguiProbe for Stroke {
         f("color", &mut self.color);
         f("width", &mut non_negative(&mut self.width));
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
 
 impl EguiProbe for Margin {
@@ -37,6 +47,16 @@ impl EguiProbe for Margin {
         f("bottom", &mut non_negative(&mut self.bottom));
         f("right", &mut non_negative(&mut self.right));
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
 
 impl EguiProbe for Rounding {
@@ -57,6 +77,16 @@ impl EguiProbe for Rounding {
         f("sw", &mut non_negative(&mut self.sw));
         f("se", &mut non_negative(&mut self.se));
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
 
 impl EguiProbe for Shadow {
@@ -74,6 +104,16 @@ impl EguiProbe for Shadow {
         f("extrusion", &mut non_negative(&mut self.extrusion));
         f("color", &mut self.color);
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
 
 impl EguiProbe for Frame {
@@ -96,4 +136,14 @@ impl EguiProbe for Frame {
         f("fill", &mut self.fill);
         f("stroke", &mut self.stroke);
     }
+
+    #[inline(always)]
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
 }
@@ -0,0 +1,134 @@
+use crate::{
+    path::{is_within, join_path},
+    probe_path_mut, EguiProbe,
+};
+
+/// Severity of the validation issue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Returns color used to highlight values with issue of this severity.
+    pub fn color(&self, visuals: &egui::Visuals) -> egui::Color32 {
+        match self {
+            Severity::Warning => visuals.warn_fg_color,
+            Severity::Error => visuals.error_fg_color,
+        }
+    }
+}
+
+/// Issue reported by [`EguiProbe::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Validation {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Validation {
+    pub fn error(message: impl Into<String>) -> Self {
+        Validation {
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Validation {
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<String> for Validation {
+    #[inline(always)]
+    fn from(message: String) -> Self {
+        Validation::error(message)
+    }
+}
+
+impl From<&str> for Validation {
+    #[inline(always)]
+    fn from(message: &str) -> Self {
+        Validation::error(message)
+    }
+}
+
+/// Validation issue of the value with the given path.
+///
+/// See [`crate::probe_path_mut`] for path format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub path: String,
+    pub validation: Validation,
+}
+
+/// Validates the value and all its inner values recursively.
+pub fn validation_issues(value: &mut dyn EguiProbe) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    collect_issues(value, "", &mut issues);
+    issues
+}
+
+/// Replaces issues of the changed values and their inner values with fresh ones.
+pub(crate) fn revalidate_changed(
+    value: &mut dyn EguiProbe,
+    changed_paths: &[String],
+    issues: &mut Vec<ValidationIssue>,
+) {
+    for path in changed_paths {
+        issues.retain(|issue| !is_within(&issue.path, path));
+        probe_path_mut(value, path, |value| collect_issues(value, path, issues));
+    }
+}
+
+fn collect_issues(value: &mut dyn EguiProbe, path: &str, issues: &mut Vec<ValidationIssue>) {
+    if let Err(validation) = value.validate() {
+        issues.push(ValidationIssue {
+            path: path.to_owned(),
+            validation,
+        });
+    }
+
+    if value.has_inner() {
+        value.iterate_inner(&mut |label, value| {
+            collect_issues(value, &join_path(path, label), issues);
+        });
+    }
+}
+
+/// Modifier to report issues found by a validator function.
+///
+/// Validator receives the wrapped probe and is called only when the value is validated.
+pub struct EguiProbeValidated<'a, T: ?Sized, F> {
+    pub value: &'a mut T,
+    pub validator: F,
+}
+
+impl<T, F> EguiProbe for EguiProbeValidated<'_, T, F>
+where
+    T: EguiProbe + ?Sized,
+    F: Fn(&T) -> Result<(), Validation>,
+{
+    #[inline(always)]
+    fn validate(&mut self) -> Result<(), Validation> {
+        (self.validator)(self.value)?;
+        self.value.validate()
+    }
+
+    forward_probe_methods!(
+        |this| this.value =>
+        probe,
+        has_inner,
+        iterate_inner,
+        iterate_inner_range,
+        tooltip,
+        as_clipboard_value,
+        as_text_value,
+        as_default_value,
+        as_multi_value,
+    );
+}
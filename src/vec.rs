@@ -1,7 +1,10 @@
+use std::ops::Range;
+
 use crate::{
-    collections::{DeleteMe, EguiProbeFrozen},
+    collections::{iterate_sequence, iterate_slice_range, EguiProbeFrozen, Sequence},
+    hex::{hex_probe, EguiProbeHex, HexBytes},
     option::option_probe_with,
-    EguiProbe,
+    EguiProbe, MultiValue,
 };
 
 impl<T> EguiProbe for Vec<T>
@@ -10,13 +13,18 @@ where
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
         ui.horizontal(|ui| {
-            ui.weak(format!("[{}]", self.len()));
-            let r = ui.small_button(style.add_button_text());
-            if r.clicked() {
+            let r = ui.weak(format!("[{}]", self.len()));
+            if style.read_only {
+                return r;
+            }
+            let mut add = ui.small_button(style.add_button_text());
+            if add.clicked() {
                 self.push(T::default());
+                add.mark_changed();
             }
+            r | add
         })
-        .response
+        .inner
     }
 
     fn has_inner(&mut self) -> bool {
@@ -24,16 +32,38 @@ where
     }
 
     fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
-        let mut idx = 0;
-        self.retain_mut(|value| {
-            let mut item = DeleteMe {
-                value,
-                delete: false,
-            };
-            f(&format!("[{idx}]"), &mut item);
-            idx += 1;
-            !item.delete
-        });
+        iterate_sequence(self, 0..usize::MAX, None, f);
+    }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_sequence(self, range, None, f)
+    }
+}
+
+impl<T> Sequence for Vec<T> {
+    type Item = T;
+
+    fn sequence_len(&self) -> usize {
+        self.len()
+    }
+
+    fn for_each_item(&mut self, range: Range<usize>, f: &mut dyn FnMut(usize, &mut T)) {
+        let start = range.start;
+        for (i, value) in self[range].iter_mut().enumerate() {
+            f(start + i, value);
+        }
+    }
+
+    fn insert_item(&mut self, idx: usize, item: T) {
+        self.insert(idx, item);
+    }
+
+    fn remove_item(&mut self, idx: usize) -> T {
+        self.remove(idx)
     }
 }
 
@@ -54,6 +84,14 @@ where
             f(&format!("[{i}]"), value);
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        iterate_slice_range(self.value, range, f)
+    }
 }
 
 impl<T> EguiProbe for EguiProbeFrozen<'_, Option<Vec<T>>>
@@ -62,7 +100,7 @@ where
 {
     fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
         option_probe_with(self.value, ui, style, |value, ui, _style| {
-            ui.weak(format!("[{}]", value.len()));
+            ui.weak(format!("[{}]", value.len()))
         })
     }
 
@@ -80,4 +118,40 @@ where
             }
         }
     }
+
+    fn iterate_inner_range(
+        &mut self,
+        range: Range<usize>,
+        f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+    ) -> usize {
+        match self.value {
+            Some(vec) => iterate_slice_range(vec, range, f),
+            None => 0,
+        }
+    }
+}
+
+impl HexBytes for Vec<u8> {
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        self
+    }
+
+    fn can_push(&self) -> bool {
+        true
+    }
+
+    fn push_byte(&mut self, byte: u8) {
+        self.push(byte);
+    }
+}
+
+impl EguiProbe for EguiProbeHex<'_, Vec<u8>> {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &crate::Style) -> egui::Response {
+        hex_probe(self.value, ui, style)
+    }
+
+    #[inline(always)]
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self.value)
+    }
 }
@@ -1,6 +1,70 @@
-use core::hash::Hash;
+use core::{
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+use std::collections::{hash_map::DefaultHasher, HashSet};
 
-use crate::{EguiProbe, Style};
+use crate::{
+    multi::{mixed_paths, mixed_probe, propagate, Mixed},
+    path::join_path,
+    validate::{revalidate_changed, validation_issues, Severity, ValidationIssue},
+    EguiProbe, Style,
+};
+
+/// Parts of a row shown by [`row_ui`].
+#[cfg_attr(not(feature = "testing"), allow(dead_code))]
+pub(crate) struct RowParts {
+    /// Whether the row is expanded, if it has inner values.
+    pub open: Option<bool>,
+    pub collapse: Option<egui::Rect>,
+    pub label: egui::Rect,
+    pub value: egui::Rect,
+}
+
+/// Shows a row horizontally.
+///
+/// `add_contents` fills parts of the row it shows,
+/// the row is then recorded for [`crate::testing::ProbeHarness`].
+#[cfg_attr(not(feature = "testing"), allow(unused_variables))]
+pub(crate) fn row_ui<R>(
+    ui: &mut egui::Ui,
+    path: &str,
+    label: &str,
+    indent: usize,
+    add_contents: impl FnOnce(&mut egui::Ui, &mut RowParts) -> R,
+) -> egui::InnerResponse<R> {
+    ui.horizontal(|ui| {
+        let mut parts = RowParts {
+            open: None,
+            collapse: None,
+            label: egui::Rect::NOTHING,
+            value: egui::Rect::NOTHING,
+        };
+        let inner = add_contents(ui, &mut parts);
+
+        #[cfg(feature = "testing")]
+        crate::testing::record_row(
+            ui.ctx(),
+            crate::testing::Row {
+                path: path.to_owned(),
+                label: label.to_owned(),
+                indent,
+                open: parts.open,
+                rect: parts
+                    .label
+                    .union(parts.value)
+                    .union(parts.collapse.unwrap_or(egui::Rect::NOTHING)),
+                label_rect: parts.label,
+                collapse_rect: parts.collapse,
+                value_rect: parts.value,
+                texts: Vec::new(),
+                label_texts: Vec::new(),
+            },
+        );
+
+        inner
+    })
+}
 
 #[derive(Clone, Copy)]
 struct ProbeHeaderState {
@@ -8,7 +72,7 @@ struct ProbeHeaderState {
     body_height: f32,
 }
 
-struct ProbeHeader {
+pub(crate) struct ProbeHeader {
     id: egui::Id,
     state: ProbeHeaderState,
     dirty: bool,
@@ -17,11 +81,16 @@ struct ProbeHeader {
 
 impl ProbeHeader {
     fn load(cx: &egui::Context, id: egui::Id) -> ProbeHeader {
+        ProbeHeader::load_or(cx, id, false)
+    }
+
+    /// Loads header state, using `open` if the header was never shown.
+    pub(crate) fn load_or(cx: &egui::Context, id: egui::Id, open: bool) -> ProbeHeader {
         let state = cx.data_mut(|d| {
             *d.get_temp_mut_or(
                 id,
                 ProbeHeaderState {
-                    open: false,
+                    open,
                     body_height: 0.0,
                 },
             )
@@ -37,7 +106,7 @@ impl ProbeHeader {
         }
     }
 
-    fn store(self, cx: &egui::Context) {
+    pub(crate) fn store(self, cx: &egui::Context) {
         if self.dirty {
             cx.data_mut(|d| d.insert_temp(self.id, self.state));
             cx.request_repaint();
@@ -49,9 +118,16 @@ impl ProbeHeader {
         self.dirty = true;
     }
 
-    // fn is_open(&self) -> bool {
-    //     self.state.open
-    // }
+    pub(crate) fn is_open(&self) -> bool {
+        self.state.open
+    }
+
+    fn open(&mut self) {
+        if !self.state.open {
+            self.state.open = true;
+            self.dirty = true;
+        }
+    }
 
     fn set_body_height(&mut self, height: f32) {
         // TODO: Better approximation
@@ -65,7 +141,7 @@ impl ProbeHeader {
         (1.0 - self.openness) * self.state.body_height
     }
 
-    fn collapse_button(&mut self, ui: &mut egui::Ui) -> egui::Response {
+    pub(crate) fn collapse_button(&mut self, ui: &mut egui::Ui) -> egui::Response {
         let desired_size = ui.spacing().icon_width_inner;
         let response =
             ui.allocate_response(egui::vec2(desired_size, desired_size), egui::Sense::click());
@@ -79,6 +155,64 @@ impl ProbeHeader {
     }
 }
 
+/// Heights of table rows together with their shown inner rows,
+/// as measured when rows were last visible.
+#[derive(Clone, Default)]
+struct ProbeRows {
+    heights: Vec<f32>,
+
+    // Whether number of rows is known.
+    measured: bool,
+}
+
+impl ProbeRows {
+    /// Returns range of rows that intersect the visible span of the table starting at `top`
+    /// and total height of rows before the range.
+    fn visible(
+        &self,
+        top: f32,
+        span: Range<f32>,
+        row_height: f32,
+        limit: usize,
+    ) -> (Range<usize>, f32) {
+        let limit = if self.measured {
+            limit.min(self.heights.len())
+        } else {
+            limit
+        };
+        let height = |idx: usize| self.heights.get(idx).copied().unwrap_or(row_height);
+
+        let mut y = top;
+        let mut start = 0;
+        while start < limit && y + height(start) < span.start {
+            y += height(start);
+            start += 1;
+        }
+
+        let skipped = y - top;
+        let mut end = start;
+        while end < limit && y <= span.end {
+            y += height(end);
+            end += 1;
+        }
+
+        (start..end, skipped)
+    }
+
+    /// Stores heights of rows measured from `start`
+    /// and returns total height of rows after them.
+    fn update(&mut self, start: usize, measured: &[f32], len: usize, row_height: f32) -> f32 {
+        self.heights.resize(len, row_height);
+        self.measured = true;
+
+        let end = (start + measured.len()).min(len);
+        if start < end {
+            self.heights[start..end].copy_from_slice(&measured[..end - start]);
+        }
+        self.heights[end..].iter().sum()
+    }
+}
+
 #[derive(Clone, Copy)]
 struct ProbeLayoutState {
     labels_width: f32,
@@ -92,7 +226,7 @@ pub struct ProbeLayout {
 }
 
 impl ProbeLayout {
-    fn load(cx: &egui::Context, id: egui::Id) -> ProbeLayout {
+    pub(crate) fn load(cx: &egui::Context, id: egui::Id) -> ProbeLayout {
         let state = cx.data_mut(|d| *d.get_temp_mut_or(id, ProbeLayoutState { labels_width: 0.0 }));
         ProbeLayout {
             id,
@@ -102,7 +236,7 @@ impl ProbeLayout {
         }
     }
 
-    fn store(mut self, cx: &egui::Context) {
+    pub(crate) fn store(mut self, cx: &egui::Context) {
         if self.dirty {
             self.state.labels_width = self.min_labels_width;
             cx.data_mut(|d| d.insert_temp(self.id, self.state));
@@ -171,16 +305,58 @@ impl ProbeLayout {
     }
 }
 
+/// Response of the [`Probe`] widget.
+pub struct ProbeResponse {
+    /// Response of the whole probe widget.
+    /// It is marked as changed if any value was changed.
+    pub response: egui::Response,
+
+    /// Paths of the values that were changed this frame.
+    ///
+    /// Path consists of labels from the root value to the changed one,
+    /// e.g. `inner.multi_line` or `vector[2]`.
+    /// Root value itself has empty path.
+    pub changed_paths: Vec<String>,
+
+    /// Validation issues of the value and all its inner values,
+    /// including ones that are not shown.
+    ///
+    /// Only issues of shown values and values changed this frame are reported
+    /// if [`Probe::validate_hidden`] is disabled.
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ProbeResponse {
+    /// Returns `true` if any value was changed this frame.
+    #[inline]
+    pub fn changed(&self) -> bool {
+        !self.changed_paths.is_empty()
+    }
+
+    /// Returns `true` if there are validation issues with error severity.
+    #[inline]
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.validation.severity == Severity::Error)
+    }
+}
+
 /// Widget for editing a value via `EguiProbe` trait.
 ///
 /// For simple values it will show a probe UI for it.
 /// For complex values it will header with collapsible body.
 #[must_use = "You should call .show()"]
 pub struct Probe<'a, T> {
-    id_source: egui::Id,
-    label: egui::WidgetText,
-    style: Style,
-    value: &'a mut T,
+    pub(crate) id_source: egui::Id,
+    pub(crate) label: egui::WidgetText,
+    pub(crate) style: Style,
+    pub(crate) value: &'a mut T,
+    pub(crate) filter: bool,
+    pub(crate) filter_descendants: bool,
+    pub(crate) validate_hidden: bool,
+    #[cfg(feature = "serde")]
+    pub(crate) as_text: Option<fn(&mut T) -> &mut dyn crate::TextValue>,
 }
 
 impl<'a, T> Probe<'a, T>
@@ -195,73 +371,656 @@ where
             label,
             style: Style::default(),
             value,
+            filter: false,
+            filter_descendants: true,
+            validate_hidden: true,
+            #[cfg(feature = "serde")]
+            as_text: None,
+        }
+    }
+
+    /// Shows values without allowing to edit them.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.style.read_only = read_only;
+        self
+    }
+
+    /// Shows text field to filter rows by label.
+    ///
+    /// Rows with labels that do not contain the filter text are hidden.
+    /// Matched parts of labels are highlighted.
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Controls whether rows with descendants matching the filter are shown.
+    ///
+    /// Such rows are expanded automatically to reveal matched descendants.
+    /// Enabled by default.
+    pub fn filter_descendants(mut self, filter_descendants: bool) -> Self {
+        self.filter_descendants = filter_descendants;
+        self
+    }
+
+    /// Controls whether values in collapsed, paged-out and scrolled-out rows are validated.
+    ///
+    /// When disabled, [`ProbeResponse::issues`] has issues of shown values
+    /// and of values changed this frame only,
+    /// so that large values are not walked every frame.
+    /// Enabled by default.
+    pub fn validate_hidden(mut self, validate_hidden: bool) -> Self {
+        self.validate_hidden = validate_hidden;
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T> Probe<'a, T>
+where
+    T: EguiProbe + crate::TextValue,
+{
+    /// Shows toggle in the header to edit the whole value as RON or JSON text.
+    ///
+    /// Value is replaced only when edited text is parsed successfully.
+    pub fn text_toggle(mut self, text_toggle: bool) -> Self {
+        fn as_text<T: crate::TextValue>(value: &mut T) -> &mut dyn crate::TextValue {
+            value
         }
+
+        self.as_text = if text_toggle {
+            Some(as_text::<T>)
+        } else {
+            None
+        };
+        self
     }
+}
 
+impl<'a, T> Probe<'a, T>
+where
+    T: EguiProbe,
+{
     /// Show probbing UI to edit the value.
-    pub fn show(self, ui: &mut egui::Ui) -> egui::Response {
+    pub fn show(self, ui: &mut egui::Ui) -> ProbeResponse {
+        self.show_with_mixed(ui, None)
+    }
+
+    /// Shows probbing UI with values differing between instances
+    /// edited by [`ProbeMulti`] hidden.
+    pub(crate) fn show_with_mixed(
+        self,
+        ui: &mut egui::Ui,
+        mut mixed: Option<&mut Mixed>,
+    ) -> ProbeResponse {
         if !self.value.has_inner() {
-            return self.value.probe(ui, &self.style);
+            let response = row_ui(ui, "", self.label.text(), 0, |ui, parts| {
+                let response = mixed_probe(self.value, ui, &self.style, mixed.as_deref_mut(), "");
+                parts.value = response.rect;
+                response
+            })
+            .inner;
+
+            let changed_paths = if response.changed() {
+                vec![String::new()]
+            } else {
+                Vec::new()
+            };
+            let issues = self
+                .value
+                .validate()
+                .err()
+                .map(|validation| ValidationIssue {
+                    path: String::new(),
+                    validation,
+                })
+                .into_iter()
+                .collect();
+            return ProbeResponse {
+                response,
+                changed_paths,
+                issues,
+            };
         }
 
-        ui.allocate_ui(ui.available_size(), |ui| {
-            let ref mut child_ui = ui.child_ui_with_id_source(
-                ui.max_rect(),
-                egui::Layout::top_down(egui::Align::Min),
-                self.id_source,
-            );
-
-            let mut header =
-                ProbeHeader::load(child_ui.ctx(), child_ui.make_persistent_id("probe_header"));
-
-            egui::Frame::none()
-                .fill(child_ui.visuals().extreme_bg_color)
-                .inner_margin(child_ui.spacing().item_spacing * 0.5)
-                .show(child_ui, |child_ui| {
-                    child_ui.horizontal(|child_ui| {
-                        header.collapse_button(child_ui);
-                        child_ui.label(self.label);
-                    });
-                });
-
-            if header.openness > 0.0 && self.value.has_inner() {
-                let mut layout =
-                    ProbeLayout::load(child_ui.ctx(), child_ui.make_persistent_id("probe_layout"));
-
-                show_table(
-                    self.value,
-                    &mut header,
-                    &mut layout,
-                    0,
-                    child_ui,
-                    &self.style,
-                    "table",
+        let mut changed_paths = Vec::new();
+        let mut issues = Vec::new();
+
+        let mut response = ui
+            .allocate_ui(ui.available_size(), |ui| {
+                let child_ui = &mut ui.child_ui_with_id_source(
+                    ui.max_rect(),
+                    egui::Layout::top_down(egui::Align::Min),
+                    self.id_source,
                 );
 
-                layout.store(child_ui.ctx());
+                let mut header =
+                    ProbeHeader::load(child_ui.ctx(), child_ui.make_persistent_id("probe_header"));
+
+                let filter_id = child_ui.make_persistent_id("probe_filter");
+                let mut filter = String::new();
+                if self.filter {
+                    filter = child_ui
+                        .ctx()
+                        .data_mut(|d| d.get_temp(filter_id))
+                        .unwrap_or_default();
+                }
+
+                let defaults_id = child_ui.make_persistent_id("probe_defaults");
+
+                #[cfg(feature = "serde")]
+                let text_mode_id = child_ui.make_persistent_id("probe_text_mode");
+                #[cfg(feature = "serde")]
+                let mut text_mode = self.as_text.is_some()
+                    && child_ui
+                        .ctx()
+                        .data_mut(|d| d.get_temp(text_mode_id))
+                        .unwrap_or(false);
+
+                egui::Frame::none()
+                    .fill(child_ui.visuals().extreme_bg_color)
+                    .inner_margin(child_ui.spacing().item_spacing * 0.5)
+                    .show(child_ui, |child_ui| {
+                        let label = self.label.text().to_owned();
+                        row_ui(child_ui, "", &label, 0, |child_ui, parts| {
+                            parts.collapse = Some(header.collapse_button(child_ui).rect);
+
+                            let label = match self.value.validate() {
+                                Ok(()) => child_ui.label(self.label),
+                                Err(validation) => {
+                                    let color = validation.severity.color(child_ui.visuals());
+                                    let r = child_ui.label(self.label.color(color));
+                                    child_ui.colored_label(color, &validation.message);
+                                    issues.push(ValidationIssue {
+                                        path: String::new(),
+                                        validation,
+                                    });
+                                    r
+                                }
+                            };
+
+                            parts.label = label.rect;
+
+                            #[cfg(feature = "serde")]
+                            if self.as_text.is_some()
+                                && child_ui.toggle_value(&mut text_mode, "Text").changed()
+                            {
+                                child_ui
+                                    .ctx()
+                                    .data_mut(|d| d.insert_temp(text_mode_id, text_mode));
+                                header.open();
+                            }
+
+                            crate::default::reset_all_button(
+                                self.value,
+                                child_ui,
+                                &self.style,
+                                defaults_id,
+                                &mut changed_paths,
+                            );
+
+                            if self.filter {
+                                child_ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |child_ui| {
+                                        let text_edit = egui::TextEdit::singleline(&mut filter)
+                                            .hint_text("filter");
+                                        if child_ui.add(text_edit).changed() {
+                                            child_ui.ctx().data_mut(|d| {
+                                                d.insert_temp(filter_id, filter.clone())
+                                            });
+                                        }
+                                    },
+                                );
+                            }
+
+                            parts.open = Some(header.state.open);
+                        });
+                    });
+
+                let matches = if filter.is_empty() {
+                    FilterMatches::default()
+                } else {
+                    FilterMatches::load(child_ui.ctx(), filter_id, &filter, self.value)
+                };
+                if matches.ancestors.contains("") {
+                    header.open();
+                }
+
+                // Value edited as text replaces the table.
+                #[cfg(feature = "serde")]
+                let text_shown = match self.as_text.filter(|_| text_mode) {
+                    None => false,
+                    Some(as_text) => {
+                        if header.state.open {
+                            let response = crate::serde_text::text_value_ui(
+                                as_text(self.value),
+                                child_ui,
+                                &self.style,
+                                child_ui.make_persistent_id("probe_text"),
+                            );
+                            if response.changed() {
+                                changed_paths.push(String::new());
+                            }
+                        }
+                        true
+                    }
+                };
+                #[cfg(not(feature = "serde"))]
+                let text_shown = false;
+
+                if !text_shown && header.openness > 0.0 && self.value.has_inner() {
+                    let mut table = ProbeTable {
+                        layout: ProbeLayout::load(
+                            child_ui.ctx(),
+                            child_ui.make_persistent_id("probe_layout"),
+                        ),
+                        style: &self.style,
+                        changed_paths: &mut changed_paths,
+                        issues: &mut issues,
+                        filter: &filter,
+                        filter_ancestors: &matches.ancestors,
+                        filtering: !filter.is_empty(),
+                        filter_descendants: self.filter_descendants,
+                        mixed,
+                    };
+
+                    show_table(
+                        self.value,
+                        &mut header,
+                        &mut table,
+                        "",
+                        0,
+                        child_ui,
+                        "table",
+                    );
+
+                    table.layout.store(child_ui.ctx());
+                }
+
+                header.store(child_ui.ctx());
+
+                // Edits may add or remove values with defaults.
+                if !changed_paths.is_empty() {
+                    crate::default::forget_defaults(child_ui.ctx(), defaults_id);
+                }
+
+                if !filter.is_empty() {
+                    matches.store(child_ui.ctx(), filter_id);
+                }
+
+                let final_rect = child_ui.min_rect();
+                ui.advance_cursor_after_rect(final_rect);
+
+                // let response = ui.interact(final_rect, child_ui.id(), egui::Sense::hover());
+                // response.widget_info(|| egui::WidgetInfo::new(egui::WidgetType::Other));
+
+                // response
+            })
+            .response;
+
+        if !changed_paths.is_empty() {
+            response.mark_changed();
+        }
+
+        if self.validate_hidden {
+            issues = validation_issues(self.value);
+        } else {
+            // Shown rows were validated before they were edited.
+            revalidate_changed(self.value, &changed_paths, &mut issues);
+        }
+
+        ProbeResponse {
+            response,
+            changed_paths,
+            issues,
+        }
+    }
+}
+
+/// Widget for editing several values of the same type together.
+///
+/// Shows a single table for the first value.
+/// Values that differ between instances are shown as "—" until clicked.
+/// Edits are written to all instances.
+///
+/// Differing values are found by comparing instances every frame,
+/// unless [`ProbeMulti::generation`] is set.
+#[must_use = "You should call .show()"]
+pub struct ProbeMulti<'a, 'b, T> {
+    id_source: egui::Id,
+    label: egui::WidgetText,
+    style: Style,
+    values: &'a mut [&'b mut T],
+    filter: bool,
+    filter_descendants: bool,
+    validate_hidden: bool,
+    generation: Option<egui::Id>,
+}
+
+impl<'a, 'b, T> ProbeMulti<'a, 'b, T>
+where
+    T: EguiProbe,
+{
+    /// Creates a new `ProbeMulti` widget.
+    pub fn new(label: impl Into<egui::WidgetText>, values: &'a mut [&'b mut T]) -> Self {
+        let label = label.into();
+        ProbeMulti {
+            id_source: egui::Id::new(label.text()),
+            label,
+            style: Style::default(),
+            values,
+            filter: false,
+            filter_descendants: true,
+            validate_hidden: true,
+            generation: None,
+        }
+    }
+
+    /// Shows values without allowing to edit them.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.style.read_only = read_only;
+        self
+    }
+
+    /// Shows text field to filter rows by label.
+    ///
+    /// See [`Probe::filter`].
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Controls whether rows with descendants matching the filter are shown.
+    ///
+    /// See [`Probe::filter_descendants`].
+    pub fn filter_descendants(mut self, filter_descendants: bool) -> Self {
+        self.filter_descendants = filter_descendants;
+        self
+    }
+
+    /// Controls whether values in rows that are not shown are validated.
+    ///
+    /// See [`Probe::validate_hidden`].
+    pub fn validate_hidden(mut self, validate_hidden: bool) -> Self {
+        self.validate_hidden = validate_hidden;
+        self
+    }
+
+    /// Reuses differing values found in previous frames with the same generation
+    /// instead of comparing instances every frame.
+    ///
+    /// Change the generation whenever instances are selected or modified outside the widget,
+    /// e.g. pass a counter incremented on every change.
+    /// Edits made with the widget are noticed regardless of the generation.
+    pub fn generation(mut self, generation: impl Hash) -> Self {
+        self.generation = Some(egui::Id::new(generation));
+        self
+    }
+
+    /// Show probbing UI to edit the values.
+    ///
+    /// Paths and issues of the response are of the first value.
+    pub fn show(self, ui: &mut egui::Ui) -> ProbeResponse {
+        let (first, rest) = match self.values.split_first_mut() {
+            None => {
+                let response = ui.weak(self.label.text());
+                return ProbeResponse {
+                    response,
+                    changed_paths: Vec::new(),
+                    issues: Vec::new(),
+                };
+            }
+            Some((first, rest)) => (&mut **first, rest),
+        };
+
+        let mut mixed = Mixed::load(
+            ui.ctx(),
+            ui.make_persistent_id((self.id_source, "probe_mixed")),
+            self.generation,
+            || mixed_paths(first, rest),
+        );
+
+        let probe = Probe {
+            id_source: self.id_source,
+            label: self.label,
+            style: self.style,
+            value: &mut *first,
+            filter: self.filter,
+            filter_descendants: self.filter_descendants,
+            validate_hidden: self.validate_hidden,
+            #[cfg(feature = "serde")]
+            as_text: None,
+        };
+        let response = probe.show_with_mixed(ui, Some(&mut mixed));
+
+        for path in &response.changed_paths {
+            propagate(first, rest, path);
+        }
+        if response.changed() {
+            mixed.update(mixed_paths(first, rest));
+        }
+
+        mixed.store(ui.ctx());
+
+        response
+    }
+}
+
+/// State shared by all rows of the probe table.
+struct ProbeTable<'a> {
+    layout: ProbeLayout,
+    style: &'a Style,
+    changed_paths: &'a mut Vec<String>,
+
+    // Validation issues of the shown rows.
+    issues: &'a mut Vec<ValidationIssue>,
+    filter: &'a str,
+
+    // Paths of values with inner values matching the filter.
+    filter_ancestors: &'a HashSet<String>,
+
+    // Whether rows are filtered at current level.
+    // Rows inside matched subtree are not filtered.
+    filtering: bool,
+    filter_descendants: bool,
+
+    // Values differing between instances edited by `ProbeMulti`.
+    mixed: Option<&'a mut Mixed>,
+}
+
+/// Finds first case-insensitive occurrence of the filter in the label.
+fn find_match(label: &str, filter: &str) -> Option<Range<usize>> {
+    if filter.is_empty() {
+        return None;
+    }
+
+    label.char_indices().find_map(|(start, _)| {
+        let mut chars = label[start..].char_indices();
+        let mut end = start;
+        for f in filter.chars() {
+            let (idx, c) = chars.next()?;
+            if !c.to_lowercase().eq(f.to_lowercase()) {
+                return None;
             }
+            end = start + idx + c.len_utf8();
+        }
+        Some(start..end)
+    })
+}
+
+/// Values matching the filter,
+/// collected again only when the filter text or labels of inner values change.
+#[derive(Clone, Default)]
+struct FilterMatches {
+    /// Hash of the filter text and labels of all inner values.
+    key: u64,
+
+    /// Paths of values that have inner values, recursively, with labels matching the filter.
+    /// Root value has empty path.
+    ancestors: HashSet<String>,
+}
+
+impl FilterMatches {
+    /// Loads matches collected in previous frames for the same filter and labels,
+    /// or collects them walking the value once.
+    ///
+    /// Values added, removed or renamed anywhere, e.g. by switching enum variant,
+    /// change the labels and so are noticed.
+    fn load(cx: &egui::Context, id: egui::Id, filter: &str, value: &mut dyn EguiProbe) -> Self {
+        let mut hasher = DefaultHasher::new();
+        filter.hash(&mut hasher);
+        hash_labels(value, &mut hasher);
+        let key = hasher.finish();
 
-            header.store(child_ui.ctx());
+        let id = id.with("matches");
+        match cx.data_mut(|d| d.remove_temp::<FilterMatches>(id)) {
+            Some(matches) if matches.key == key => matches,
+            _ => {
+                let mut ancestors = HashSet::new();
+                collect_ancestors(value, "", filter, &mut ancestors);
+                FilterMatches { key, ancestors }
+            }
+        }
+    }
+
+    fn store(self, cx: &egui::Context, id: egui::Id) {
+        cx.data_mut(|d| d.insert_temp(id.with("matches"), self));
+    }
+}
+
+/// Hashes labels of all inner values, recursively, with their nesting.
+fn hash_labels(value: &mut dyn EguiProbe, hasher: &mut DefaultHasher) {
+    value.iterate_inner(&mut |label, value| {
+        label.hash(hasher);
+        if value.has_inner() {
+            hasher.write_u8(1);
+            hash_labels(value, hasher);
+        }
+        hasher.write_u8(0);
+    });
+}
+
+/// Collects paths of values with inner values matching the filter.
+///
+/// Returns `true` if label of any inner value, recursively, matches the filter.
+fn collect_ancestors(
+    value: &mut dyn EguiProbe,
+    path: &str,
+    filter: &str,
+    ancestors: &mut HashSet<String>,
+) -> bool {
+    let mut found = false;
+    value.iterate_inner(&mut |label, value| {
+        let inner_path = join_path(path, label);
+        let inner_found =
+            value.has_inner() && collect_ancestors(value, &inner_path, filter, ancestors);
+        found |= inner_found || find_match(label, filter).is_some();
+    });
+    if found {
+        ancestors.insert(path.to_owned());
+    }
+    found
+}
+
+/// Shows label with highlighted range.
+fn highlighted_label(
+    ui: &mut egui::Ui,
+    label: &str,
+    range: Range<usize>,
+    color: Option<egui::Color32>,
+) -> egui::Response {
+    let format = egui::TextFormat {
+        font_id: egui::TextStyle::Body.resolve(ui.style()),
+        color: color.unwrap_or_else(|| ui.visuals().text_color()),
+        ..Default::default()
+    };
+    let highlight = egui::TextFormat {
+        color: ui.visuals().selection.stroke.color,
+        background: ui.visuals().selection.bg_fill,
+        ..format.clone()
+    };
+
+    let mut job = egui::text::LayoutJob::default();
+    job.append(&label[..range.start], 0.0, format.clone());
+    job.append(&label[range.clone()], 0.0, highlight);
+    job.append(&label[range.end..], 0.0, format);
+
+    ui.label(job)
+}
+
+/// Actions taken in the context menu of the row label.
+#[derive(Default)]
+struct LabelMenu {
+    changed: bool,
+
+    // Whether the value is edited as text.
+    #[cfg(feature = "serde")]
+    as_text: bool,
+}
+
+/// Shows context menu of the row label with actions the value opts in to.
+#[cfg_attr(not(feature = "serde"), allow(unused_variables))]
+fn label_menu(
+    value: &mut dyn EguiProbe,
+    response: &mut egui::Response,
+    style: &Style,
+    id: egui::Id,
+) -> LabelMenu {
+    let mut menu = LabelMenu::default();
 
-            let final_rect = child_ui.min_rect();
-            ui.advance_cursor_after_rect(final_rect);
+    let has_clipboard = value.as_clipboard_value().is_some();
+    let has_default = value.as_default_value().is_some();
 
-            // let response = ui.interact(final_rect, child_ui.id(), egui::Sense::hover());
-            // response.widget_info(|| egui::WidgetInfo::new(egui::WidgetType::Other));
+    #[cfg(feature = "serde")]
+    let has_text = match value.as_text_value() {
+        None => false,
+        Some(text_value) => {
+            menu.as_text = crate::serde_text::text_mode(&response.ctx, id.with("as_text"));
+            let paste_id = id.with("paste_text");
+            if !style.read_only && crate::serde_text::paste_text(text_value, response, paste_id) {
+                menu.changed = true;
+            }
+            true
+        }
+    };
+    #[cfg(not(feature = "serde"))]
+    let has_text = false;
 
-            // response
-        })
-        .response
+    if !has_clipboard && !has_text {
+        return menu;
     }
+
+    *response = response.interact(egui::Sense::click());
+    response.context_menu(|ui| {
+        if let Some(clipboard_value) = value.as_clipboard_value() {
+            menu.changed |=
+                crate::clipboard::clipboard_menu(clipboard_value, ui, style, !has_default);
+        }
+
+        #[cfg(feature = "serde")]
+        if let Some(text_value) = value.as_text_value() {
+            if has_clipboard {
+                ui.separator();
+            }
+            crate::serde_text::copy_text_button(text_value, ui);
+            if !style.read_only {
+                crate::serde_text::paste_text_button(ui, id.with("paste_text"));
+            }
+            crate::serde_text::text_mode_button(ui, id.with("as_text"), &mut menu.as_text);
+        }
+    });
+
+    menu
 }
 
 fn show_header(
     label: &str,
     value: &mut dyn EguiProbe,
-    layout: &mut ProbeLayout,
+    table: &mut ProbeTable,
+    path: &str,
     indent: usize,
     ui: &mut egui::Ui,
-    style: &Style,
     id_source: impl Hash,
 ) -> Option<ProbeHeader> {
     let mut header = None;
@@ -272,17 +1031,85 @@ fn show_header(
         header = Some(ProbeHeader::load(ui.ctx(), id));
     }
 
-    ui.horizontal(|ui| {
-        let label_response = layout.inner_label_ui(indent, id.with("label"), ui, |ui| {
-            if let Some(header) = &mut header {
-                header.collapse_button(ui);
+    let validation = value.validate().err();
+
+    // `None` if the value has no known default.
+    let modified = value
+        .as_default_value()
+        .map(|default_value| !default_value.is_default());
+
+    row_ui(ui, path, label, indent, |ui, parts| {
+        let color = validation
+            .as_ref()
+            .map(|validation| validation.severity.color(ui.visuals()));
+
+        let mut label_response = table
+            .layout
+            .inner_label_ui(indent, id.with("label"), ui, |ui| {
+                if let Some(header) = &mut header {
+                    parts.collapse = Some(header.collapse_button(ui).rect);
+                }
+                let r = match (find_match(label, table.filter), color) {
+                    (None, None) => ui.label(label),
+                    (None, Some(color)) => ui.colored_label(color, label),
+                    (Some(range), color) => highlighted_label(ui, label, range, color),
+                };
+                if modified == Some(true) {
+                    crate::default::modified_marker(ui);
+                }
+                r
+            });
+
+        if let Some(tooltip) = value.tooltip() {
+            label_response = label_response.on_hover_text(tooltip);
+        }
+
+        let style = table.style;
+        let menu = label_menu(value, &mut label_response, style, id);
+
+        let mut changed = menu.changed;
+        table.layout.inner_value_ui(id.with("value"), ui, |ui| {
+            #[cfg(feature = "serde")]
+            let response = match value.as_text_value().filter(|_| menu.as_text) {
+                Some(text_value) => {
+                    crate::serde_text::text_value_ui(text_value, ui, style, id.with("text"))
+                }
+                None => mixed_probe(value, ui, style, table.mixed.as_deref_mut(), path),
+            };
+            #[cfg(not(feature = "serde"))]
+            let response = mixed_probe(value, ui, style, table.mixed.as_deref_mut(), path);
+
+            changed |= response.labelled_by(label_response.id).changed();
+
+            if let Some(modified) = modified {
+                if crate::default::reset_button(ui, style, modified) {
+                    if let Some(default_value) = value.as_default_value() {
+                        default_value.reset_to_default();
+                    }
+                    changed = true;
+                }
+            }
+
+            if let (Some(validation), Some(color)) = (&validation, color) {
+                ui.colored_label(color, &validation.message);
             }
-            ui.label(label)
-        });
 
-        layout.inner_value_ui(id.with("value"), ui, |ui| {
-            value.probe(ui, style).labelled_by(label_response.id);
+            parts.value = ui.min_rect();
         });
+
+        if changed {
+            table.changed_paths.push(path.to_owned());
+        }
+
+        if let Some(validation) = validation {
+            table.issues.push(ValidationIssue {
+                path: path.to_owned(),
+                validation,
+            });
+        }
+
+        parts.label = label_response.rect;
+        parts.open = header.as_ref().map(|header| header.state.open);
     });
 
     header
@@ -291,10 +1118,10 @@ fn show_header(
 fn show_table(
     value: &mut dyn EguiProbe,
     header: &mut ProbeHeader,
-    layout: &mut ProbeLayout,
+    table: &mut ProbeTable,
+    path: &str,
     indent: usize,
     ui: &mut egui::Ui,
-    style: &Style,
     id_source: impl Hash,
 ) {
     let cursor = ui.cursor();
@@ -314,27 +1141,91 @@ fn show_table(
             .intersect(egui::Rect::everything_below(ui.min_rect().max.y)),
     );
 
-    let mut idx = 0;
-    value.iterate_inner(&mut |label, value| {
-        let header = show_header(label, value, layout, indent + 1, &mut table_ui, style, idx);
-
-        if let Some(mut header) = header {
-            if header.openness > 0.0 {
-                show_table(
-                    value,
-                    &mut header,
-                    layout,
-                    indent + 1,
-                    &mut table_ui,
-                    style,
-                    idx,
-                );
+    let page_size = table.style.page_size.unwrap_or(usize::MAX);
+    let pages_id = table_ui.make_persistent_id("probe_pages");
+    let mut pages: usize = table_ui
+        .ctx()
+        .data_mut(|d| d.get_temp(pages_id))
+        .unwrap_or(1);
+    let limit = page_size.saturating_mul(pages);
+
+    // Height of rows that were never shown.
+    let row_height = table_ui.spacing().interact_size.y + table_ui.spacing().item_spacing.y;
+
+    let mut hidden = 0;
+    if table.filtering {
+        // Filter is matched against every value, so matching rows are shown as is.
+        let mut idx = 0;
+        let mut shown = 0;
+        value.iterate_inner(&mut |label, value| {
+            if find_match(label, table.filter).is_none()
+                && (!table.filter_descendants
+                    || !table.filter_ancestors.contains(&join_path(path, label)))
+            {
+                idx += 1;
+                return;
             }
-            header.store(table_ui.ctx());
-        }
 
-        idx += 1;
-    });
+            if shown < limit {
+                shown += 1;
+                show_row(label, value, table, path, indent, &mut table_ui, idx);
+            } else {
+                hidden += 1;
+            }
+            idx += 1;
+        });
+    } else {
+        // Only rows within the visible area are reached,
+        // others are replaced with space of their last known height.
+        let rows_id = table_ui.make_persistent_id("probe_rows");
+        let clip_rect = table_ui.clip_rect();
+        let top = table_ui.cursor().min.y;
+        let (range, skipped) = table_ui.ctx().data_mut(|d| {
+            d.get_temp_mut_or_default::<ProbeRows>(rows_id).visible(
+                top,
+                clip_rect.min.y..clip_rect.max.y,
+                row_height,
+                limit,
+            )
+        });
+        skip_rows(&mut table_ui, skipped);
+
+        let start = range.start;
+        let mut heights = Vec::new();
+        let len = value.iterate_inner_range(range, &mut |label, value| {
+            let top = table_ui.cursor().min.y;
+            let idx = start + heights.len();
+            show_row(label, value, table, path, indent, &mut table_ui, idx);
+            heights.push(table_ui.cursor().min.y - top);
+        });
+
+        let shown = len.min(limit);
+        hidden = len - shown;
+
+        let remaining = table_ui.ctx().data_mut(|d| {
+            d.get_temp_mut_or_default::<ProbeRows>(rows_id)
+                .update(start, &heights, shown, row_height)
+        });
+        skip_rows(&mut table_ui, remaining);
+    }
+
+    if hidden > 0 {
+        table_ui.horizontal(|ui| {
+            let id = ui.make_persistent_id("probe_pager");
+            table
+                .layout
+                .inner_label_ui(indent + 1, id.with("label"), ui, |ui| ui.weak("…"));
+            table.layout.inner_value_ui(id.with("value"), ui, |ui| {
+                let r = ui
+                    .small_button(format!("Show {} more", hidden.min(page_size)))
+                    .on_hover_text(format!("{hidden} more values are hidden"));
+                if r.clicked() {
+                    pages += 1;
+                    ui.ctx().data_mut(|d| d.insert_temp(pages_id, pages));
+                }
+            });
+        });
+    }
 
     let final_table_rect = table_ui.min_rect();
 
@@ -342,3 +1233,44 @@ fn show_table(
     let table_height = ui.cursor().min.y - table_rect.min.y;
     header.set_body_height(table_height);
 }
+
+/// Shows the row of the inner value with its own inner rows.
+fn show_row(
+    label: &str,
+    value: &mut dyn EguiProbe,
+    table: &mut ProbeTable,
+    path: &str,
+    indent: usize,
+    ui: &mut egui::Ui,
+    idx: usize,
+) {
+    // Rows that don't match the filter are shown only because their descendants do.
+    let expand = table.filtering && find_match(label, table.filter).is_none();
+
+    let path = join_path(path, label);
+    let Some(mut header) = show_header(label, value, table, &path, indent + 1, ui, idx) else {
+        return;
+    };
+
+    if expand {
+        header.open();
+    }
+    if header.openness > 0.0 {
+        let filtering = table.filtering;
+        table.filtering = filtering && expand;
+        show_table(value, &mut header, table, &path, indent + 1, ui, idx);
+        table.filtering = filtering;
+    }
+    header.store(ui.ctx());
+}
+
+/// Leaves space of rows that are not shown.
+fn skip_rows(ui: &mut egui::Ui, height: f32) {
+    if height > 0.0 {
+        let spacing = ui.spacing().item_spacing.y;
+        ui.advance_cursor_after_rect(egui::Rect::from_min_size(
+            ui.cursor().min,
+            egui::vec2(0.0, (height - spacing).max(0.0)),
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    cell::{Cell, RefCell},
+    num::Wrapping,
+    ops::Range,
+    rc::Rc,
+    sync::{Arc, Mutex, MutexGuard, RwLock, RwLockWriteGuard, TryLockError},
+};
+
+use crate::{EguiProbe, Style, Validation};
+
+/// Implements `EguiProbe` for type that gives exclusive access to the value
+/// by forwarding all methods to it.
+macro_rules! forward_probe {
+    (|$this:ident: $ty:ty| $value:expr $(, $($bounds:tt)+)?) => {
+        impl<T> EguiProbe for $ty
+        where
+            T: EguiProbe,
+            $($($bounds)+)?
+        {
+            forward_probe_methods!(
+                |$this| $value =>
+                probe,
+                has_inner,
+                iterate_inner,
+                iterate_inner_range,
+                tooltip,
+                validate,
+                as_clipboard_value,
+                as_text_value,
+                as_default_value,
+                as_multi_value,
+            );
+        }
+    };
+}
+
+forward_probe!(|this: Box<T>| **this, T: ?Sized);
+forward_probe!(|this: RefCell<T>| this.get_mut(), T: ?Sized);
+forward_probe!(|this: Cell<T>| this.get_mut(), T: Copy);
+forward_probe!(|this: Wrapping<T>| this.0);
+
+/// Implements `EguiProbe` for shared value that may be unavailable.
+///
+/// Placeholder is shown instead of the value while it is borrowed or locked elsewhere.
+/// Values are accessed only while probed, so values that are not accessible
+/// can't be copied, compared or assigned.
+macro_rules! shared_probe {
+    ($ty:ty, |$this:ident| $access:expr, $placeholder:literal) => {
+        impl<T> EguiProbe for $ty
+        where
+            T: EguiProbe + ?Sized,
+        {
+            #[inline(always)]
+            fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+                let $this = self;
+                match $access {
+                    Some(mut value) => value.probe(ui, style),
+                    None => ui.weak($placeholder).on_hover_text(concat!(
+                        "Value is ",
+                        $placeholder,
+                        " elsewhere"
+                    )),
+                }
+            }
+
+            #[inline(always)]
+            fn has_inner(&mut self) -> bool {
+                let $this = self;
+                $access.is_some_and(|mut value| value.has_inner())
+            }
+
+            #[inline(always)]
+            fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+                let $this = self;
+                if let Some(mut value) = $access {
+                    value.iterate_inner(f);
+                }
+            }
+
+            #[inline(always)]
+            fn iterate_inner_range(
+                &mut self,
+                range: Range<usize>,
+                f: &mut dyn FnMut(&str, &mut dyn EguiProbe),
+            ) -> usize {
+                let $this = self;
+                match $access {
+                    Some(mut value) => value.iterate_inner_range(range, f),
+                    None => 0,
+                }
+            }
+
+            #[inline(always)]
+            fn validate(&mut self) -> Result<(), Validation> {
+                let $this = self;
+                match $access {
+                    Some(mut value) => value.validate(),
+                    None => Ok(()),
+                }
+            }
+        }
+    };
+}
+
+shared_probe!(
+    Rc<RefCell<T>>,
+    |this| this.try_borrow_mut().ok(),
+    "borrowed"
+);
+shared_probe!(Arc<Mutex<T>>, |this| try_lock(this), "locked");
+shared_probe!(Arc<RwLock<T>>, |this| try_write(this), "locked");
+
+/// Value of poisoned mutex is still accessible.
+fn try_lock<T: ?Sized>(mutex: &Mutex<T>) -> Option<MutexGuard<'_, T>> {
+    match mutex.try_lock() {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(err)) => Some(err.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+/// Value of poisoned lock is still accessible.
+fn try_write<T: ?Sized>(lock: &RwLock<T>) -> Option<RwLockWriteGuard<'_, T>> {
+    match lock.try_write() {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(err)) => Some(err.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
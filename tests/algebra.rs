@@ -0,0 +1,74 @@
+use egui_probe::{
+    egui::{self, pos2, vec2, Pos2, Rect, Vec2},
+    normalized,
+    testing::ProbeHarness,
+    EguiProbe, Style,
+};
+
+struct Heading {
+    forward: Vec2,
+}
+
+impl EguiProbe for Heading {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Heading")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("forward", &mut normalized(&mut self.forward));
+    }
+}
+
+#[test]
+fn vec2_components() {
+    let mut harness = ProbeHarness::new(vec2(1.0, 2.0));
+    assert_eq!(harness.row("").value_text(), "1 2");
+
+    harness.type_text_at(harness.text_rect("", "2").center(), "5");
+    assert_eq!(harness.value, Vec2::new(1.0, 5.0));
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn pos2_components() {
+    let mut harness = ProbeHarness::new(pos2(1.0, 2.0));
+
+    harness.type_text("", "3");
+    assert_eq!(harness.value, Pos2::new(3.0, 2.0));
+}
+
+#[test]
+fn rect_sides() {
+    let mut harness = ProbeHarness::new(Rect::from_min_max(pos2(1.0, 2.0), pos2(3.0, 4.0)));
+    assert_eq!(harness.row("").value_text(), "2 left 1 bottom 4 right 3");
+
+    harness.type_text_at(harness.text_rect("", "4").center(), "8");
+    assert_eq!(harness.value.max, pos2(3.0, 8.0));
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(vec2(1.0, 2.0)).read_only(true);
+
+    harness.type_text("", "3");
+    assert_eq!(harness.value, vec2(1.0, 2.0));
+}
+
+#[test]
+fn normalized_vec2() {
+    let mut harness = ProbeHarness::new(Heading {
+        forward: vec2(1.0, 0.0),
+    });
+    harness.expand("");
+
+    harness.type_text("forward", "0");
+    assert_eq!(harness.value.forward, vec2(1.0, 0.0));
+
+    harness.type_text_at(harness.text_rect("forward", "0").center(), "1");
+    assert_eq!(harness.value.forward, vec2(1.0, 1.0).normalized());
+    assert_eq!(harness.changed_paths(), ["forward"]);
+}
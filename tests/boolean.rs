@@ -0,0 +1,20 @@
+use egui_probe::testing::ProbeHarness;
+
+#[test]
+fn checkbox() {
+    let mut harness = ProbeHarness::new(false);
+    harness.click("");
+    assert!(harness.value);
+    assert_eq!(harness.changed_paths(), [""]);
+
+    harness.click("");
+    assert!(!harness.value);
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(false).read_only(true);
+    harness.click("");
+    assert!(!harness.value);
+    assert!(harness.changed_paths().is_empty());
+}
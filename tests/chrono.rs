@@ -0,0 +1,97 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use egui_probe::testing::ProbeHarness;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn date_time(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    date(year, month, day).and_hms_opt(hour, minute, 0).unwrap()
+}
+
+#[test]
+fn naive_date() {
+    let mut harness = ProbeHarness::new(date(2024, 3, 5));
+    assert_eq!(harness.row("").value_text(), "2024-03-05 📅");
+
+    harness.type_text("", "1999-12-31");
+    assert_eq!(harness.value, date(1999, 12, 31));
+    assert_eq!(harness.changed_paths(), [""]);
+
+    harness.click_text("", "📅");
+    harness.click_any_text("1");
+    assert_eq!(harness.value, date(1999, 12, 1));
+}
+
+#[test]
+fn naive_time() {
+    let mut harness = ProbeHarness::new(NaiveTime::from_hms_milli_opt(8, 30, 0, 250).unwrap());
+    assert_eq!(harness.row("").value_text(), "08:30:00.250");
+
+    harness.type_text("", "23:59");
+    assert_eq!(harness.value, NaiveTime::from_hms_opt(23, 59, 0).unwrap());
+
+    harness.type_text("", "24:00");
+    assert!(harness.has_any_text("hour 24 is not in 0..=23"));
+    assert_eq!(harness.value, NaiveTime::from_hms_opt(23, 59, 0).unwrap());
+}
+
+#[test]
+fn naive_date_time() {
+    let mut harness = ProbeHarness::new(date_time(2024, 3, 5, 13, 45));
+    assert_eq!(harness.row("").value_text(), "2024-03-05 13:45:00 📅");
+
+    harness.type_text("", "2024-03-05 13:45 +01:00");
+    assert!(harness.has_any_text("naive date and time has no offset"));
+
+    harness.click_text("", "📅");
+    harness.click_any_text("20");
+    assert_eq!(harness.value, date_time(2024, 3, 20, 13, 45));
+    assert!(!harness.has_any_text("naive date and time has no offset"));
+}
+
+#[test]
+fn utc() {
+    let mut harness = ProbeHarness::new(Utc.with_ymd_and_hms(2024, 3, 5, 13, 45, 0).unwrap());
+    assert_eq!(
+        harness.row("").value_text(),
+        "2024-03-05 13:45:00 +00:00 📅"
+    );
+
+    // Time with offset is converted to the time zone of the value.
+    harness.type_text("", "2024-03-05 13:45:00 +02:00");
+    assert_eq!(
+        harness.value,
+        Utc.with_ymd_and_hms(2024, 3, 5, 11, 45, 0).unwrap()
+    );
+}
+
+#[test]
+fn fixed_offset() {
+    let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    let value: DateTime<FixedOffset> = offset.with_ymd_and_hms(2024, 3, 5, 13, 45, 0).unwrap();
+    let mut harness = ProbeHarness::new(value);
+    assert_eq!(
+        harness.row("").value_text(),
+        "2024-03-05 13:45:00 +02:00 📅"
+    );
+
+    // Time without offset is local time of the value.
+    harness.type_text("", "2024-03-05 10:00");
+    assert_eq!(
+        harness.value,
+        offset.with_ymd_and_hms(2024, 3, 5, 10, 0, 0).unwrap()
+    );
+    assert_eq!(harness.value.offset(), &offset);
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(date(2024, 3, 5)).read_only(true);
+    assert_eq!(harness.row("").value_text(), "2024-03-05");
+
+    harness.type_text("", "1999-12-31");
+    assert_eq!(harness.value, date(2024, 3, 5));
+}
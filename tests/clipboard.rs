@@ -0,0 +1,142 @@
+use egui_probe::{
+    clipboard_value, default_value,
+    egui::{self, Color32, Stroke},
+    testing::ProbeHarness,
+    EguiProbe, Style,
+};
+
+struct Value {
+    a: Stroke,
+    b: Stroke,
+    width: f32,
+    list: Vec<u32>,
+    rect: egui::Rect,
+}
+
+impl EguiProbe for Value {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Value")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("a", &mut self.a);
+        f("b", &mut self.b);
+        f("width", &mut self.width);
+        f("list", &mut clipboard_value(&mut self.list));
+        f("rect", &mut self.rect);
+    }
+}
+
+fn harness(read_only: bool) -> ProbeHarness<Value> {
+    let mut harness = ProbeHarness::new(Value {
+        a: Stroke::new(2.0, Color32::RED),
+        b: Stroke::NONE,
+        width: 1.0,
+        list: vec![1, 2],
+        rect: egui::Rect::from_min_max(egui::pos2(1.0, 2.0), egui::pos2(3.0, 4.0)),
+    })
+    .read_only(read_only);
+    harness.expand("");
+    harness
+}
+
+#[test]
+fn copy_paste() {
+    let mut harness = harness(false);
+    harness.context_menu("a");
+    harness.click_any_text("Copy");
+    harness.context_menu("b");
+    harness.click_any_text("Paste");
+    assert_eq!(harness.value.b, Stroke::new(2.0, Color32::RED));
+    assert_eq!(harness.changed_paths(), ["b"]);
+}
+
+#[test]
+fn paste_requires_same_type() {
+    let mut harness = harness(false);
+    harness.context_menu("a");
+    harness.click_any_text("Copy");
+    harness.context_menu("width");
+    harness.click_any_text("Paste");
+    assert_eq!(harness.value.width, 1.0);
+    assert!(harness.changed_paths().is_empty());
+}
+
+#[test]
+fn reset() {
+    let mut harness = harness(false);
+    harness.context_menu("a");
+    harness.click_any_text("Reset to default");
+    assert_eq!(harness.value.a, Stroke::default());
+    assert_eq!(harness.changed_paths(), ["a"]);
+}
+
+#[test]
+fn opt_in_wrapper() {
+    let mut harness = harness(false);
+    harness.context_menu("list");
+    harness.click_any_text("Copy");
+    harness.context_menu("list");
+    harness.click_any_text("Reset to default");
+    assert!(harness.value.list.is_empty());
+
+    harness.context_menu("list");
+    harness.click_any_text("Paste");
+    assert_eq!(harness.value.list, [1, 2]);
+}
+
+struct WithDefault {
+    a: Stroke,
+}
+
+impl EguiProbe for WithDefault {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("WithDefault")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("a", &mut default_value(&mut self.a, Stroke::NONE));
+    }
+}
+
+#[test]
+fn single_reset_with_default() {
+    let mut harness = ProbeHarness::new(WithDefault {
+        a: Stroke::new(2.0, Color32::RED),
+    });
+    harness.expand("");
+    harness.click_text("a", "⟲");
+    assert_eq!(harness.value.a, Stroke::NONE);
+
+    harness.context_menu("a");
+    assert!(harness.has_any_text("Copy"));
+    assert!(!harness.has_any_text("Reset to default"));
+}
+
+#[test]
+fn no_menu_without_opt_in() {
+    let mut harness = harness(false);
+    harness.context_menu("rect");
+    assert!(!harness.has_any_text("Copy"));
+}
+
+#[test]
+fn read_only() {
+    let mut harness = harness(true);
+    harness.context_menu("a");
+    harness.click_any_text("Copy");
+    harness.context_menu("b");
+    harness.click_any_text("Paste");
+    harness.context_menu("a");
+    harness.click_any_text("Reset to default");
+    assert_eq!(harness.value.a, Stroke::new(2.0, Color32::RED));
+    assert_eq!(harness.value.b, Stroke::NONE);
+}
@@ -0,0 +1,41 @@
+use egui_probe::{
+    egui::{ecolor::Hsva, Color32, Rgba},
+    testing::ProbeHarness,
+};
+
+#[test]
+fn color32() {
+    let mut harness = ProbeHarness::new(Color32::RED);
+
+    harness.click("");
+    harness.type_text_at(harness.any_text_rect("G 0").center(), "128");
+    assert_eq!(harness.value, Color32::from_rgb(255, 128, 0));
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn rgba() {
+    let mut harness = ProbeHarness::new(Rgba::RED);
+
+    harness.click("");
+    harness.type_text_at(harness.any_text_rect("B 0").center(), "255");
+    assert_eq!(Color32::from(harness.value), Color32::from_rgb(255, 0, 255));
+}
+
+#[test]
+fn hsva() {
+    let mut harness = ProbeHarness::new(Hsva::from(Color32::RED));
+
+    harness.click("");
+    harness.type_text_at(harness.any_text_rect("G 0").center(), "255");
+    assert_eq!(Color32::from(harness.value), Color32::from_rgb(255, 255, 0));
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(Color32::RED).read_only(true);
+
+    harness.click("");
+    assert!(!harness.has_any_text("G 0"));
+    assert_eq!(harness.value, Color32::RED);
+}
@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+use egui_probe::{egui, testing::ProbeHarness, EguiProbe, Style};
+
+/// Shows the value as the only field of the root value,
+/// so that probe UI of the value itself is shown in a row.
+pub struct Field<T>(pub T);
+
+impl<T> EguiProbe for Field<T>
+where
+    T: EguiProbe,
+{
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Field")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("field", &mut self.0);
+    }
+}
+
+/// Creates harness for the value shown as a field with all rows expanded.
+pub fn field<T>(value: T) -> ProbeHarness<Field<T>>
+where
+    T: EguiProbe,
+{
+    let mut harness = ProbeHarness::new(Field(value));
+    harness.expand_all();
+    harness
+}
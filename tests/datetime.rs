@@ -0,0 +1,91 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use egui_probe::testing::ProbeHarness;
+
+fn at(secs: i64) -> SystemTime {
+    match u64::try_from(secs) {
+        Ok(secs) => UNIX_EPOCH + Duration::from_secs(secs),
+        Err(_) => UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs()),
+    }
+}
+
+#[test]
+fn formatted() {
+    for (secs, text) in [
+        (0, "1970-01-01 00:00:00 UTC"),
+        (1_709_646_300, "2024-03-05 13:45:00 UTC"),
+        (951_825_600, "2000-02-29 12:00:00 UTC"),
+        (-2_203_891_200, "1900-03-01 00:00:00 UTC"),
+    ] {
+        let harness = ProbeHarness::new(at(secs));
+        assert_eq!(harness.row("").value_text(), format!("{text} 📅"));
+    }
+
+    let harness = ProbeHarness::new(UNIX_EPOCH - Duration::from_millis(1500));
+    assert_eq!(
+        harness.row("").value_text(),
+        "1969-12-31 23:59:58.500 UTC 📅"
+    );
+}
+
+#[test]
+fn typed() {
+    let mut harness = ProbeHarness::new(UNIX_EPOCH);
+    harness.type_text("", "1999-12-31 23:00");
+    assert_eq!(harness.value, at(946_681_200));
+    assert_eq!(harness.changed_paths(), [""]);
+
+    // Text is reformatted once editing is finished.
+    assert_eq!(harness.row("").value_text(), "1999-12-31 23:00:00 UTC 📅");
+
+    harness.type_text("", "2000-01-01T00:00:00+01:00");
+    assert_eq!(harness.value, at(946_681_200));
+}
+
+#[test]
+fn parse_error() {
+    let mut harness = ProbeHarness::new(UNIX_EPOCH);
+    harness.type_text("", "2024-13-01 00:00:00");
+    assert_eq!(harness.value, UNIX_EPOCH);
+    assert!(harness.has_any_text("month 13 is not in 1..=12"));
+
+    harness.type_text("", "2023-02-29 00:00:00");
+    assert!(harness.has_any_text("day 29 is not in 1..=28 for February"));
+
+    harness.type_text("", "2024-02-29 00:00:00");
+    assert_eq!(harness.value, at(1_709_164_800));
+    assert!(!harness.has_any_text("day 29 is not in 1..=28 for February"));
+}
+
+#[test]
+fn calendar() {
+    let mut harness = ProbeHarness::new(at(1_709_646_300));
+    harness.click_text("", "📅");
+    assert!(harness.has_any_text("March 2024"));
+
+    harness.click_any_text("20");
+    assert_eq!(harness.value, at(1_710_942_300));
+    assert_eq!(harness.changed_paths(), [""]);
+    assert!(!harness.has_any_text("March 2024"));
+}
+
+#[test]
+fn calendar_months() {
+    let mut harness = ProbeHarness::new(at(1_709_646_300));
+    harness.click_text("", "📅");
+    harness.click_any_text("⏵");
+    assert!(harness.has_any_text("April 2024"));
+    assert_eq!(harness.value, at(1_709_646_300));
+
+    harness.click_any_text("1");
+    assert_eq!(harness.value, at(1_711_979_100));
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(UNIX_EPOCH).read_only(true);
+    assert_eq!(harness.row("").value_text(), "1970-01-01 00:00:00 UTC");
+
+    harness.type_text("", "2000-01-01 00:00:00");
+    assert_eq!(harness.value, UNIX_EPOCH);
+}
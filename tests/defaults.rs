@@ -0,0 +1,130 @@
+use egui_probe::{default_value, egui, testing::ProbeHarness, EguiProbe, Style};
+
+struct Inner {
+    b: u32,
+}
+
+impl EguiProbe for Inner {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Inner")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("b", &mut default_value(&mut self.b, 0));
+    }
+}
+
+struct Value {
+    a: f32,
+    inner: Inner,
+    plain: u32,
+}
+
+impl EguiProbe for Value {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Value")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("a", &mut default_value(&mut self.a, 1.0));
+        f("inner", &mut self.inner);
+        f("plain", &mut self.plain);
+    }
+}
+
+fn harness(read_only: bool) -> ProbeHarness<Value> {
+    let mut harness = ProbeHarness::new(Value {
+        a: 2.0,
+        inner: Inner { b: 0 },
+        plain: 3,
+    })
+    .read_only(read_only);
+    harness.expand_all();
+    harness
+}
+
+fn is_marked(harness: &ProbeHarness<Value>, path: &str) -> bool {
+    harness.row(path).label_texts.contains(&"⏺".to_owned())
+}
+
+#[test]
+fn modified_marker() {
+    let mut harness = harness(false);
+    assert!(is_marked(&harness, "a"));
+    assert!(!is_marked(&harness, "inner.b"));
+    assert!(!is_marked(&harness, "plain"));
+
+    harness.value.inner.b = 5;
+    harness.run();
+    assert!(is_marked(&harness, "inner.b"));
+}
+
+#[test]
+fn reset() {
+    let mut harness = harness(false);
+    harness.click_text("a", "⟲");
+    assert_eq!(harness.value.a, 1.0);
+    assert_eq!(harness.changed_paths(), ["a"]);
+
+    harness.run();
+    assert!(!is_marked(&harness, "a"));
+}
+
+#[test]
+fn reset_requires_modified() {
+    let mut harness = harness(false);
+    harness.value.inner.b = 5;
+    harness.click_text("inner.b", "⟲");
+    assert_eq!(harness.value.inner.b, 0);
+
+    harness.click_text("inner.b", "⟲");
+    assert!(harness.changed_paths().is_empty());
+}
+
+#[test]
+fn no_reset_without_default() {
+    let harness = harness(false);
+    assert!(!harness.row("plain").texts.contains(&"⟲".to_owned()));
+}
+
+#[test]
+fn reset_all() {
+    let mut harness = harness(false);
+    harness.value.inner.b = 5;
+    harness.run();
+    harness.click_any_text("Reset all");
+    assert_eq!(harness.value.a, 1.0);
+    assert_eq!(harness.value.inner.b, 0);
+    assert_eq!(harness.value.plain, 3);
+    assert_eq!(harness.changed_paths(), ["a", "inner.b"]);
+}
+
+#[test]
+fn read_only() {
+    let mut harness = harness(true);
+    assert!(is_marked(&harness, "a"));
+
+    harness.click_text("a", "⟲");
+    harness.click_any_text("Reset all");
+    assert_eq!(harness.value.a, 2.0);
+    assert!(harness.changed_paths().is_empty());
+}
+
+#[test]
+fn no_reset_all_without_defaults() {
+    let mut harness = ProbeHarness::new(Inner { b: 1 });
+    harness.expand("");
+    assert!(harness.has_any_text("Reset all"));
+
+    let mut harness = ProbeHarness::new(vec![1u32]);
+    harness.expand("");
+    assert!(!harness.has_any_text("Reset all"));
+}
@@ -0,0 +1,330 @@
+#![cfg(feature = "derive")]
+
+use egui_probe::{testing::ProbeHarness, EguiProbe};
+
+fn not_empty(value: &str) -> Result<(), &'static str> {
+    if value.is_empty() {
+        Err("must not be empty")
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Default, EguiProbe)]
+struct Value {
+    #[egui_probe(name = "renamed")]
+    flag: bool,
+
+    #[egui_probe(toggle_switch)]
+    switch: bool,
+
+    #[egui_probe(range = 0..=10)]
+    ranged: u32,
+
+    #[egui_probe(readonly)]
+    locked: u32,
+
+    #[egui_probe(skip)]
+    skipped: u32,
+
+    #[egui_probe(frozen)]
+    frozen: Vec<u32>,
+
+    #[egui_probe(validate = not_empty)]
+    name: String,
+}
+
+#[derive(Default, EguiProbe)]
+#[egui_probe(tags inlined)]
+enum Shape {
+    #[default]
+    Empty,
+
+    Circle {
+        radius: f32,
+    },
+}
+
+fn harness() -> ProbeHarness<Value> {
+    let mut harness = ProbeHarness::new(Value {
+        frozen: vec![1],
+        ..Value::default()
+    });
+    harness.expand_all();
+    harness
+}
+
+#[test]
+fn fields() {
+    let harness = harness();
+    let paths: Vec<_> = harness.rows().iter().map(|row| row.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        [
+            "",
+            "renamed",
+            "switch",
+            "ranged",
+            "locked",
+            "frozen",
+            "frozen[0]",
+            "name"
+        ]
+    );
+}
+
+#[test]
+fn toggle_switch() {
+    let mut harness = harness();
+    harness.click("renamed");
+    harness.click("switch");
+    assert!(harness.value.flag);
+    assert!(harness.value.switch);
+}
+
+#[test]
+fn range() {
+    let mut harness = harness();
+    harness.type_text("ranged", "100");
+    assert_eq!(harness.value.ranged, 10);
+}
+
+#[test]
+fn readonly() {
+    let mut harness = harness();
+    harness.type_text("locked", "5");
+    assert_eq!(harness.value.locked, 0);
+    assert!(harness.changed_paths().is_empty());
+}
+
+#[test]
+fn frozen() {
+    let harness = harness();
+    assert_eq!(harness.row("frozen").value_text(), "[1]");
+}
+
+#[test]
+fn validate() {
+    let mut harness = harness();
+    let paths: Vec<_> = harness.issues().iter().map(|issue| &issue.path).collect();
+    assert_eq!(paths, ["name"]);
+
+    harness.type_text("name", "probe");
+    assert!(harness.issues().is_empty());
+}
+
+#[test]
+fn inlined_tags() {
+    let mut harness = ProbeHarness::new(Shape::Empty);
+    harness.click_any_text("Circle");
+    assert!(matches!(harness.value, Shape::Circle { .. }));
+}
+
+#[derive(Default, EguiProbe)]
+#[egui_probe(defaults)]
+struct WithDefaults {
+    count: u32,
+
+    #[egui_probe(range = 0.0..=10.0, default = 5.0)]
+    scale: f32,
+
+    #[egui_probe(readonly)]
+    locked: u32,
+}
+
+#[test]
+fn defaults() {
+    let mut harness = ProbeHarness::new(WithDefaults {
+        count: 3,
+        scale: 1.0,
+        locked: 2,
+    });
+    harness.expand_all();
+    assert!(harness.row("count").label_texts.contains(&"⏺".to_owned()));
+    assert!(!harness.row("locked").texts.contains(&"⟲".to_owned()));
+
+    harness.click_text("count", "⟲");
+    assert_eq!(harness.value.count, 0);
+
+    harness.click_any_text("Reset all");
+    assert_eq!(harness.value.scale, 5.0);
+    assert_eq!(harness.value.locked, 2);
+}
+
+#[derive(Clone, Default, PartialEq, EguiProbe)]
+#[egui_probe(tags inlined, multi)]
+enum Direction {
+    #[default]
+    Left,
+    Right,
+}
+
+#[derive(EguiProbe)]
+struct Selected {
+    #[egui_probe(range = 0..=10)]
+    level: u32,
+    direction: Direction,
+}
+
+#[test]
+fn multi() {
+    let mut harness = ProbeHarness::multi(vec![
+        Selected {
+            level: 1,
+            direction: Direction::Left,
+        },
+        Selected {
+            level: 2,
+            direction: Direction::Left,
+        },
+    ]);
+    harness.expand_all();
+    assert_eq!(harness.row("level").value_text(), "—");
+
+    harness.click_text("direction", "Right");
+    assert!(harness
+        .value
+        .iter()
+        .all(|selected| selected.direction == Direction::Right));
+}
+
+#[derive(Default, EguiProbe)]
+struct Row {
+    name: String,
+    weight: u32,
+}
+
+#[derive(EguiProbe)]
+struct Rows {
+    #[egui_probe(table)]
+    rows: Vec<Row>,
+}
+
+#[test]
+fn table() {
+    let mut harness = ProbeHarness::new(Rows {
+        rows: vec![
+            Row {
+                name: "b".to_owned(),
+                weight: 2,
+            },
+            Row {
+                name: "a".to_owned(),
+                weight: 1,
+            },
+        ],
+    });
+    harness.expand_all();
+    assert!(!harness.has_row("rows[0]"));
+    assert!(harness.has_any_text("weight"));
+
+    harness.click_text("rows", "+");
+    assert_eq!(harness.value.rows.len(), 3);
+}
+
+#[derive(EguiProbe)]
+struct Packet {
+    #[egui_probe(hex)]
+    payload: Vec<u8>,
+    #[egui_probe(hex)]
+    checksum: [u8; 2],
+}
+
+#[test]
+fn hex() {
+    let mut harness = ProbeHarness::new(Packet {
+        payload: vec![1, 2],
+        checksum: [0xab, 0xcd],
+    });
+    harness.expand_all();
+    assert!(!harness.has_row("payload[0]"));
+
+    harness.click_text("payload", "02");
+    harness.paste("payload", "ffee");
+    assert_eq!(harness.value.payload, [1, 0xff, 0xee]);
+}
+
+#[derive(EguiProbe)]
+struct Heading {
+    #[egui_probe(normalized)]
+    forward: egui_probe::egui::Vec2,
+}
+
+#[test]
+fn normalized() {
+    let mut harness = ProbeHarness::new(Heading {
+        forward: egui_probe::egui::Vec2::X,
+    });
+    harness.expand_all();
+
+    harness.type_text("forward", "3");
+    assert_eq!(harness.value.forward, egui_probe::egui::Vec2::X);
+}
+
+#[derive(Clone, Debug, Default, PartialEq, EguiProbe)]
+struct Waypoint {
+    x: f32,
+    y: f32,
+}
+
+#[derive(EguiProbe)]
+struct Route {
+    #[egui_probe(as egui_probe::clone_items)]
+    waypoints: Vec<Waypoint>,
+}
+
+#[test]
+fn clone_items() {
+    let waypoint = Waypoint { x: 1.0, y: 2.0 };
+    let mut harness = ProbeHarness::new(Route {
+        waypoints: vec![waypoint.clone()],
+    });
+    harness.expand_all();
+
+    harness.click_text("waypoints[0]", "🗐");
+    assert_eq!(harness.value.waypoints, [waypoint.clone(), waypoint]);
+}
+
+#[derive(EguiProbe)]
+struct Assets {
+    #[egui_probe(path(extensions = ["png"]))]
+    image: std::path::PathBuf,
+    #[egui_probe(path(dir_only))]
+    output: Option<std::path::PathBuf>,
+}
+
+#[test]
+fn path() {
+    let dir = std::env::temp_dir();
+    let mut harness = ProbeHarness::new(Assets {
+        image: dir.clone(),
+        output: Some(dir.clone()),
+    });
+    harness.expand_all();
+    assert!(harness.row("image").texts.iter().any(|t| t == "⚠"));
+    assert!(harness.row("output").texts.iter().any(|t| t == "📁"));
+
+    harness.click_text("output", "🗁");
+    harness.click_any_text("Select");
+    assert_eq!(harness.value.output, Some(dir));
+}
+
+#[cfg(feature = "glam")]
+#[derive(EguiProbe)]
+struct Tint {
+    #[egui_probe(range = 0.0..=1.0)]
+    color: glam::Vec3,
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn glam_range() {
+    let mut harness = ProbeHarness::new(Tint {
+        color: glam::Vec3::ZERO,
+    });
+    harness.expand_all();
+    assert_eq!(harness.row("color").value_text(), "0 0 0 0..=1");
+
+    harness.type_text("color", "5");
+    assert_eq!(harness.value.color, glam::Vec3::X);
+}
@@ -0,0 +1,140 @@
+use egui_probe::{egui, multi_value, testing::ProbeHarness, EguiProbe, Style};
+
+#[derive(Debug)]
+struct Inner {
+    count: u32,
+}
+
+impl EguiProbe for Inner {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Inner")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("count", &mut self.count);
+    }
+}
+
+#[derive(Debug)]
+struct Config {
+    speed: f32,
+    name: String,
+    inner: Inner,
+    other: Inner,
+    tags: Vec<u32>,
+}
+
+impl EguiProbe for Config {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Config")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("speed", &mut self.speed);
+        f("name", &mut self.name);
+        f("inner", &mut self.inner);
+        f("other", &mut self.other);
+        f("tags", &mut multi_value(&mut self.tags));
+    }
+}
+
+fn config(speed: f32, count: u32) -> Config {
+    Config {
+        speed,
+        name: "a".to_owned(),
+        inner: Inner { count },
+        other: Inner { count: 1 },
+        tags: vec![1],
+    }
+}
+
+#[test]
+fn side_by_side() {
+    let harness = ProbeHarness::diff(config(1.0, 1), config(2.0, 1));
+    assert_eq!(harness.row("speed").value_text(), "1 2 ← →");
+    assert_eq!(harness.row("name").value_text(), "a a");
+    assert!(harness.has_any_text("1 difference"));
+}
+
+#[test]
+fn collapse_identical() {
+    let harness = ProbeHarness::diff(config(1.0, 1), config(1.0, 2));
+    assert_eq!(harness.row("inner").open, Some(true));
+    assert_eq!(harness.row("inner.count").value_text(), "1 2 ← →");
+    assert_eq!(harness.row("other").open, Some(false));
+    assert!(!harness.has_row("other.count"));
+    assert_eq!(harness.row("tags").open, Some(false));
+}
+
+#[test]
+fn expand_identical() {
+    let mut harness = ProbeHarness::diff(config(1.0, 1), config(1.0, 1));
+    assert!(harness.has_any_text("No differences"));
+    harness.toggle("other");
+    assert_eq!(harness.row("other.count").value_text(), "1 1");
+}
+
+#[test]
+fn copy() {
+    let mut harness = ProbeHarness::diff(config(1.0, 1), config(2.0, 2));
+    harness.click_text("speed", "→");
+    assert_eq!(harness.value.1.speed, 1.0);
+    assert_eq!(harness.changed_paths(), ["speed"]);
+
+    harness.click_text("inner", "←");
+    assert_eq!(harness.value.0.inner.count, 2);
+    assert_eq!(harness.row("inner.count").value_text(), "2 2");
+    assert!(harness.has_any_text("No differences"));
+}
+
+#[test]
+fn missing() {
+    let mut left = config(1.0, 1);
+    left.tags = vec![1, 2];
+    let mut harness = ProbeHarness::diff(left, config(1.0, 1));
+    harness.expand("tags");
+    assert!(!harness.row("tags[0]").value_text().contains('—'));
+    assert!(harness.row("tags[1]").value_text().ends_with('—'));
+
+    // Items are not reordered in the diff.
+    let texts = &harness.row("tags[0]").texts;
+    assert!(!texts
+        .iter()
+        .any(|text| ["☰", "⏶", "⏷", "🗐", "➕"].contains(&text.as_str())));
+
+    harness.click_text("tags", "→");
+    assert_eq!(harness.value.1.tags, [1, 2]);
+}
+
+#[test]
+fn edit() {
+    let mut harness = ProbeHarness::diff(config(1.0, 1), config(1.0, 1));
+    harness.type_text("name", "b");
+    assert_eq!(harness.value.0.name, "b");
+    assert_eq!(harness.value.1.name, "a");
+    assert_eq!(harness.row("name").value_text(), "b a ← →");
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::diff(config(1.0, 1), config(2.0, 1)).read_only(true);
+    assert_eq!(harness.row("speed").value_text(), "1 2");
+    harness.type_text("name", "b");
+    assert_eq!(harness.value.0.name, "a");
+}
+
+#[test]
+fn leaf() {
+    let mut harness = ProbeHarness::diff(1u32, 2u32);
+    assert_eq!(harness.row("").value_text(), "1 2 ← →");
+    harness.click_text("", "←");
+    assert_eq!(harness.value, (2, 2));
+}
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use egui_probe::testing::ProbeHarness;
+
+#[test]
+fn fitting_unit() {
+    let harness = ProbeHarness::new(Duration::from_millis(1500));
+    assert_eq!(harness.row("").value_text(), "1.5 s");
+
+    let harness = ProbeHarness::new(Duration::from_micros(20));
+    assert_eq!(harness.row("").value_text(), "20 µs");
+
+    let harness = ProbeHarness::new(Duration::ZERO);
+    assert_eq!(harness.row("").value_text(), "0 s");
+}
+
+#[test]
+fn typed() {
+    let mut harness = ProbeHarness::new(Duration::from_secs(1));
+    harness.type_text("", "2.25");
+    assert_eq!(harness.value, Duration::from_millis(2250));
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn unit_selected() {
+    let mut harness = ProbeHarness::new(Duration::from_millis(1500));
+    harness.click_text("", "s");
+    harness.click_any_text("ms");
+    assert_eq!(harness.row("").value_text(), "1500 ms");
+    assert_eq!(harness.value, Duration::from_millis(1500));
+
+    // Unit is kept when the value changes.
+    harness.type_text("", "20");
+    assert_eq!(harness.value, Duration::from_millis(20));
+    assert_eq!(harness.row("").value_text(), "20 ms");
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(Duration::from_secs(90)).read_only(true);
+    assert_eq!(harness.row("").value_text(), "1.5 min");
+
+    harness.type_text("", "5");
+    assert_eq!(harness.value, Duration::from_secs(90));
+}
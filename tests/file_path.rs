@@ -0,0 +1,158 @@
+use std::{fs, path::PathBuf};
+
+use egui_probe::{egui, file_path, testing::ProbeHarness, EguiProbe, PathFilter, Style};
+
+/// Creates directory with `sub` directory and `a.png` and `b.txt` files.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("egui_probe_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("sub")).unwrap();
+    fs::write(dir.join("a.png"), b"").unwrap();
+    fs::write(dir.join("b.txt"), b"").unwrap();
+    dir
+}
+
+struct Assets {
+    image: PathBuf,
+    output: Option<PathBuf>,
+}
+
+impl EguiProbe for Assets {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Assets")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        let images = PathFilter {
+            extensions: &["png"],
+            dir_only: false,
+        };
+        let dirs = PathFilter {
+            extensions: &[],
+            dir_only: true,
+        };
+        f("image", &mut file_path(&mut self.image, images));
+        f("output", &mut file_path(&mut self.output, dirs));
+    }
+}
+
+fn has_text(harness: &ProbeHarness<impl EguiProbe>, path: &str, text: &str) -> bool {
+    harness.row(path).texts.iter().any(|t| t == text)
+}
+
+#[test]
+fn typed() {
+    let mut harness = ProbeHarness::new(PathBuf::new());
+    harness.type_text("", "assets/image.png");
+    assert_eq!(harness.value, PathBuf::from("assets/image.png"));
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn existence_cached() {
+    let dir = temp_dir("existence_cached");
+    let path = dir.join("c.txt");
+    let mut harness = ProbeHarness::new(path.clone());
+    assert!(has_text(&harness, "", "⚠"));
+
+    // File system is not checked again right away.
+    fs::write(&path, b"").unwrap();
+    harness.run();
+    assert!(has_text(&harness, "", "⚠"));
+
+    for _ in 0..150 {
+        harness.step();
+    }
+    assert!(has_text(&harness, "", "📄"));
+}
+
+#[test]
+fn existence() {
+    let dir = temp_dir("existence");
+    assert!(has_text(&ProbeHarness::new(dir.clone()), "", "📁"));
+    assert!(has_text(&ProbeHarness::new(dir.join("a.png")), "", "📄"));
+    assert!(has_text(&ProbeHarness::new(dir.join("missing")), "", "⚠"));
+
+    // Empty path has no status.
+    let harness = ProbeHarness::new(PathBuf::new());
+    assert!(!has_text(&harness, "", "⚠"));
+}
+
+#[test]
+fn browse() {
+    let dir = temp_dir("browse");
+    let mut harness = ProbeHarness::new(dir.clone());
+    harness.click_text("", "🗁");
+    for entry in ["📁 sub", "📄 a.png", "📄 b.txt"] {
+        assert!(harness.has_any_text(entry), "no `{entry}`");
+    }
+    assert!(harness.any_text_rect("📁 sub").min.y < harness.any_text_rect("📄 a.png").min.y);
+
+    harness.click_any_text("📄 b.txt");
+    assert_eq!(harness.value, dir.join("b.txt"));
+    assert_eq!(harness.changed_paths(), [""]);
+    assert!(!harness.has_any_text("📄 a.png"));
+}
+
+#[test]
+fn browse_directories() {
+    let dir = temp_dir("browse_directories");
+    let mut harness = ProbeHarness::new(dir.join("a.png"));
+    harness.click_text("", "🗁");
+    harness.click_any_text("📁 sub");
+    assert!(harness.has_any_text("empty"));
+
+    harness.click_any_text("⬆");
+    assert!(harness.has_any_text("📄 a.png"));
+    assert_eq!(harness.value, dir.join("a.png"));
+}
+
+#[test]
+fn filtered() {
+    let dir = temp_dir("filtered");
+    let mut harness = ProbeHarness::new(Assets {
+        image: dir.join("b.txt"),
+        output: Some(dir.clone()),
+    });
+    harness.expand("");
+    assert!(has_text(&harness, "image", "⚠"));
+    assert!(has_text(&harness, "output", "📁"));
+
+    harness.click_text("image", "🗁");
+    assert!(harness.has_any_text("📄 a.png"));
+    assert!(!harness.has_any_text("📄 b.txt"));
+
+    harness.click_any_text("📄 a.png");
+    assert_eq!(harness.value.image, dir.join("a.png"));
+    assert!(has_text(&harness, "image", "📄"));
+}
+
+#[test]
+fn dir_only() {
+    let dir = temp_dir("dir_only");
+    let mut harness = ProbeHarness::new(Assets {
+        image: PathBuf::new(),
+        output: Some(dir.clone()),
+    });
+    harness.expand("");
+    harness.click_text("output", "🗁");
+    assert!(!harness.has_any_text("📄 a.png"));
+
+    harness.click_any_text("📁 sub");
+    harness.click_any_text("Select");
+    assert_eq!(harness.value.output, Some(dir.join("sub")));
+}
+
+#[test]
+fn read_only() {
+    let dir = temp_dir("read_only");
+    let mut harness = ProbeHarness::new(dir.clone()).read_only(true);
+    assert!(!has_text(&harness, "", "🗁"));
+
+    harness.type_text("", "other");
+    assert_eq!(harness.value, dir);
+}
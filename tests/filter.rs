@@ -0,0 +1,184 @@
+use egui_probe::{egui, testing::ProbeHarness, EguiProbe, Probe, Style};
+
+#[derive(Default)]
+struct Inner {
+    alpha: u32,
+    beta: u32,
+}
+
+impl EguiProbe for Inner {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Inner")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("alpha", &mut self.alpha);
+        f("beta", &mut self.beta);
+    }
+}
+
+struct Root {
+    inner: Inner,
+    other: Inner,
+    gamma: u32,
+    extra: Vec<Inner>,
+}
+
+impl EguiProbe for Root {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Root")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("inner", &mut self.inner);
+        f("other", &mut self.other);
+        f("gamma", &mut self.gamma);
+        f("extra", &mut self.extra);
+    }
+}
+
+fn root() -> Root {
+    Root {
+        inner: Inner { alpha: 1, beta: 2 },
+        other: Inner { alpha: 3, beta: 4 },
+        gamma: 5,
+        extra: Vec::new(),
+    }
+}
+
+fn filtered(filter_descendants: bool) -> ProbeHarness<Root> {
+    let show = if filter_descendants {
+        |value: &mut Root, ui: &mut egui::Ui, read_only| {
+            Probe::new("value", value)
+                .filter(true)
+                .read_only(read_only)
+                .show(ui)
+        }
+    } else {
+        |value: &mut Root, ui: &mut egui::Ui, read_only| {
+            Probe::new("value", value)
+                .filter(true)
+                .filter_descendants(false)
+                .read_only(read_only)
+                .show(ui)
+        }
+    };
+    ProbeHarness::with_show(root(), show)
+}
+
+fn set_filter(harness: &mut ProbeHarness<Root>, filter: &str) {
+    let pos = harness.any_text_rect("filter").center();
+    harness.type_text_at(pos, filter);
+}
+
+fn paths(harness: &ProbeHarness<Root>) -> Vec<&str> {
+    harness.rows().iter().map(|row| row.path.as_str()).collect()
+}
+
+/// Returns highlighted parts of texts painted in the last frame.
+fn highlighted(harness: &ProbeHarness<Root>) -> Vec<String> {
+    fn collect(shape: &egui::Shape, out: &mut Vec<String>) {
+        match shape {
+            egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| collect(shape, out)),
+            egui::Shape::Text(text) => {
+                let job = &text.galley.job;
+                for section in &job.sections {
+                    if section.format.background != egui::Color32::TRANSPARENT {
+                        out.push(job.text[section.byte_range.clone()].to_owned());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut out = Vec::new();
+    for clipped in harness.shapes() {
+        collect(&clipped.shape, &mut out);
+    }
+    out
+}
+
+#[test]
+fn matching_rows() {
+    let mut harness = filtered(true);
+    assert!(!harness.has_row("inner"));
+
+    set_filter(&mut harness, "GAM");
+    assert_eq!(paths(&harness), ["", "gamma"]);
+    assert_eq!(highlighted(&harness), ["gam"]);
+}
+
+#[test]
+fn ancestors_expanded() {
+    let mut harness = filtered(true);
+
+    set_filter(&mut harness, "alp");
+    assert_eq!(
+        paths(&harness),
+        ["", "inner", "inner.alpha", "other", "other.alpha"]
+    );
+    assert_eq!(harness.row("inner").open, Some(true));
+    assert_eq!(highlighted(&harness), ["alp", "alp"]);
+}
+
+#[test]
+fn matching_subtree_not_filtered() {
+    let mut harness = filtered(true);
+
+    set_filter(&mut harness, "inn");
+    harness.expand("inner");
+    assert_eq!(paths(&harness), ["", "inner", "inner.alpha", "inner.beta"]);
+    assert_eq!(highlighted(&harness), ["inn"]);
+}
+
+#[test]
+fn descendants_not_shown() {
+    let mut harness = filtered(false);
+
+    set_filter(&mut harness, "alp");
+    assert_eq!(paths(&harness), [""]);
+}
+
+#[test]
+fn matches_follow_edits() {
+    let mut harness = filtered(true);
+
+    set_filter(&mut harness, "alp");
+    harness.type_text("inner.alpha", "7");
+    assert_eq!(harness.value.inner.alpha, 7);
+    assert_eq!(
+        paths(&harness),
+        ["", "inner", "inner.alpha", "other", "other.alpha"]
+    );
+}
+
+#[test]
+fn matches_follow_structure() {
+    let mut harness = filtered(true);
+
+    set_filter(&mut harness, "alp");
+    harness.value.extra.push(Inner { alpha: 6, beta: 7 });
+    harness.run();
+    assert_eq!(
+        paths(&harness),
+        [
+            "",
+            "inner",
+            "inner.alpha",
+            "other",
+            "other.alpha",
+            "extra",
+            "extra[0]",
+            "extra[0].alpha"
+        ]
+    );
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "glam")]
+
+use egui_probe::{egui, normalized, testing::ProbeHarness, EguiProbe, Style};
+use glam::{Affine2, Affine3A, IVec2, Mat2, Mat3A, Quat, Vec2, Vec3, Vec3A};
+
+struct Direction {
+    dir: Vec2,
+    rotation: Quat,
+}
+
+impl EguiProbe for Direction {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Direction")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("dir", &mut normalized(&mut self.dir));
+        f("rotation", &mut normalized(&mut self.rotation));
+    }
+}
+
+fn harness() -> ProbeHarness<Direction> {
+    let mut harness = ProbeHarness::new(Direction {
+        dir: Vec2::X,
+        rotation: Quat::IDENTITY,
+    });
+    harness.expand("");
+    harness
+}
+
+fn approx_eq(a: Quat, b: Quat) -> bool {
+    a.abs_diff_eq(b, 1e-4) || a.abs_diff_eq(-b, 1e-4)
+}
+
+#[test]
+fn vector_components() {
+    let mut harness = ProbeHarness::new(Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(harness.row("").value_text(), "1 2 3");
+
+    harness.type_text_at(harness.text_rect("", "3").center(), "5");
+    assert_eq!(harness.value, Vec3::new(1.0, 2.0, 5.0));
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn integer_vector() {
+    let mut harness = ProbeHarness::new(IVec2::new(-1, 2));
+    assert_eq!(harness.row("").value_text(), "-1 2");
+
+    harness.type_text("", "7");
+    assert_eq!(harness.value, IVec2::new(7, 2));
+}
+
+#[test]
+fn quat_euler() {
+    let mut harness = ProbeHarness::new(Quat::IDENTITY);
+    assert_eq!(harness.row("").value_text(), "0° 0° 0° xyzw");
+
+    harness.type_text("", "90");
+    assert!(approx_eq(
+        harness.value,
+        Quat::from_rotation_x(90f32.to_radians())
+    ));
+}
+
+#[test]
+fn quat_raw() {
+    let mut harness = ProbeHarness::new(Quat::IDENTITY);
+    harness.click_text("", "xyzw");
+    assert_eq!(harness.row("").value_text(), "0 0 0 1 xyzw");
+
+    harness.type_text("", "1");
+    assert_eq!(harness.value, Quat::from_xyzw(1.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn matrix_grid() {
+    let mut harness = ProbeHarness::new(Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(harness.row("").texts.len(), 4);
+
+    // Columns of the matrix are columns of the grid.
+    let one = harness.text_rect("", "1");
+    assert_eq!(one.min.x, harness.text_rect("", "2").min.x);
+    assert!(one.min.y < harness.text_rect("", "2").min.y);
+    assert_eq!(one.min.y, harness.text_rect("", "3").min.y);
+
+    harness.type_text_at(harness.text_rect("", "2").center(), "5");
+    assert_eq!(harness.value.col(0).y, 5.0);
+}
+
+#[test]
+fn affine_decomposed() {
+    let mut harness = ProbeHarness::new(Affine3A::from_scale_rotation_translation(
+        Vec3::splat(2.0),
+        Quat::IDENTITY,
+        Vec3::new(1.0, 0.0, 0.0),
+    ));
+    harness.expand("");
+    assert_eq!(harness.row("translation").value_text(), "1 0 0");
+    assert_eq!(harness.row("rotation").value_text(), "0° 0° 0° xyzw");
+    assert_eq!(harness.row("scale").value_text(), "2 2 2");
+
+    harness.type_text("translation", "3");
+    assert_eq!(harness.value.translation, Vec3A::new(3.0, 0.0, 0.0));
+    assert_eq!(
+        harness.value.matrix3,
+        Mat3A::from_diagonal(Vec3::splat(2.0))
+    );
+}
+
+#[test]
+fn affine2_rotation() {
+    let mut harness = ProbeHarness::new(Affine2::IDENTITY);
+    harness.expand("");
+    assert_eq!(harness.row("rotation").value_text(), "0°");
+
+    harness.type_text("rotation", "90");
+    let (_, rotation, _) = harness.value.to_scale_angle_translation();
+    assert!((rotation - 90f32.to_radians()).abs() < 1e-4);
+}
+
+#[test]
+fn normalized_vector() {
+    let mut harness = harness();
+    harness.type_text_at(harness.text_rect("dir", "0").center(), "1");
+    assert!(harness
+        .value
+        .dir
+        .abs_diff_eq(Vec2::splat(0.5f32.sqrt()), 1e-4));
+
+    harness.type_text("dir", "0");
+    assert!(harness.value.dir.abs_diff_eq(Vec2::Y, 1e-4));
+}
+
+#[test]
+fn normalized_zero() {
+    // Zero length vector can not be normalized, so the change is discarded.
+    let mut harness = harness();
+    harness.type_text("dir", "0");
+    assert_eq!(harness.value.dir, Vec2::X);
+}
+
+#[test]
+fn normalized_quat() {
+    let mut harness = harness();
+    harness.click_text("rotation", "xyzw");
+
+    harness.type_text("rotation", "1");
+    let expected = Quat::from_xyzw(1.0, 0.0, 0.0, 1.0).normalize();
+    assert!(harness.value.rotation.abs_diff_eq(expected, 1e-4));
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(Quat::IDENTITY).read_only(true);
+    harness.type_text("", "90");
+    assert_eq!(harness.value, Quat::IDENTITY);
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "hashbrown")]
+
+mod common;
+
+use common::field;
+
+#[test]
+fn hash_map() {
+    let mut map = hashbrown::HashMap::new();
+    map.insert("foo".to_owned(), 1u32);
+
+    let mut harness = field(map);
+    assert_eq!(harness.row("field").value_text(), "[1] + new key");
+
+    harness.type_text_at(harness.text_rect("field", "new key").center(), "bar");
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.get("bar"), Some(&0));
+
+    harness.type_text("field.bar", "3");
+    assert_eq!(harness.value.0["bar"], 3);
+
+    harness.click_text("field.foo", "-");
+    assert!(!harness.value.0.contains_key("foo"));
+}
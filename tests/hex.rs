@@ -0,0 +1,121 @@
+use egui_probe::{egui, hex, testing::ProbeHarness, EguiProbe, Style};
+
+struct Blob {
+    data: Vec<u8>,
+    key: [u8; 2],
+}
+
+impl EguiProbe for Blob {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Blob")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("data", &mut hex(&mut self.data));
+        f("key", &mut hex(&mut self.key));
+    }
+}
+
+fn harness(data: &[u8]) -> ProbeHarness<Blob> {
+    let mut harness = ProbeHarness::new(Blob {
+        data: data.to_vec(),
+        key: [0x12, 0x34],
+    });
+    harness.expand("");
+    harness
+}
+
+fn has_text(harness: &ProbeHarness<Blob>, path: &str, text: &str) -> bool {
+    harness.row(path).texts.iter().any(|t| t == text)
+}
+
+fn is_above(harness: &ProbeHarness<Blob>, a: &str, b: &str) -> bool {
+    harness.any_text_rect(a).min.y < harness.any_text_rect(b).min.y
+}
+
+#[test]
+fn grid() {
+    let harness = harness(b"Hi!\x00");
+    assert!(!harness.has_row("data[0]"));
+    for text in ["0000", "48", "69", "21", "00", "Hi!."] {
+        assert!(has_text(&harness, "data", text), "no `{text}`");
+    }
+    assert!(has_text(&harness, "key", "12"));
+}
+
+#[test]
+fn offsets() {
+    let harness = harness(&[0; 40]);
+    for text in ["0000", "0010", "0020"] {
+        assert!(has_text(&harness, "data", text), "no `{text}`");
+    }
+    assert!(is_above(&harness, "0010", "0020"));
+}
+
+#[test]
+fn overwrite() {
+    let mut harness = harness(&[1, 2, 3]);
+    let pos = harness.any_text_rect("02").center();
+    harness.type_text_at(pos, "abc");
+    assert_eq!(harness.value.data, [1, 0xab, 0xc3]);
+    assert_eq!(harness.changed_paths(), ["data"]);
+}
+
+#[test]
+fn append() {
+    let mut harness = harness(&[1]);
+    let pos = harness.any_text_rect("··").center();
+    harness.type_text_at(pos, "ff0");
+    assert_eq!(harness.value.data, [1, 0xff, 0]);
+}
+
+#[test]
+fn fixed_size() {
+    let mut harness = harness(&[]);
+    let pos = harness.any_text_rect("34").center();
+    harness.type_text_at(pos, "5678");
+    assert_eq!(harness.value.key, [0x12, 0x56]);
+}
+
+#[test]
+fn paste() {
+    let mut harness = harness(&[1, 2, 3]);
+    harness.click_text("data", "02");
+    harness.paste("data", "de ad be ef");
+    assert_eq!(harness.value.data, [1, 0xde, 0xad, 0xbe, 0xef]);
+
+    harness.paste("data", "not hex");
+    assert_eq!(harness.value.data, [1, 0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[test]
+fn arrows() {
+    let mut harness = harness(&[1, 2, 3]);
+    harness.click_text("data", "01");
+    harness.press_key(egui::Key::ArrowRight);
+    harness.press_key(egui::Key::ArrowRight);
+    harness.paste("data", "0xff");
+    assert_eq!(harness.value.data, [1, 2, 0xff]);
+}
+
+#[test]
+fn ascii_column() {
+    let mut harness = harness(b"abc");
+    let rect = harness.any_text_rect("abc");
+    harness.click_at(egui::pos2(rect.max.x - 2.0, rect.center().y));
+    harness.paste("data", "41");
+    assert_eq!(harness.value.data, b"abA");
+}
+
+#[test]
+fn read_only() {
+    let mut harness = harness(&[1]).read_only(true);
+    assert!(!harness.has_any_text("··"));
+    harness.click_text("data", "01");
+    harness.paste("data", "ff");
+    assert_eq!(harness.value.data, [1]);
+}
@@ -0,0 +1,90 @@
+use egui_probe::{
+    egui::{Key, KeyboardShortcut, Modifiers},
+    testing::ProbeHarness,
+    ProbeHistory,
+};
+
+const UNDO: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+const REDO: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Y);
+
+fn harness(max_len: usize) -> ProbeHarness<(i32, ProbeHistory<i32>)> {
+    ProbeHarness::history(0, ProbeHistory::with_max_len(max_len))
+}
+
+#[test]
+fn undo_redo() {
+    let mut harness = harness(100);
+    assert!(!harness.value.1.can_undo());
+
+    harness.type_text("", "1");
+    harness.type_text("", "2");
+    assert!(harness.value.1.can_undo());
+
+    harness.press_shortcut(UNDO);
+    assert_eq!(harness.value.0, 1);
+    assert_eq!(harness.changed_paths(), [""]);
+
+    harness.press_shortcut(UNDO);
+    assert_eq!(harness.value.0, 0);
+    assert!(!harness.value.1.can_undo());
+
+    harness.press_shortcut(REDO);
+    assert_eq!(harness.value.0, 1);
+
+    let shift_z = KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+    harness.press_shortcut(shift_z);
+    assert_eq!(harness.value.0, 2);
+    assert!(!harness.value.1.can_redo());
+}
+
+#[test]
+fn edit_clears_redo() {
+    let mut harness = harness(100);
+    harness.type_text("", "1");
+    harness.press_shortcut(UNDO);
+    assert!(harness.value.1.can_redo());
+
+    harness.type_text("", "5");
+    assert!(!harness.value.1.can_redo());
+
+    harness.press_shortcut(UNDO);
+    assert_eq!(harness.value.0, 0);
+}
+
+#[test]
+fn gesture_is_one_step() {
+    let mut harness = harness(100);
+    harness.drag("", egui_probe::egui::vec2(40.0, 0.0));
+    assert!(harness.value.0 > 1);
+
+    harness.press_shortcut(UNDO);
+    assert_eq!(harness.value.0, 0);
+    assert!(!harness.value.1.can_undo());
+}
+
+#[test]
+fn max_len() {
+    let mut harness = harness(2);
+    for text in ["1", "2", "3"] {
+        harness.type_text("", text);
+    }
+
+    harness.press_shortcut(UNDO);
+    harness.press_shortcut(UNDO);
+    assert_eq!(harness.value.0, 1);
+    assert!(!harness.value.1.can_undo());
+
+    harness.press_shortcut(UNDO);
+    assert_eq!(harness.value.0, 1);
+}
+
+#[test]
+fn no_history() {
+    let mut harness = harness(0);
+    harness.type_text("", "1");
+    assert_eq!(harness.value.0, 1);
+    assert!(!harness.value.1.can_undo());
+
+    harness.press_shortcut(UNDO);
+    assert_eq!(harness.value.0, 1);
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use common::field;
+
+#[test]
+fn hash_map() {
+    let mut harness = field(HashMap::from([("foo".to_owned(), 1u32)]));
+    assert_eq!(harness.row("field").value_text(), "[1] + new key");
+    assert_eq!(harness.row("field.foo").value_text(), "1 -");
+
+    harness.type_text_at(harness.text_rect("field", "new key").center(), "bar");
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.get("bar"), Some(&0));
+    assert_eq!(harness.changed_paths(), ["field"]);
+    assert!(harness.has_row("field.bar"));
+
+    harness.click_text("field.foo", "-");
+    assert!(!harness.value.0.contains_key("foo"));
+    assert_eq!(harness.changed_paths(), ["field.foo"]);
+}
+
+#[test]
+fn hash_map_duplicate_key() {
+    let mut harness = field(HashMap::from([("foo".to_owned(), 1u32)]));
+
+    harness.type_text_at(harness.text_rect("field", "new key").center(), "foo");
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.len(), 1);
+    assert_eq!(harness.value.0["foo"], 1);
+    assert!(harness.changed_paths().is_empty());
+}
+
+#[test]
+fn btree_map() {
+    let mut harness = field(BTreeMap::from([(2u32, "two".to_owned())]));
+
+    harness.type_text_at(
+        harness.text_rect("field", "new key").center(),
+        "not a number",
+    );
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.len(), 1);
+
+    harness.type_text_at(harness.text_rect("field", "not a number").center(), "1");
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.keys().collect::<Vec<_>>(), [&1, &2]);
+
+    let paths: Vec<_> = harness.rows().iter().map(|row| row.path.as_str()).collect();
+    assert_eq!(paths, ["", "field", "field.1", "field.2"]);
+
+    harness.type_text("field.2", "deux");
+    assert_eq!(harness.value.0[&2], "deux");
+    assert_eq!(harness.changed_paths(), ["field.2"]);
+}
+
+#[test]
+fn map_read_only() {
+    let harness = field(BTreeMap::from([(1u32, 1u32)])).read_only(true);
+    assert_eq!(harness.row("field").value_text(), "[1]");
+    assert_eq!(harness.row("field.1").value_text(), "1");
+}
+
+#[test]
+fn hash_set() {
+    let mut harness = field(HashSet::from(["foo".to_owned()]));
+    assert_eq!(harness.row("field.foo").value_text(), "-");
+
+    harness.type_text_at(harness.text_rect("field", "new key").center(), "bar");
+    harness.click_text("field", "+");
+    assert!(harness.value.0.contains("bar"));
+
+    harness.click_text("field.foo", "-");
+    assert_eq!(harness.value.0, HashSet::from(["bar".to_owned()]));
+}
+
+#[test]
+fn btree_set() {
+    let mut harness = field(BTreeSet::from([3u32, 1]));
+    let paths: Vec<_> = harness.rows().iter().map(|row| row.path.as_str()).collect();
+    assert_eq!(paths, ["", "field", "field.1", "field.3"]);
+
+    harness.type_text_at(harness.text_rect("field", "new key").center(), "3");
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.len(), 2);
+
+    harness.click_text("field.1", "-");
+    assert_eq!(harness.value.0, BTreeSet::from([3]));
+
+    let harness = field(BTreeSet::from([3u32])).read_only(true);
+    assert_eq!(harness.row("field").value_text(), "[1]");
+    assert!(harness.row("field.3").texts.is_empty());
+}
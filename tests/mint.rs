@@ -0,0 +1,56 @@
+#![cfg(feature = "mint")]
+
+use egui_probe::testing::ProbeHarness;
+use mint::{ColumnMatrix2, Point3, Quaternion, RowMatrix2, Vector2, Vector3};
+
+#[test]
+fn vector_components() {
+    let mut harness = ProbeHarness::new(Vector3::from([1.0f32, 2.0, 3.0]));
+    assert_eq!(harness.row("").value_text(), "1 2 3");
+
+    harness.type_text_at(harness.text_rect("", "3").center(), "5");
+    assert_eq!(harness.value, Vector3::from([1.0, 2.0, 5.0]));
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn point_components() {
+    let mut harness = ProbeHarness::new(Point3::from([1u8, 2, 3]));
+    harness.type_text("", "7");
+    assert_eq!(harness.value, Point3::from([7, 2, 3]));
+}
+
+#[test]
+fn row_and_column_matrices() {
+    let column = ColumnMatrix2::from([[1.0f32, 2.0], [3.0, 4.0]]);
+    let row = RowMatrix2::from(column);
+
+    // Both layouts of the same matrix look the same.
+    let column = ProbeHarness::new(column);
+    let row = ProbeHarness::new(row);
+    for text in ["1", "2", "3", "4"] {
+        assert_eq!(column.text_rect("", text), row.text_rect("", text));
+    }
+
+    // First column of the matrix is the first column of the grid.
+    let one = column.text_rect("", "1");
+    assert_eq!(one.min.x, column.text_rect("", "2").min.x);
+    assert!(one.min.y < column.text_rect("", "2").min.y);
+}
+
+#[test]
+fn matrix_edit() {
+    let mut harness = ProbeHarness::new(RowMatrix2::from([[1.0f32, 2.0], [3.0, 4.0]]));
+    harness.type_text_at(harness.text_rect("", "2").center(), "5");
+    assert_eq!(harness.value.x, Vector2::from([1.0, 5.0]));
+}
+
+#[test]
+fn quaternion() {
+    let mut harness = ProbeHarness::new(Quaternion::from([0.0f32, 0.0, 0.0, 1.0]));
+    assert_eq!(harness.row("").value_text(), "0° 0° 0° xyzw");
+
+    harness.click_text("", "xyzw");
+    harness.type_text("", "2");
+    assert_eq!(harness.value, Quaternion::from([2.0, 0.0, 0.0, 1.0]));
+}
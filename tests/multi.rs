@@ -0,0 +1,236 @@
+use egui_probe::{
+    egui::{self, Color32},
+    multi_value,
+    testing::ProbeHarness,
+    EguiProbe, MultiValue, ProbeMulti, Style,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Walk,
+    Run,
+}
+
+impl EguiProbe for Mode {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.horizontal(|ui| {
+            let r = ui.selectable_value(self, Mode::Walk, "Walk");
+            r | ui.selectable_value(self, Mode::Run, "Run")
+        })
+        .inner
+    }
+
+    fn as_multi_value(&mut self) -> Option<&mut dyn MultiValue> {
+        Some(self)
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    count: u32,
+}
+
+impl EguiProbe for Inner {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Inner")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("count", &mut self.count);
+    }
+}
+
+#[derive(Debug)]
+struct Entity {
+    speed: f32,
+    visible: bool,
+    name: String,
+    color: Color32,
+    mode: Mode,
+    inner: Inner,
+    tags: Vec<u32>,
+}
+
+impl EguiProbe for Entity {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Entity")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("speed", &mut self.speed);
+        f("visible", &mut self.visible);
+        f("name", &mut self.name);
+        f("color", &mut self.color);
+        f("mode", &mut self.mode);
+        f("inner", &mut self.inner);
+        f("tags", &mut multi_value(&mut self.tags));
+    }
+}
+
+fn entity(speed: f32, name: &str) -> Entity {
+    Entity {
+        speed,
+        visible: false,
+        name: name.to_owned(),
+        color: Color32::RED,
+        mode: Mode::Walk,
+        inner: Inner { count: 1 },
+        tags: vec![1],
+    }
+}
+
+fn harness() -> ProbeHarness<Vec<Entity>> {
+    let mut harness = ProbeHarness::multi(vec![entity(1.0, "a"), entity(2.0, "a")]);
+    harness.expand_all();
+    harness
+}
+
+#[test]
+fn single_table() {
+    let harness = harness();
+    let paths: Vec<_> = harness.rows().iter().map(|row| row.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        [
+            "",
+            "speed",
+            "visible",
+            "name",
+            "color",
+            "mode",
+            "inner",
+            "inner.count",
+            "tags",
+            "tags[0]"
+        ]
+    );
+}
+
+#[test]
+fn mixed() {
+    let harness = harness();
+    assert_eq!(harness.row("speed").value_text(), "—");
+    assert_eq!(harness.row("name").value_text(), "a");
+}
+
+#[test]
+fn reveal_mixed() {
+    let mut harness = harness();
+    harness.click_text("speed", "—");
+    assert_eq!(harness.row("speed").value_text(), "1");
+    assert_eq!(harness.value[0].speed, 1.0);
+    assert_eq!(harness.value[1].speed, 2.0);
+    assert!(harness.changed_paths().is_empty());
+
+    harness.type_text("speed", "5");
+    assert_eq!(harness.value[0].speed, 5.0);
+    assert_eq!(harness.value[1].speed, 5.0);
+    assert_eq!(harness.row("speed").value_text(), "5");
+}
+
+#[test]
+fn edit_all() {
+    let mut harness = harness();
+    harness.click("visible");
+    harness.type_text("name", "b");
+    harness.type_text("inner.count", "3");
+    harness.click_text("mode", "Run");
+    assert!(harness.value.iter().all(|entity| entity.visible));
+    assert!(harness.value.iter().all(|entity| entity.name == "b"));
+    assert!(harness.value.iter().all(|entity| entity.inner.count == 3));
+    assert!(harness.value.iter().all(|entity| entity.mode == Mode::Run));
+}
+
+#[test]
+fn edit_collection() {
+    let mut harness = harness();
+    harness.click_text("tags", "+");
+    assert!(harness.value.iter().all(|entity| entity.tags == [1, 0]));
+}
+
+#[test]
+fn mixed_nested() {
+    let mut first = entity(1.0, "a");
+    first.inner.count = 2;
+    let mut harness = ProbeHarness::multi(vec![first, entity(1.0, "a")]);
+    harness.expand_all();
+    assert_eq!(harness.row("inner.count").value_text(), "—");
+    assert_eq!(harness.row("speed").value_text(), "1");
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::multi(vec![entity(1.0, "a"), entity(2.0, "a")]).read_only(true);
+    harness.expand_all();
+    harness.click("visible");
+    assert!(harness.value.iter().all(|entity| !entity.visible));
+}
+
+#[test]
+fn no_values() {
+    let harness = ProbeHarness::multi(Vec::<Entity>::new());
+    assert!(harness.has_any_text("value"));
+    assert!(harness.rows().is_empty());
+}
+
+#[test]
+fn mixed_sequences() {
+    let mut harness = ProbeHarness::multi(vec![vec![1u32, 2, 3], vec![1, 5]]);
+    harness.expand_all();
+    let is_mixed =
+        |harness: &ProbeHarness<_>, path| harness.row(path).texts.iter().any(|text| text == "—");
+    assert!(!is_mixed(&harness, "[0]"));
+    assert!(is_mixed(&harness, "[1]"));
+    assert!(is_mixed(&harness, "[2]"));
+
+    harness.click_text("[1]", "—");
+    harness.type_text_at(harness.text_rect("[1]", "2").center(), "7");
+    assert_eq!(harness.value, [vec![1, 7, 3], vec![1, 7]]);
+    assert!(!is_mixed(&harness, "[1]"));
+    assert!(is_mixed(&harness, "[2]"));
+}
+
+#[test]
+fn changed_elsewhere() {
+    let mut harness = harness();
+    harness.value[1].name = "b".to_owned();
+    harness.run();
+    assert_eq!(harness.row("name").value_text(), "—");
+
+    harness.value[1].speed = 1.0;
+    harness.run();
+    assert_eq!(harness.row("speed").value_text(), "1");
+}
+
+#[test]
+fn generation() {
+    let mut harness = ProbeHarness::with_show(
+        (vec![entity(1.0, "a"), entity(2.0, "a")], 0u32),
+        |(values, generation), ui, read_only| {
+            let mut values: Vec<&mut Entity> = values.iter_mut().collect();
+            ProbeMulti::new("value", &mut values)
+                .generation(*generation)
+                .read_only(read_only)
+                .show(ui)
+        },
+    );
+    harness.expand_all();
+    assert_eq!(harness.row("speed").value_text(), "—");
+
+    // Differing values are reused until generation changes.
+    harness.value.0[1].name = "b".to_owned();
+    harness.run();
+    assert_eq!(harness.row("name").value_text(), "a");
+
+    harness.value.1 += 1;
+    harness.run();
+    assert_eq!(harness.row("name").value_text(), "—");
+}
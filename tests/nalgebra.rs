@@ -0,0 +1,96 @@
+#![cfg(feature = "nalgebra")]
+
+use egui_probe::testing::ProbeHarness;
+use nalgebra::{
+    Isometry2, Isometry3, Matrix2x3, Point2, Translation3, UnitComplex, UnitQuaternion, Vector3,
+};
+
+#[test]
+fn vector_components() {
+    let mut harness = ProbeHarness::new(Vector3::new(1.0f32, 2.0, 3.0));
+    assert_eq!(harness.row("").value_text(), "1 2 3");
+
+    harness.type_text_at(harness.text_rect("", "3").center(), "5");
+    assert_eq!(harness.value, Vector3::new(1.0, 2.0, 5.0));
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn point_components() {
+    let mut harness = ProbeHarness::new(Point2::new(1i32, 2));
+    assert_eq!(harness.row("").value_text(), "1 2");
+
+    harness.type_text("", "7");
+    assert_eq!(harness.value, Point2::new(7, 2));
+}
+
+#[test]
+fn matrix_grid() {
+    let mut harness = ProbeHarness::new(Matrix2x3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0));
+    assert_eq!(harness.row("").texts.len(), 6);
+
+    // Rows of the matrix are rows of the grid.
+    let one = harness.text_rect("", "1");
+    assert_eq!(one.min.y, harness.text_rect("", "3").min.y);
+    assert_eq!(one.min.x, harness.text_rect("", "4").min.x);
+    assert!(one.min.y < harness.text_rect("", "4").min.y);
+
+    harness.type_text_at(harness.text_rect("", "6").center(), "9");
+    assert_eq!(harness.value[(1, 2)], 9.0);
+}
+
+#[test]
+fn unit_quaternion_euler() {
+    let mut harness = ProbeHarness::new(UnitQuaternion::<f32>::identity());
+    assert_eq!(harness.row("").value_text(), "0° 0° 0° xyzw");
+
+    harness.type_text("", "90");
+    let expected = UnitQuaternion::from_euler_angles(90f32.to_radians(), 0.0, 0.0);
+    assert!(harness.value.angle_to(&expected) < 1e-3);
+}
+
+#[test]
+fn unit_quaternion_raw() {
+    let mut harness = ProbeHarness::new(UnitQuaternion::<f64>::identity());
+    harness.click_text("", "xyzw");
+    assert_eq!(harness.row("").value_text(), "0 0 0 1 xyzw");
+
+    harness.type_text("", "1");
+    assert!((harness.value.norm() - 1.0).abs() < 1e-6);
+    assert!((harness.value.angle() - 90f64.to_radians()).abs() < 1e-6);
+}
+
+#[test]
+fn isometry3() {
+    let mut harness = ProbeHarness::new(Isometry3::from_parts(
+        Translation3::new(1.0f32, 0.0, 0.0),
+        UnitQuaternion::identity(),
+    ));
+    harness.expand("");
+    assert_eq!(harness.row("translation").value_text(), "1 0 0");
+    assert_eq!(harness.row("rotation").value_text(), "0° 0° 0° xyzw");
+
+    harness.type_text("translation", "3");
+    assert_eq!(
+        harness.value.translation.vector,
+        Vector3::new(3.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn isometry2_rotation() {
+    let mut harness = ProbeHarness::new(Isometry2::<f64>::identity());
+    harness.expand("");
+    assert_eq!(harness.row("rotation").value_text(), "0°");
+
+    harness.type_text("rotation", "90");
+    let expected = UnitComplex::new(90f64.to_radians());
+    assert!(harness.value.rotation.angle_to(&expected).abs() < 1e-6);
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(Vector3::new(1.0f32, 2.0, 3.0)).read_only(true);
+    harness.type_text("", "5");
+    assert_eq!(harness.value, Vector3::new(1.0, 2.0, 3.0));
+}
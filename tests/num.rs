@@ -0,0 +1,54 @@
+use egui_probe::{egui, testing::ProbeHarness};
+
+macro_rules! typed {
+    ($($num_type:ident),* $(,)?) => {$({
+        let mut harness = ProbeHarness::new(1 as $num_type);
+        assert_eq!(harness.row("").value_text(), "1");
+
+        harness.type_text("", "7");
+        assert_eq!(harness.value, 7 as $num_type, stringify!($num_type));
+        assert_eq!(harness.changed_paths(), [""]);
+    })*};
+}
+
+#[test]
+fn all_types_typed() {
+    typed!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+}
+
+#[test]
+fn dragged() {
+    let mut harness = ProbeHarness::new(0.0f32);
+    harness.drag("", egui::vec2(40.0, 0.0));
+    assert!(harness.value > 0.0);
+    assert_eq!(harness.changed_paths(), [""]);
+
+    let mut harness = ProbeHarness::new(0i32);
+    harness.drag("", egui::vec2(-40.0, 0.0));
+    assert!(harness.value < 0);
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(3u8).read_only(true);
+    assert_eq!(harness.row("").value_text(), "3");
+
+    harness.type_text("", "9");
+    harness.drag("", egui::vec2(40.0, 0.0));
+    assert_eq!(harness.value, 3);
+    assert!(harness.changed_paths().is_empty());
+}
+
+#[test]
+fn angle() {
+    let mut value = core::f32::consts::PI;
+
+    let mut harness = ProbeHarness::new(egui_probe::angle(&mut value));
+    assert_eq!(harness.row("").value_text(), "180°");
+    harness.type_text("", "90");
+    drop(harness);
+    assert!((value - core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+
+    let harness = ProbeHarness::new(egui_probe::angle(&mut value)).read_only(true);
+    assert_eq!(harness.row("").value_text(), "90.0°");
+}
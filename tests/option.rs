@@ -0,0 +1,28 @@
+use egui_probe::testing::ProbeHarness;
+
+#[test]
+fn toggle_some_none() {
+    let mut harness = ProbeHarness::new(None::<u32>);
+    assert_eq!(harness.row("").value_text(), "None Some");
+
+    harness.click_text("", "Some");
+    assert_eq!(harness.value, Some(0));
+    assert_eq!(harness.changed_paths(), [""]);
+    assert_eq!(harness.row("").value_text(), "None Some 0");
+
+    harness.type_text_at(harness.text_rect("", "0").center(), "5");
+    assert_eq!(harness.value, Some(5));
+
+    harness.click_text("", "None");
+    assert_eq!(harness.value, None);
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(None::<u32>).read_only(true);
+    assert_eq!(harness.row("").value_text(), "None");
+
+    harness.value = Some(3);
+    harness.run();
+    assert_eq!(harness.row("").value_text(), "3");
+}
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use egui_probe::{
+    egui, has_path, leaf_paths, probe_path_mut, visit_paths, ClipboardValue, EguiProbe, Style,
+};
+
+/// Leaf that can be read back through its clipboard copy.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Leaf(u32);
+
+impl EguiProbe for Leaf {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        self.0.probe(ui, style)
+    }
+
+    fn as_clipboard_value(&mut self) -> Option<&mut dyn ClipboardValue> {
+        Some(self)
+    }
+}
+
+struct Inner {
+    leaf: Leaf,
+    list: Vec<Leaf>,
+}
+
+impl EguiProbe for Inner {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Inner")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("leaf", &mut self.leaf);
+        f("list", &mut self.list);
+    }
+}
+
+struct Root {
+    inner: Inner,
+    map: BTreeMap<String, Vec<Leaf>>,
+    odd: Leaf,
+}
+
+impl EguiProbe for Root {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Root")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("inner", &mut self.inner);
+        f("map", &mut self.map);
+        f("odd \"label\".x", &mut self.odd);
+    }
+}
+
+fn root() -> Root {
+    Root {
+        inner: Inner {
+            leaf: Leaf(1),
+            list: vec![Leaf(2), Leaf(3)],
+        },
+        map: BTreeMap::from([
+            ("plain".to_owned(), vec![Leaf(4)]),
+            ("a.b".to_owned(), vec![Leaf(5)]),
+            ("[x".to_owned(), vec![Leaf(6)]),
+            ("[1]".to_owned(), vec![Leaf(7)]),
+        ]),
+        odd: Leaf(8),
+    }
+}
+
+fn leaf_at(value: &mut Root, path: &str) -> Option<Leaf> {
+    probe_path_mut(value, path, |value| {
+        let copied = value.as_clipboard_value()?.copy_value();
+        copied.downcast_ref::<Leaf>().copied()
+    })
+    .flatten()
+}
+
+#[test]
+fn visit_order() {
+    let mut value = root();
+    let mut paths = Vec::new();
+    visit_paths(&mut value, &mut |path, _| paths.push(path.to_owned()));
+    assert_eq!(
+        paths,
+        [
+            "inner",
+            "inner.leaf",
+            "inner.list",
+            "inner.list[0]",
+            "inner.list[1]",
+            "map",
+            "map[1]",
+            "map[1][0]",
+            r#"map."[x""#,
+            r#"map."[x"[0]"#,
+            r#"map."a.b""#,
+            r#"map."a.b"[0]"#,
+            "map.plain",
+            "map.plain[0]",
+            r#""odd \"label\".x""#,
+        ]
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut value = root();
+    let mut paths = Vec::new();
+    visit_paths(&mut value, &mut |path, _| paths.push(path.to_owned()));
+    for path in &paths {
+        assert!(has_path(&mut value, path), "Path `{path}` is not found");
+    }
+
+    let mut leaves: Vec<_> = leaf_paths(&mut value)
+        .iter()
+        .map(|path| leaf_at(&mut value, path).unwrap().0)
+        .collect();
+    leaves.sort_unstable();
+    assert_eq!(leaves, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    assert_eq!(leaf_at(&mut value, "inner.list[1]"), Some(Leaf(3)));
+    assert_eq!(leaf_at(&mut value, r#"map."a.b"[0]"#), Some(Leaf(5)));
+    assert_eq!(leaf_at(&mut value, "map[1][0]"), Some(Leaf(7)));
+    assert_eq!(leaf_at(&mut value, r#""odd \"label\".x""#), Some(Leaf(8)));
+    assert!(!has_path(&mut value, "map.a.b"));
+    assert!(!has_path(&mut value, "inner.list[2]"));
+}
+
+#[test]
+fn modify_by_path() {
+    let mut value = root();
+    probe_path_mut(&mut value, r#"map."a.b"[0]"#, |value| {
+        value.as_clipboard_value().unwrap().reset_value();
+    });
+    assert_eq!(value.map["a.b"], [Leaf(0)]);
+}
+
+#[test]
+fn visit_read_only() {
+    let mut value = root();
+    visit_paths(&mut value, &mut |path, value| {
+        assert!(value.as_clipboard_value().is_none(), "`{path}` is editable");
+        assert!(value.as_default_value().is_none(), "`{path}` is editable");
+    });
+}
@@ -0,0 +1,270 @@
+mod common;
+
+use std::collections::{BinaryHeap, LinkedList, VecDeque};
+
+use common::field;
+use egui_probe::{clone_items, egui, testing::ProbeHarness, EguiProbe, Style};
+
+#[test]
+fn vec_rows() {
+    let harness = field(vec![1u32, 2]);
+    let paths: Vec<_> = harness.rows().iter().map(|row| row.path.as_str()).collect();
+    assert_eq!(paths, ["", "field", "field[0]", "field[1]"]);
+    assert_eq!(harness.row("field").value_text(), "[2] +");
+    assert_eq!(
+        harness.row("field[1]").indent,
+        harness.row("field").indent + 1
+    );
+}
+
+#[test]
+fn vec_add_remove() {
+    let mut harness = field(vec![1u32, 2]);
+
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0, [1, 2, 0]);
+    assert_eq!(harness.changed_paths(), ["field"]);
+    assert!(harness.has_row("field[2]"));
+
+    harness.click_text("field[0]", "-");
+    assert_eq!(harness.value.0, [2, 0]);
+    assert_eq!(harness.changed_paths(), ["field[0]"]);
+    assert!(!harness.has_row("field[2]"));
+}
+
+#[test]
+fn vec_edit_item() {
+    let mut harness = field(vec![1u32, 2]);
+    harness.type_text_at(harness.text_rect("field[1]", "2").center(), "5");
+    assert_eq!(harness.value.0, [1, 5]);
+    assert_eq!(harness.changed_paths(), ["field[1]"]);
+}
+
+#[test]
+fn vec_buttons() {
+    let mut harness = field(vec![1u32, 2, 3]);
+
+    harness.click_text("field[1]", "⏶");
+    assert_eq!(harness.value.0, [2, 1, 3]);
+
+    harness.click_text("field[1]", "⏷");
+    assert_eq!(harness.value.0, [2, 3, 1]);
+
+    harness.click_text("field[0]", "🗐");
+    assert_eq!(harness.value.0, [2, 2, 3, 1]);
+
+    harness.click_text("field[3]", "➕");
+    assert_eq!(harness.value.0, [2, 2, 3, 0, 1]);
+}
+
+#[test]
+fn vec_drag_reorder() {
+    let mut harness = field(vec![1u32, 2, 3]);
+
+    harness.drag_to("field[2]", "field[0]");
+    assert_eq!(harness.value.0, [3, 1, 2]);
+
+    harness.drag_to("field[0]", "field[1]");
+    assert_eq!(harness.value.0, [1, 3, 2]);
+}
+
+/// Item that can't be cloned.
+#[derive(Debug, Default, PartialEq)]
+struct Handle(u32);
+
+impl EguiProbe for Handle {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        self.0.probe(ui, style)
+    }
+}
+
+#[test]
+fn vec_not_clone() {
+    let mut harness = field(vec![Handle(1), Handle(2)]);
+
+    // Item can't be duplicated without a clipboard value.
+    harness.click_text("field[0]", "🗐");
+    assert_eq!(harness.value.0, [Handle(1), Handle(2)]);
+
+    harness.drag_to("field[1]", "field[0]");
+    assert_eq!(harness.value.0, [Handle(2), Handle(1)]);
+}
+
+/// Item that can be cloned but has no clipboard value.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Cloned(u32);
+
+impl EguiProbe for Cloned {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        self.0.probe(ui, style)
+    }
+}
+
+#[test]
+fn vec_clone_items() {
+    struct Items(Vec<Cloned>);
+
+    impl EguiProbe for Items {
+        fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+            ui.weak("Items")
+        }
+
+        fn has_inner(&mut self) -> bool {
+            true
+        }
+
+        fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+            f("items", &mut clone_items(&mut self.0));
+        }
+    }
+
+    let mut harness = ProbeHarness::new(Items(vec![Cloned(1), Cloned(2)]));
+    harness.expand_all();
+
+    harness.click_text("items[1]", "🗐");
+    assert_eq!(harness.value.0, [Cloned(1), Cloned(2), Cloned(2)]);
+
+    harness.click_text("items", "+");
+    assert_eq!(
+        harness.value.0,
+        [Cloned(1), Cloned(2), Cloned(2), Cloned(0)]
+    );
+}
+
+struct Two {
+    a: Vec<u32>,
+    b: Vec<u32>,
+}
+
+impl EguiProbe for Two {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Two")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("a", &mut self.a);
+        f("b", &mut self.b);
+    }
+}
+
+#[test]
+fn vec_drag_between_sequences() {
+    let mut harness = ProbeHarness::new(Two {
+        a: vec![1, 2],
+        b: vec![3, 4],
+    });
+    harness.expand_all();
+
+    harness.drag_to("a[0]", "b[1]");
+    assert_eq!(harness.value.a, [1, 2]);
+    assert_eq!(harness.value.b, [3, 4]);
+
+    harness.drag_to("b[1]", "b[0]");
+    assert_eq!(harness.value.b, [4, 3]);
+}
+
+#[test]
+fn vec_read_only() {
+    let harness = field(vec![1u32, 2]).read_only(true);
+    assert_eq!(harness.row("field").value_text(), "[2]");
+    assert_eq!(harness.row("field[0]").value_text(), "1");
+}
+
+#[test]
+fn vec_deque() {
+    let mut harness = field(VecDeque::from([1u32, 2]));
+
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0, [1, 2, 0]);
+
+    harness.click_text("field[2]", "⏶");
+    assert_eq!(harness.value.0, [1, 0, 2]);
+
+    harness.click_text("field[0]", "-");
+    assert_eq!(harness.value.0, [0, 2]);
+}
+
+#[test]
+fn linked_list() {
+    let mut harness = field(LinkedList::from([1u32, 2]));
+
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0, LinkedList::from([1, 2, 0]));
+
+    harness.click_text("field[1]", "-");
+    assert_eq!(harness.value.0, LinkedList::from([1, 0]));
+}
+
+#[test]
+fn binary_heap() {
+    let mut harness = field(BinaryHeap::from([3u32, 1, 2]));
+    assert_eq!(harness.row("field[0]").value_text(), "1 -");
+    assert_eq!(harness.row("field[2]").value_text(), "3 -");
+
+    harness.type_text("field[2]", "9");
+    assert_eq!(harness.value.0.clone().into_sorted_vec(), [1, 2, 9]);
+    assert_eq!(harness.row("field[2]").value_text(), "9 -");
+
+    harness.click_text("field[2]", "-");
+    assert_eq!(harness.value.0.clone().into_sorted_vec(), [1, 2]);
+
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.clone().into_sorted_vec(), [0, 1, 2]);
+}
+
+#[test]
+fn array() {
+    let mut harness = field([1u8, 2, 3]);
+    assert_eq!(harness.row("field").value_text(), "[3]");
+
+    harness.type_text("field[2]", "9");
+    assert_eq!(harness.value.0, [1, 2, 9]);
+    assert_eq!(harness.changed_paths(), ["field[2]"]);
+}
+
+#[test]
+fn vec_pages() {
+    let mut harness = field(vec![0u8; 250]).screen_size(egui::vec2(800.0, 10_000.0));
+    assert!(harness.has_row("field[99]"));
+    assert!(!harness.has_row("field[100]"));
+
+    harness.click_any_text("Show 100 more");
+    assert!(harness.has_row("field[199]"));
+    assert!(!harness.has_row("field[200]"));
+
+    harness.click_any_text("Show 50 more");
+    assert!(harness.has_row("field[249]"));
+    assert!(!harness.has_any_text("Show 50 more"));
+}
+
+#[test]
+fn vec_virtualized() {
+    let harness = field(vec![0u8; 100]);
+    assert!(harness.has_row("field[0]"));
+    assert!(!harness.has_row("field[99]"));
+
+    // Rows that are not shown still take their space.
+    let row_height = harness.row("field[1]").rect.min.y - harness.row("field[0]").rect.min.y;
+    let bottom = harness.row("field[0]").rect.min.y + row_height * 100.0;
+    assert!((harness.ctx().used_rect().max.y - bottom).abs() < row_height);
+}
+
+#[test]
+fn vec_range() {
+    let mut value = vec![0u8; 100_000];
+    let mut labels = Vec::new();
+    let len = value.iterate_inner_range(10..12, &mut |label, _| labels.push(label.to_owned()));
+    assert_eq!(len, 100_000);
+    assert_eq!(labels, ["[10]", "[11]"]);
+
+    // Range past the end is clamped.
+    labels.clear();
+    let mut value = VecDeque::from([1u8, 2, 3]);
+    let len = value.iterate_inner_range(2..10, &mut |label, _| labels.push(label.to_owned()));
+    assert_eq!(len, 3);
+    assert_eq!(labels, ["[2]"]);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "serde")]
+
+use egui_probe::{
+    clipboard_value, egui, testing::ProbeHarness, text_value, EguiProbe, Style, TextFormat,
+    TextValue,
+};
+
+struct Value {
+    list: Vec<u32>,
+}
+
+impl EguiProbe for Value {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Value")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f(
+            "list",
+            &mut clipboard_value(&mut text_value(&mut self.list)),
+        );
+    }
+}
+
+fn edited_as_text(read_only: bool) -> ProbeHarness<Value> {
+    let mut harness = ProbeHarness::new(Value { list: vec![1, 2] }).read_only(read_only);
+    harness.expand("");
+    harness.context_menu("list");
+    harness.click_any_text("Edit as text");
+    harness
+}
+
+fn type_list(harness: &mut ProbeHarness<Value>, format: TextFormat, text: &str) {
+    let current = harness.value.list.to_text(format).unwrap();
+    harness.type_text_at(harness.text_rect("list", &current).center(), text);
+}
+
+#[test]
+fn edit_as_text() {
+    let mut harness = edited_as_text(false);
+    assert!(harness.row("list").texts.contains(&"RON".to_owned()));
+
+    type_list(&mut harness, TextFormat::Ron, "[3]");
+    assert_eq!(harness.value.list, [3]);
+    assert_eq!(harness.changed_paths(), ["list"]);
+
+    harness.click_text("list", "JSON");
+    type_list(&mut harness, TextFormat::Json, "[4, 5]");
+    assert_eq!(harness.value.list, [4, 5]);
+}
+
+#[test]
+fn parse_error() {
+    let mut harness = edited_as_text(false);
+    type_list(&mut harness, TextFormat::Ron, "[3");
+    assert_eq!(harness.value.list, [1, 2]);
+    assert!(harness.changed_paths().is_empty());
+    assert!(harness.row("list").texts.contains(&"[3".to_owned()));
+}
+
+#[test]
+fn edit_as_value() {
+    let mut harness = edited_as_text(false);
+    harness.context_menu("list");
+    harness.click_any_text("Edit as value");
+    assert!(!harness.row("list").texts.contains(&"RON".to_owned()));
+    assert_eq!(harness.row("list").value_text(), "[2] +");
+}
+
+#[test]
+fn read_only() {
+    let mut harness = edited_as_text(true);
+    type_list(&mut harness, TextFormat::Ron, "[3]");
+    assert_eq!(harness.value.list, [1, 2]);
+}
+
+#[test]
+fn copy_as_text() {
+    let mut harness = ProbeHarness::new(Value { list: vec![1, 2] });
+    harness.expand("");
+    harness.context_menu("list");
+    harness.click_any_text("Copy as text");
+    assert_eq!(
+        harness.copied_text(),
+        vec![1u32, 2].to_text(TextFormat::Ron).unwrap()
+    );
+}
+
+#[test]
+fn paste_text() {
+    let mut harness = ProbeHarness::new(Value { list: vec![1, 2] });
+    harness.expand("");
+    harness.paste("list", "[7]");
+    assert_eq!(harness.value.list, [1, 2]);
+
+    harness.context_menu("list");
+    harness.click_any_text("Paste text");
+    assert!(harness.has_any_text("Paste RON or JSON text"));
+
+    harness.paste("list", "not a list");
+    assert_eq!(harness.value.list, [1, 2]);
+    assert!(harness.has_any_text("Paste RON or JSON text"));
+
+    harness.paste("list", "[7]");
+    assert_eq!(harness.value.list, [7]);
+    assert_eq!(harness.changed_paths(), ["list"]);
+    assert!(!harness.has_any_text("Paste RON or JSON text"));
+
+    harness.paste("list", "[8]");
+    assert_eq!(harness.value.list, [7]);
+}
+
+#[test]
+fn paste_text_cancel() {
+    let mut harness = ProbeHarness::new(Value { list: vec![1, 2] });
+    harness.expand("");
+    harness.context_menu("list");
+    harness.click_any_text("Paste text");
+    harness.press_shortcut(egui::KeyboardShortcut::new(
+        egui::Modifiers::NONE,
+        egui::Key::Escape,
+    ));
+    assert!(!harness.has_any_text("Paste RON or JSON text"));
+
+    harness.paste("list", "[7]");
+    assert_eq!(harness.value.list, [1, 2]);
+}
+
+#[test]
+fn reset_with_text_value() {
+    let mut harness = ProbeHarness::new(Value { list: vec![1, 2] });
+    harness.expand("");
+    harness.context_menu("list");
+    harness.click_any_text("Reset to default");
+    assert!(harness.value.list.is_empty());
+}
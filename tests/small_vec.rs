@@ -0,0 +1,37 @@
+#![cfg(any(feature = "smallvec1", feature = "smallvec2"))]
+
+mod common;
+
+use common::field;
+
+#[cfg(feature = "smallvec1")]
+#[test]
+fn smallvec1() {
+    let mut harness = field(smallvec1::SmallVec::<[u32; 2]>::from_slice(&[1, 2]));
+    assert_eq!(harness.row("field").value_text(), "[2] +");
+
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.as_slice(), [1, 2, 0]);
+
+    harness.drag_to("field[2]", "field[0]");
+    assert_eq!(harness.value.0.as_slice(), [0, 1, 2]);
+
+    harness.click_text("field[1]", "-");
+    assert_eq!(harness.value.0.as_slice(), [0, 2]);
+}
+
+#[cfg(feature = "smallvec2")]
+#[test]
+fn smallvec2() {
+    let mut harness = field(smallvec2::SmallVec::<u32, 2>::from_iter([1, 2]));
+    assert_eq!(harness.row("field").value_text(), "[2] +");
+
+    harness.click_text("field", "+");
+    assert_eq!(harness.value.0.as_slice(), [1, 2, 0]);
+
+    harness.click_text("field[0]", "🗐");
+    assert_eq!(harness.value.0.as_slice(), [1, 1, 2, 0]);
+
+    harness.click_text("field[1]", "-");
+    assert_eq!(harness.value.0.as_slice(), [1, 2, 0]);
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::field;
+use egui_probe::{egui, testing::ProbeHarness, EguiProbe, Style};
+
+/// Value with rows of different label widths and nesting.
+struct Layout {
+    flag: bool,
+    number: f32,
+    long_label_name: String,
+    color: egui::Color32,
+    list: Vec<u8>,
+    optional: Option<egui::Vec2>,
+}
+
+impl EguiProbe for Layout {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Layout")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("flag", &mut self.flag);
+        f("number", &mut self.number);
+        f("long label name", &mut self.long_label_name);
+        f("color", &mut self.color);
+        f("list", &mut self.list);
+        f("optional", &mut self.optional);
+    }
+}
+
+fn layout() -> Layout {
+    Layout {
+        flag: true,
+        number: 1.5,
+        long_label_name: "text".to_owned(),
+        color: egui::Color32::RED,
+        list: vec![1, 2],
+        optional: Some(egui::vec2(1.0, 2.0)),
+    }
+}
+
+#[test]
+fn leaf() {
+    ProbeHarness::new(42u32).assert_snapshot("leaf");
+}
+
+#[test]
+fn collapsed() {
+    ProbeHarness::new(layout()).assert_snapshot("collapsed");
+}
+
+#[test]
+fn expanded() {
+    field(layout()).assert_snapshot("expanded");
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(layout()).read_only(true);
+    harness.expand_all();
+    harness.assert_snapshot("read_only");
+}
+
+#[test]
+fn values_aligned() {
+    let harness = field(layout());
+    let rows: Vec<_> = harness
+        .rows()
+        .iter()
+        .filter(|row| !row.path.is_empty())
+        .collect();
+    for row in &rows {
+        assert!(
+            (row.value_rect.min.x - rows[0].value_rect.min.x).abs() < 0.01,
+            "Value column of row `{}` is not aligned",
+            row.path
+        );
+    }
+}
+
+#[test]
+fn tessellate() {
+    let harness = field(layout());
+    assert!(!harness.tessellate().is_empty());
+}
+
+#[test]
+fn missing_golden_file() {
+    // Golden file would be written instead.
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        return;
+    }
+    let result = std::panic::catch_unwind(|| {
+        ProbeHarness::new(42u32).assert_snapshot("missing");
+    });
+    assert!(result.is_err());
+}
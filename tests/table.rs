@@ -0,0 +1,138 @@
+use egui_probe::{egui, table, testing::ProbeHarness, EguiProbe, Style};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Particle {
+    mass: f32,
+    name: String,
+}
+
+impl EguiProbe for Particle {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Particle")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("mass", &mut self.mass);
+        f("name", &mut self.name);
+    }
+}
+
+struct Particles {
+    particles: Vec<Particle>,
+}
+
+impl EguiProbe for Particles {
+    fn probe(&mut self, ui: &mut egui::Ui, _style: &Style) -> egui::Response {
+        ui.weak("Particles")
+    }
+
+    fn has_inner(&mut self) -> bool {
+        true
+    }
+
+    fn iterate_inner(&mut self, f: &mut dyn FnMut(&str, &mut dyn EguiProbe)) {
+        f("particles", &mut table(&mut self.particles));
+    }
+}
+
+fn particle(mass: f32, name: &str) -> Particle {
+    Particle {
+        mass,
+        name: name.to_owned(),
+    }
+}
+
+fn harness(particles: Vec<Particle>) -> ProbeHarness<Particles> {
+    let mut harness = ProbeHarness::new(Particles { particles });
+    harness.expand("");
+    harness
+}
+
+/// Returns `true` if the first text is shown above the second one.
+fn is_above(harness: &ProbeHarness<Particles>, a: &str, b: &str) -> bool {
+    harness.any_text_rect(a).min.y < harness.any_text_rect(b).min.y
+}
+
+#[test]
+fn grid() {
+    let harness = harness(vec![particle(2.0, "b"), particle(1.0, "a")]);
+    assert!(!harness.has_row("particles[0]"));
+    assert!(harness.has_any_text("mass"));
+    assert!(harness.has_any_text("name"));
+    assert!(harness.has_any_text("[1]"));
+    assert!(is_above(&harness, "mass", "[0]"));
+    assert!(is_above(&harness, "[0]", "[1]"));
+    assert_eq!(
+        harness.any_text_rect("[0]").min.y,
+        harness.any_text_rect("b").min.y
+    );
+}
+
+#[test]
+fn sort() {
+    let mut harness = harness(vec![
+        particle(2.0, "b"),
+        particle(1.0, "a"),
+        particle(3.0, "c"),
+    ]);
+    harness.click_any_text("mass");
+    assert!(harness.has_any_text("mass ⏶"));
+    assert!(is_above(&harness, "[1]", "[0]"));
+    assert!(is_above(&harness, "[0]", "[2]"));
+
+    harness.click_any_text("mass ⏶");
+    assert!(is_above(&harness, "[2]", "[0]"));
+    assert!(is_above(&harness, "[0]", "[1]"));
+
+    harness.click_any_text("mass ⏷");
+    assert!(harness.has_any_text("mass"));
+    assert!(is_above(&harness, "[0]", "[1]"));
+
+    harness.click_any_text("name");
+    assert!(is_above(&harness, "[1]", "[0]"));
+
+    // Sorting does not reorder the vector.
+    assert_eq!(harness.value.particles[0], particle(2.0, "b"));
+}
+
+#[test]
+fn edit() {
+    let mut harness = harness(vec![particle(1.0, "a")]);
+    let pos = harness.any_text_rect("a").center();
+    harness.type_text_at(pos, "b");
+    assert_eq!(harness.value.particles[0].name, "b");
+    assert_eq!(harness.changed_paths(), ["particles"]);
+}
+
+#[test]
+fn add_remove() {
+    let mut harness = harness(vec![particle(1.0, "a"), particle(2.0, "b")]);
+    harness.click_text("particles", "+");
+    assert_eq!(harness.value.particles.len(), 3);
+    assert_eq!(harness.value.particles[2], Particle::default());
+
+    harness.click_text("particles", "-");
+    assert_eq!(
+        harness.value.particles,
+        [particle(2.0, "b"), Particle::default()]
+    );
+}
+
+#[test]
+fn read_only() {
+    let harness = harness(vec![particle(1.0, "a")]).read_only(true);
+    assert!(!harness.has_any_text("+"));
+    assert!(!harness.has_any_text("-"));
+}
+
+#[test]
+fn virtualized() {
+    let particles = (0..10_000).map(|idx| particle(idx as f32, "a")).collect();
+    let harness = harness(particles);
+    assert!(harness.has_any_text("[0]"));
+    assert!(!harness.has_any_text("[9999]"));
+}
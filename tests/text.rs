@@ -0,0 +1,40 @@
+use egui_probe::testing::ProbeHarness;
+
+#[test]
+fn string() {
+    let mut harness = ProbeHarness::new("foo".to_owned());
+    assert_eq!(harness.row("").value_text(), "foo");
+
+    harness.type_text("", "bar");
+    assert_eq!(harness.value, "bar");
+    assert_eq!(harness.changed_paths(), [""]);
+}
+
+#[test]
+fn string_read_only() {
+    let mut harness = ProbeHarness::new("foo".to_owned()).read_only(true);
+    assert_eq!(harness.row("").value_text(), "foo");
+
+    harness.type_text("", "bar");
+    assert_eq!(harness.value, "foo");
+    assert!(harness.changed_paths().is_empty());
+}
+
+#[test]
+fn str_is_not_editable() {
+    let mut harness = ProbeHarness::new("foo");
+    assert_eq!(harness.row("").value_text(), "foo");
+
+    harness.type_text("", "bar");
+    assert_eq!(harness.value, "foo");
+}
+
+#[test]
+fn cow() {
+    let mut harness = ProbeHarness::new(std::borrow::Cow::Borrowed("foo"));
+    assert_eq!(harness.row("").value_text(), "foo");
+
+    harness.type_text("", "bar");
+    assert_eq!(harness.value, "bar");
+    assert!(matches!(harness.value, std::borrow::Cow::Owned(_)));
+}
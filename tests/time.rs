@@ -0,0 +1,86 @@
+#![cfg(feature = "time")]
+
+use egui_probe::testing::ProbeHarness;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+fn date(year: i32, month: Month, day: u8) -> Date {
+    Date::from_calendar_date(year, month, day).unwrap()
+}
+
+fn date_time(date: Date, hour: u8, minute: u8) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(date, Time::from_hms(hour, minute, 0).unwrap())
+}
+
+#[test]
+fn date_typed() {
+    let mut harness = ProbeHarness::new(date(2024, Month::March, 5));
+    assert_eq!(harness.row("").value_text(), "2024-03-05 📅");
+
+    harness.type_text("", "2024-02-29");
+    assert_eq!(harness.value, date(2024, Month::February, 29));
+    assert_eq!(harness.changed_paths(), [""]);
+
+    // Year out of range of the crate is reported.
+    harness.type_text("", "10000-01-01");
+    let texts = &harness.row("").texts;
+    assert!(texts.iter().any(|text| text.contains("year")));
+    assert_eq!(harness.value, date(2024, Month::February, 29));
+}
+
+#[test]
+fn time_typed() {
+    let mut harness = ProbeHarness::new(Time::from_hms_micro(8, 30, 0, 5).unwrap());
+    assert_eq!(harness.row("").value_text(), "08:30:00.000005");
+
+    harness.type_text("", "12:00:01.5");
+    assert_eq!(harness.value, Time::from_hms_milli(12, 0, 1, 500).unwrap());
+}
+
+#[test]
+fn primitive_date_time() {
+    let mut harness = ProbeHarness::new(date_time(date(2024, Month::March, 5), 13, 45));
+    assert_eq!(harness.row("").value_text(), "2024-03-05 13:45:00 📅");
+
+    harness.click_text("", "📅");
+    harness.click_any_text("⏴");
+    assert!(harness.has_any_text("February 2024"));
+    harness.click_any_text("29");
+    assert_eq!(
+        harness.value,
+        date_time(date(2024, Month::February, 29), 13, 45)
+    );
+}
+
+#[test]
+fn offset_date_time() {
+    let offset = UtcOffset::from_hms(-5, -30, 0).unwrap();
+    let value = date_time(date(2024, Month::March, 5), 13, 45).assume_offset(offset);
+    let mut harness = ProbeHarness::new(value);
+    assert_eq!(
+        harness.row("").value_text(),
+        "2024-03-05 13:45:00 -05:30 📅"
+    );
+
+    // Time without offset keeps offset of the value.
+    harness.type_text("", "2024-03-05 10:00");
+    assert_eq!(
+        harness.value,
+        date_time(date(2024, Month::March, 5), 10, 0).assume_offset(offset)
+    );
+
+    harness.type_text("", "2024-03-05 10:00 Z");
+    assert_eq!(harness.value.offset(), UtcOffset::UTC);
+    assert_eq!(
+        harness.value,
+        OffsetDateTime::from_unix_timestamp(1_709_632_800).unwrap()
+    );
+}
+
+#[test]
+fn read_only() {
+    let mut harness = ProbeHarness::new(Time::MIDNIGHT).read_only(true);
+    assert_eq!(harness.row("").value_text(), "00:00:00");
+
+    harness.type_text("", "12:00");
+    assert_eq!(harness.value, Time::MIDNIGHT);
+}
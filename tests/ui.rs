@@ -0,0 +1,101 @@
+use egui_probe::{
+    egui::{epaint::Shadow, Color32, Frame, Margin, Rounding, Stroke},
+    testing::ProbeHarness,
+};
+
+fn paths<T: egui_probe::EguiProbe>(harness: &ProbeHarness<T>) -> Vec<&str> {
+    harness.rows().iter().map(|row| row.path.as_str()).collect()
+}
+
+#[test]
+fn stroke() {
+    let mut harness = ProbeHarness::new(Stroke::new(1.0, Color32::RED));
+    harness.expand_all();
+    assert_eq!(paths(&harness), ["", "color", "width"]);
+
+    harness.type_text("width", "2");
+    assert_eq!(harness.value.width, 2.0);
+    assert_eq!(harness.changed_paths(), ["width"]);
+}
+
+#[test]
+fn non_negative() {
+    let mut harness = ProbeHarness::new(Margin::same(1.0));
+    harness.expand_all();
+    assert_eq!(paths(&harness), ["", "top", "left", "bottom", "right"]);
+
+    harness.type_text("left", "-5");
+    assert_eq!(harness.value.left, 0.0);
+}
+
+#[test]
+fn rounding() {
+    let mut harness = ProbeHarness::new(Rounding::ZERO);
+    harness.expand_all();
+    assert_eq!(paths(&harness), ["", "nw", "ne", "sw", "se"]);
+
+    harness.type_text("se", "4");
+    assert_eq!(harness.value.se, 4.0);
+}
+
+#[test]
+fn shadow() {
+    let mut harness = ProbeHarness::new(Shadow::NONE);
+    harness.expand_all();
+    assert_eq!(paths(&harness), ["", "extrusion", "color"]);
+
+    harness.type_text("extrusion", "3");
+    assert_eq!(harness.value.extrusion, 3.0);
+}
+
+#[test]
+fn frame() {
+    let mut harness = ProbeHarness::new(Frame::none());
+    harness.expand_all();
+    assert_eq!(
+        paths(&harness),
+        [
+            "",
+            "inner_margin",
+            "inner_margin.top",
+            "inner_margin.left",
+            "inner_margin.bottom",
+            "inner_margin.right",
+            "outer_margin",
+            "outer_margin.top",
+            "outer_margin.left",
+            "outer_margin.bottom",
+            "outer_margin.right",
+            "rounding",
+            "rounding.nw",
+            "rounding.ne",
+            "rounding.sw",
+            "rounding.se",
+            "shadow",
+            "shadow.extrusion",
+            "shadow.color",
+            "fill",
+            "stroke",
+            "stroke.color",
+            "stroke.width",
+        ]
+    );
+
+    harness.type_text("stroke.width", "2");
+    assert_eq!(harness.value.stroke.width, 2.0);
+    assert_eq!(harness.changed_paths(), ["stroke.width"]);
+}
+
+#[test]
+fn collapse() {
+    let mut harness = ProbeHarness::new(Stroke::NONE);
+    assert_eq!(paths(&harness), [""]);
+    assert_eq!(harness.row("").open, Some(false));
+
+    harness.toggle("");
+    assert_eq!(harness.row("").open, Some(true));
+    assert!(harness.has_row("width"));
+
+    harness.toggle("");
+    assert_eq!(paths(&harness), [""]);
+}
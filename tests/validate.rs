@@ -0,0 +1,70 @@
+mod common;
+
+use common::field;
+use egui_probe::{egui, testing::ProbeHarness, EguiProbe, Probe, Severity, Style, Validation};
+
+#[derive(Clone, Default)]
+struct Positive(f32);
+
+impl EguiProbe for Positive {
+    fn probe(&mut self, ui: &mut egui::Ui, style: &Style) -> egui::Response {
+        self.0.probe(ui, style)
+    }
+
+    fn validate(&mut self) -> Result<(), Validation> {
+        if self.0 > 0.0 {
+            Ok(())
+        } else {
+            Err(Validation::error("must be positive"))
+        }
+    }
+}
+
+#[test]
+fn issue_reported() {
+    let mut harness = field(Positive(-1.0));
+    assert_eq!(harness.issues().len(), 1);
+    assert_eq!(harness.issues()[0].path, "field");
+    assert_eq!(harness.issues()[0].validation.severity, Severity::Error);
+    assert_eq!(harness.row("field").value_text(), "-1 must be positive");
+
+    harness.type_text("field", "2");
+    assert!(harness.issues().is_empty());
+    assert_eq!(harness.row("field").value_text(), "2");
+}
+
+#[test]
+fn collapsed_values_validated() {
+    let mut harness = field(vec![Positive(1.0), Positive(0.0)]);
+    harness.toggle("");
+    assert!(!harness.has_row("field"));
+
+    let paths: Vec<_> = harness.issues().iter().map(|issue| &issue.path).collect();
+    assert_eq!(paths, ["field[1]"]);
+}
+
+#[test]
+fn hidden_values_not_validated() {
+    let mut harness = ProbeHarness::with_show(
+        vec![Positive(1.0), Positive(0.0)],
+        |value, ui, read_only| {
+            Probe::new("value", value)
+                .validate_hidden(false)
+                .read_only(read_only)
+                .show(ui)
+        },
+    );
+    assert!(!harness.has_row("[1]"));
+    assert!(harness.issues().is_empty());
+
+    harness.expand("");
+    let paths: Vec<_> = harness.issues().iter().map(|issue| &issue.path).collect();
+    assert_eq!(paths, ["[1]"]);
+
+    harness.toggle("");
+    assert!(harness.issues().is_empty());
+
+    let issues = egui_probe::validation_issues(&mut harness.value);
+    let paths: Vec<_> = issues.iter().map(|issue| &issue.path).collect();
+    assert_eq!(paths, ["[1]"]);
+}
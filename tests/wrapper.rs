@@ -0,0 +1,98 @@
+use std::{
+    cell::{Cell, RefCell},
+    num::Wrapping,
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use egui_probe::testing::ProbeHarness;
+
+#[test]
+fn boxed() {
+    let mut harness = ProbeHarness::new(Box::new(vec![1, 2]));
+    harness.expand("");
+    assert!(harness.has_row("[1]"));
+
+    harness.type_text_at(harness.text_rect("[1]", "2").center(), "5");
+    assert_eq!(*harness.value, [1, 5]);
+    assert_eq!(harness.changed_paths(), ["[1]"]);
+}
+
+#[test]
+fn cells() {
+    let mut harness = ProbeHarness::new(Cell::new(3));
+    harness.type_text("", "4");
+    assert_eq!(harness.value.get(), 4);
+
+    let mut harness = ProbeHarness::new(RefCell::new("foo".to_owned()));
+    harness.type_text("", "bar");
+    assert_eq!(*harness.value.borrow(), "bar");
+
+    let mut harness = ProbeHarness::new(Wrapping(7u8));
+    harness.type_text("", "9");
+    assert_eq!(harness.value, Wrapping(9));
+}
+
+#[test]
+fn rc_ref_cell() {
+    let shared = Rc::new(RefCell::new(vec![1]));
+    let mut harness = ProbeHarness::new(shared.clone());
+    harness.expand("");
+    harness.type_text_at(harness.text_rect("[0]", "1").center(), "2");
+    assert_eq!(*shared.borrow(), [2]);
+
+    // Borrowed value is replaced with placeholder.
+    let guard = shared.borrow();
+    harness.run();
+    assert_eq!(harness.row("").value_text(), "borrowed");
+    assert!(!harness.has_row("[0]"));
+
+    drop(guard);
+    harness.run();
+    assert!(harness.has_row("[0]"));
+}
+
+#[test]
+fn arc_mutex() {
+    let shared = Arc::new(Mutex::new(1));
+    let mut harness = ProbeHarness::new(shared.clone());
+    harness.type_text("", "2");
+    assert_eq!(*shared.lock().unwrap(), 2);
+
+    let guard = shared.lock().unwrap();
+    harness.run();
+    assert_eq!(harness.row("").value_text(), "locked");
+
+    drop(guard);
+    harness.run();
+    assert_eq!(harness.row("").value_text(), "2");
+}
+
+#[test]
+fn arc_rw_lock() {
+    let shared = Arc::new(RwLock::new(1));
+    let mut harness = ProbeHarness::new(shared.clone());
+    harness.type_text("", "2");
+    assert_eq!(*shared.read().unwrap(), 2);
+
+    // Value can't be edited while read elsewhere.
+    let guard = shared.read().unwrap();
+    harness.run();
+    assert_eq!(harness.row("").value_text(), "locked");
+    drop(guard);
+}
+
+#[test]
+fn poisoned_mutex() {
+    let shared = Arc::new(Mutex::new(1));
+    let poison = shared.clone();
+    let _ = std::thread::spawn(move || {
+        let _guard = poison.lock().unwrap();
+        panic!("poison");
+    })
+    .join();
+    assert!(shared.is_poisoned());
+
+    let harness = ProbeHarness::new(shared);
+    assert_eq!(harness.row("").value_text(), "1");
+}